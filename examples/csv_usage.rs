@@ -15,7 +15,7 @@ fn main() -> Result<(), Box<dyn Error>> {
 
     for result in rdr.records() {
         let record = result?;
-        let row: Vec<Cell> = record.iter().map(|s| Cell::new(s)).collect();
+        let row: Vec<Cell> = record.iter().map(Cell::new).collect();
         table.add_row(row);
     }
 
@@ -16,6 +16,7 @@ fn main() {
         TableStyle::Dotted,
         TableStyle::Heavy,
         TableStyle::Neon,
+        TableStyle::Accessible,
     ];
     for style in styles.iter() {
         println!("{:?} style:", style);
@@ -0,0 +1,20 @@
+use std::env;
+
+use tabprinter::{SortKind, SortOptions, Table, TableStyle};
+
+fn main() -> std::io::Result<()> {
+    // List the directory given on the command line, or the current one
+    let path = env::args().nth(1).unwrap_or_else(|| ".".to_string());
+    let mut table = Table::from_dir(&path)?;
+    table.set_style(TableStyle::Round);
+
+    // Show the largest files first, comparing the byte counts as numbers
+    let options = SortOptions {
+        kind: SortKind::Natural,
+        nulls_last: true,
+        ..SortOptions::default()
+    };
+    table.sort_by_column_with("Size", false, &options);
+
+    table.print()
+}
@@ -0,0 +1,20 @@
+use std::time::Duration;
+
+use sysinfo::{ProcessesToUpdate, System};
+use tabprinter::{watch, Table, TableStyle};
+
+fn main() -> std::io::Result<()> {
+    // Keep the system between frames so the CPU usage covers the last interval
+    let mut system = System::new();
+    system.refresh_processes(ProcessesToUpdate::All, true);
+
+    watch(Duration::from_secs(1), || {
+        system.refresh_processes(ProcessesToUpdate::All, true);
+        let mut table = Table::from_system(&system);
+        table.set_style(TableStyle::Round);
+
+        // Show the twenty busiest processes; shorter listings are kept as they are
+        table.truncate_rows(20).ok();
+        table
+    })
+}
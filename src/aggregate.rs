@@ -0,0 +1,433 @@
+// SPDX-License-Identifier: MIT
+// Project: tabprinter
+// File: src/aggregate.rs
+// Author: Volker Schwaberow <volker@schwaberow.de>
+// Copyright (c) 2025 Volker Schwaberow
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::{AggregateError, ColumnRef, Row, Table, TableError};
+
+/// A statistic computed from the numeric values of a column, one value at a time.
+pub trait Aggregator: Send {
+    /// Adds a value to the statistic.
+    fn feed(&mut self, value: f64);
+    /// Returns the statistic of the values fed so far.
+    fn finish(&self) -> f64;
+    /// Returns the name of the statistic, used as its label.
+    fn name(&self) -> &str;
+}
+
+/// Creates a fresh aggregator for every column or group it is applied to.
+type AggregatorFactory = Arc<dyn Fn() -> Box<dyn Aggregator> + Send + Sync>;
+
+/// Sums the values.
+#[derive(Debug, Default)]
+struct Sum(f64);
+
+impl Aggregator for Sum {
+    fn feed(&mut self, value: f64) {
+        self.0 += value;
+    }
+    fn finish(&self) -> f64 {
+        self.0
+    }
+    fn name(&self) -> &str {
+        "sum"
+    }
+}
+
+/// Creates the built-in aggregator summing the values.
+pub(crate) fn sum_aggregator() -> Box<dyn Aggregator> {
+    Box::new(Sum::default())
+}
+
+/// Averages the values.
+#[derive(Debug, Default)]
+struct Mean {
+    sum: f64,
+    count: usize,
+}
+
+impl Aggregator for Mean {
+    fn feed(&mut self, value: f64) {
+        self.sum += value;
+        self.count += 1;
+    }
+    fn finish(&self) -> f64 {
+        self.sum / self.count as f64
+    }
+    fn name(&self) -> &str {
+        "avg"
+    }
+}
+
+/// Keeps the smallest value.
+#[derive(Debug)]
+struct Min(f64);
+
+impl Aggregator for Min {
+    fn feed(&mut self, value: f64) {
+        self.0 = self.0.min(value);
+    }
+    fn finish(&self) -> f64 {
+        self.0
+    }
+    fn name(&self) -> &str {
+        "min"
+    }
+}
+
+/// Keeps the largest value.
+#[derive(Debug)]
+struct Max(f64);
+
+impl Aggregator for Max {
+    fn feed(&mut self, value: f64) {
+        self.0 = self.0.max(value);
+    }
+    fn finish(&self) -> f64 {
+        self.0
+    }
+    fn name(&self) -> &str {
+        "max"
+    }
+}
+
+/// Counts the values.
+#[derive(Debug, Default)]
+struct Count(usize);
+
+impl Aggregator for Count {
+    fn feed(&mut self, _value: f64) {
+        self.0 += 1;
+    }
+    fn finish(&self) -> f64 {
+        self.0 as f64
+    }
+    fn name(&self) -> &str {
+        "count"
+    }
+}
+
+/// The numeric values of the columns, parsed once and reused by the aggregations
+/// until a mutation other than appending rows makes them stale.
+#[derive(Debug, Default)]
+pub(crate) struct NumericCache {
+    /// The value of every row per column, or `None` for columns not parsed yet.
+    /// Rows whose cell is not a number hold `None`.
+    columns: Vec<Option<Arc<Vec<Option<f64>>>>>,
+    /// Whether a mutation invalidated the parsed values.
+    dirty: bool,
+}
+
+impl NumericCache {
+    /// Marks the parsed values as stale.
+    pub(crate) fn invalidate(&mut self) {
+        self.dirty = true;
+    }
+}
+
+/// Named aggregators available to group-by subtotals and summaries.
+/// The built-in `sum`, `avg`, `min`, `max`, and `count` are always registered.
+#[derive(Clone)]
+pub struct AggregatorRegistry {
+    /// The factory of every aggregator by name.
+    factories: HashMap<String, AggregatorFactory>,
+}
+
+impl Default for AggregatorRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AggregatorRegistry {
+    /// Creates a registry with the built-in aggregators.
+    pub fn new() -> Self {
+        let mut registry = Self {
+            factories: HashMap::new(),
+        };
+        registry.register("sum", sum_aggregator);
+        registry.register("avg", || Box::new(Mean::default()));
+        registry.register("min", || Box::new(Min(f64::INFINITY)));
+        registry.register("max", || Box::new(Max(f64::NEG_INFINITY)));
+        registry.register("count", || Box::new(Count::default()));
+        registry
+    }
+
+    /// Registers an aggregator under the name, replacing any aggregator of the same name.
+    pub fn register<F>(&mut self, name: &str, factory: F)
+    where
+        F: Fn() -> Box<dyn Aggregator> + Send + Sync + 'static,
+    {
+        self.factories.insert(name.to_string(), Arc::new(factory));
+    }
+
+    /// Creates a fresh aggregator registered under the name.
+    pub fn create(&self, name: &str) -> Option<Box<dyn Aggregator>> {
+        self.factories.get(name).map(|factory| factory())
+    }
+}
+
+impl Table {
+    /// Returns the numeric value of every row in the specified column, or `None` for
+    /// cells that are not numbers. The values are parsed once and reused; rows added
+    /// since are parsed on the next call.
+    pub(crate) fn parsed_column(&self, column_index: usize) -> Arc<Vec<Option<f64>>> {
+        let mut cache = self
+            .numeric_cache
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        if cache.dirty || cache.columns.len() != self.columns.len() {
+            cache.columns = vec![None; self.columns.len()];
+            cache.dirty = false;
+        }
+        let parse = |row: &Row| row[column_index].content.parse::<f64>().ok();
+        let Some(slot) = cache.columns.get_mut(column_index) else {
+            return Arc::new(self.rows.iter().map(parse).collect());
+        };
+        match slot {
+            Some(values) if values.len() == self.rows.len() => {}
+            Some(values) if values.len() < self.rows.len() => {
+                let parsed = values.len();
+                Arc::make_mut(values).extend(self.rows[parsed..].iter().map(parse));
+            }
+            _ => *slot = Some(Arc::new(self.rows.iter().map(parse).collect())),
+        }
+        slot.clone().unwrap_or_default()
+    }
+
+    /// Marks the parsed numeric values as stale after the rows changed or moved.
+    pub(crate) fn invalidate_numbers(&mut self) {
+        self.numeric_cache
+            .get_mut()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .invalidate();
+    }
+
+    /// Returns the lenient numeric values of the specified column: cells that are not
+    /// numbers or are `NaN` are skipped.
+    fn lossy_values(&self, column_index: usize) -> Vec<f64> {
+        self.parsed_column(column_index)
+            .iter()
+            .flatten()
+            .copied()
+            .filter(|value| !value.is_nan())
+            .collect()
+    }
+
+    /// Returns the numeric values of the specified column, or an error listing every
+    /// cell that is not a number or is `NaN`. Empty cells hold no value and are skipped.
+    fn numeric_values(&self, column: ColumnRef<'_>) -> Result<Vec<f64>, AggregateError> {
+        let column_index = self.column_position(column).map_err(|error| match error {
+            TableError::ColumnOutOfBounds { index, len } => {
+                AggregateError::ColumnOutOfBounds { index, len }
+            }
+            TableError::UnknownColumn(header) => AggregateError::UnknownColumn(header),
+            other => unreachable!("column lookups fail with other errors: {}", other),
+        })?;
+        let mut values = Vec::new();
+        let mut unparsable = Vec::new();
+        let mut nan = Vec::new();
+        for (i, row) in self.rows.iter().enumerate() {
+            let content = row[column_index].content.trim();
+            if content.is_empty() {
+                continue;
+            }
+            match content.parse::<f64>() {
+                Ok(value) if value.is_nan() => nan.push(i),
+                Ok(value) => values.push(value),
+                Err(_) => unparsable.push((i, content.to_string())),
+            }
+        }
+        if !unparsable.is_empty() {
+            Err(AggregateError::Unparsable { cells: unparsable })
+        } else if !nan.is_empty() {
+            Err(AggregateError::NotANumber { rows: nan })
+        } else if values.is_empty() {
+            Err(AggregateError::Empty)
+        } else {
+            Ok(values)
+        }
+    }
+
+    /// Calculates the sum of the specified column, failing on cells that are not numbers.
+    pub fn try_sum_column<'a>(
+        &self,
+        column: impl Into<ColumnRef<'a>>,
+    ) -> Result<f64, AggregateError> {
+        Ok(self.numeric_values(column.into())?.iter().sum())
+    }
+
+    /// Calculates the average of the specified column, failing on cells that are not numbers.
+    pub fn try_average_column<'a>(
+        &self,
+        column: impl Into<ColumnRef<'a>>,
+    ) -> Result<f64, AggregateError> {
+        let values = self.numeric_values(column.into())?;
+        Ok(values.iter().sum::<f64>() / values.len() as f64)
+    }
+
+    /// Finds the minimum value in the specified column, failing on cells that are not numbers.
+    pub fn try_min_column<'a>(
+        &self,
+        column: impl Into<ColumnRef<'a>>,
+    ) -> Result<f64, AggregateError> {
+        Ok(self
+            .numeric_values(column.into())?
+            .into_iter()
+            .fold(f64::INFINITY, f64::min))
+    }
+
+    /// Finds the maximum value in the specified column, failing on cells that are not numbers.
+    pub fn try_max_column<'a>(
+        &self,
+        column: impl Into<ColumnRef<'a>>,
+    ) -> Result<f64, AggregateError> {
+        Ok(self
+            .numeric_values(column.into())?
+            .into_iter()
+            .fold(f64::NEG_INFINITY, f64::max))
+    }
+
+    /// Calculates the sum of the specified column, skipping cells that are not numbers
+    /// or `NaN`. Returns `None` if the column has no numeric values.
+    pub fn sum_column_lossy<'a>(&self, column: impl Into<ColumnRef<'a>>) -> Option<f64> {
+        let column_index = self.resolve_column(column);
+        let values = self.lossy_values(column_index);
+        (!values.is_empty()).then(|| values.iter().sum())
+    }
+
+    /// Calculates the average of the specified column, skipping cells that are not
+    /// numbers or `NaN`. Returns `None` if the column has no numeric values.
+    pub fn average_column_lossy<'a>(&self, column: impl Into<ColumnRef<'a>>) -> Option<f64> {
+        let column_index = self.resolve_column(column);
+        let values = self.lossy_values(column_index);
+        (!values.is_empty()).then(|| values.iter().sum::<f64>() / values.len() as f64)
+    }
+
+    /// Finds the minimum value in the specified column, skipping cells that are not
+    /// numbers or `NaN`. Returns `None` if the column has no numeric values.
+    pub fn min_column_lossy<'a>(&self, column: impl Into<ColumnRef<'a>>) -> Option<f64> {
+        let column_index = self.resolve_column(column);
+        let values = self.lossy_values(column_index);
+        (!values.is_empty()).then(|| values.into_iter().fold(f64::INFINITY, f64::min))
+    }
+
+    /// Finds the maximum value in the specified column, skipping cells that are not
+    /// numbers or `NaN`. Returns `None` if the column has no numeric values.
+    pub fn max_column_lossy<'a>(&self, column: impl Into<ColumnRef<'a>>) -> Option<f64> {
+        let column_index = self.resolve_column(column);
+        let values = self.lossy_values(column_index);
+        (!values.is_empty()).then(|| values.into_iter().fold(f64::NEG_INFINITY, f64::max))
+    }
+
+    /// Feeds the numeric values of the specified column to the aggregator and
+    /// returns its result, or `None` if the column has no numeric values.
+    pub fn aggregate_with<'a>(
+        &self,
+        column: impl Into<ColumnRef<'a>>,
+        aggregator: &mut dyn Aggregator,
+    ) -> Option<f64> {
+        let column_index = self.resolve_column(column);
+        let mut fed = false;
+        for &value in self.parsed_column(column_index).iter().flatten() {
+            aggregator.feed(value);
+            fed = true;
+        }
+        fed.then(|| aggregator.finish())
+    }
+
+    /// Returns the pairs of numeric values of two columns, skipping rows where
+    /// either cell is not a number.
+    pub(crate) fn numeric_pairs(&self, first: usize, second: usize) -> Vec<(f64, f64)> {
+        let first = self.parsed_column(first);
+        let second = self.parsed_column(second);
+        first
+            .iter()
+            .zip(second.iter())
+            .filter_map(|(a, b)| Some(((*a)?, (*b)?)))
+            .collect()
+    }
+
+    /// Calculates the average of the value column weighted by the weight column.
+    /// Rows where either cell is not a number are skipped. Returns `None` if no row
+    /// has both numbers or the weights add up to zero.
+    pub fn weighted_average<'a>(
+        &self,
+        value_column: impl Into<ColumnRef<'a>>,
+        weight_column: impl Into<ColumnRef<'a>>,
+    ) -> Option<f64> {
+        let weight_column = self.resolve_column(weight_column);
+        let value_column = self.resolve_column(value_column);
+        let pairs = self.numeric_pairs(value_column, weight_column);
+        let total_weight: f64 = pairs.iter().map(|(_, weight)| weight).sum();
+        if pairs.is_empty() || total_weight == 0.0 {
+            return None;
+        }
+        let weighted: f64 = pairs.iter().map(|(value, weight)| value * weight).sum();
+        Some(weighted / total_weight)
+    }
+
+    /// Calculates the sum of the products of two columns, row by row.
+    /// Rows where either cell is not a number are skipped. Returns `None` if no row
+    /// has both numbers.
+    pub fn dot_product<'a>(
+        &self,
+        first_column: impl Into<ColumnRef<'a>>,
+        second_column: impl Into<ColumnRef<'a>>,
+    ) -> Option<f64> {
+        let second_column = self.resolve_column(second_column);
+        let first_column = self.resolve_column(first_column);
+        let pairs = self.numeric_pairs(first_column, second_column);
+        if pairs.is_empty() {
+            return None;
+        }
+        Some(pairs.iter().map(|(a, b)| a * b).sum())
+    }
+
+    /// Registers a user-defined aggregator with the table, so it can be used by name in
+    /// `aggregate_named`, `group_by_column_with_aggregator`, and `Summary::Custom`.
+    pub fn register_aggregator<F>(&mut self, name: &str, factory: F)
+    where
+        F: Fn() -> Box<dyn Aggregator> + Send + Sync + 'static,
+    {
+        self.aggregators.register(name, factory);
+    }
+
+    /// Aggregates the specified column with the aggregator registered under the name.
+    pub fn aggregate_named<'a>(
+        &self,
+        column: impl Into<ColumnRef<'a>>,
+        name: &str,
+    ) -> Result<Option<f64>, TableError> {
+        let column_index = self.column_position(column)?;
+        let mut aggregator = self.create_aggregator(name)?;
+        Ok(self.aggregate_with(column_index, aggregator.as_mut()))
+    }
+
+    /// Creates the aggregator registered under the name.
+    pub(crate) fn create_aggregator(&self, name: &str) -> Result<Box<dyn Aggregator>, TableError> {
+        self.aggregators
+            .create(name)
+            .ok_or_else(|| TableError::UnknownAggregator(name.to_string()))
+    }
+
+    /// Groups rows by the specified column and adds a row after every group with
+    /// the statistic of the aggregator registered under the name.
+    pub fn group_by_column_with_aggregator<'a>(
+        &mut self,
+        column: impl Into<ColumnRef<'a>>,
+        name: &str,
+    ) -> Result<(), TableError> {
+        let column_index = self.column_position(column)?;
+        self.create_aggregator(name)?;
+        let aggregators = self.aggregators.clone();
+        self.group_with(column_index, &format!("Subtotal ({})", name), &|| {
+            aggregators.create(name).expect("aggregator is registered")
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,55 @@
+// SPDX-License-Identifier: MIT
+// Project: tabprinter
+// File: src/bidi.rs
+// Author: Volker Schwaberow <volker@schwaberow.de>
+// Copyright (c) 2025 Volker Schwaberow
+
+use unicode_bidi::BidiInfo;
+
+use crate::{Alignment, Table};
+
+/// Controls how bidirectional text is rendered.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BidiMode {
+    /// Cell content is printed in logical order.
+    #[default]
+    Off,
+    /// Every cell line is reordered for display with the Unicode bidi algorithm.
+    Reorder,
+    /// Cell lines are reordered and left and right column alignments are swapped,
+    /// for tables read from right to left.
+    ReorderMirrored,
+}
+
+/// Reorders every line of the text from logical to visual order.
+pub(crate) fn reorder(text: &str) -> String {
+    text.split('\n')
+        .map(|line| {
+            let info = BidiInfo::new(line, None);
+            info.paragraphs
+                .iter()
+                .map(|para| info.reorder_line(para, para.range.clone()))
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+impl BidiMode {
+    /// Returns the alignment to use for a cell under this mode.
+    pub(crate) fn align(&self, alignment: Alignment) -> Alignment {
+        match (self, alignment) {
+            (BidiMode::ReorderMirrored, Alignment::Left) => Alignment::Right,
+            (BidiMode::ReorderMirrored, Alignment::Right) => Alignment::Left,
+            _ => alignment,
+        }
+    }
+}
+
+impl Table {
+    /// Sets how bidirectional text such as Arabic or Hebrew is rendered.
+    pub fn set_bidi_mode(&mut self, mode: BidiMode) {
+        self.bidi = mode;
+        self.invalidate_widths();
+    }
+}
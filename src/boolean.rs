@@ -0,0 +1,88 @@
+// SPDX-License-Identifier: MIT
+// Project: tabprinter
+// File: src/boolean.rs
+// Author: Volker Schwaberow <volker@schwaberow.de>
+// Copyright (c) 2025 Volker Schwaberow
+
+use crate::color::Color;
+use crate::{Cell, ColumnRef, Table, TableError};
+
+/// Describes how the truth values of a boolean column are shown.
+/// Cells holding `yes`/`no`, `true`/`false`, `y`/`n`, `on`/`off`, or `1`/`0`
+/// are shown with the labels of the format; other cells are shown as they are.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BoolFormat {
+    /// `true` and `false`.
+    TrueFalse,
+    /// `yes` and `no`.
+    YesNo,
+    /// `✓` and `✗`.
+    Checkmark,
+    /// `on` and `off`.
+    OnOff,
+    /// The given labels for true and false values.
+    Custom(&'static str, &'static str),
+}
+
+impl BoolFormat {
+    /// Returns the label of the value.
+    fn label(&self, value: bool) -> &'static str {
+        let (yes, no) = match *self {
+            BoolFormat::TrueFalse => ("true", "false"),
+            BoolFormat::YesNo => ("yes", "no"),
+            BoolFormat::Checkmark => ("✓", "✗"),
+            BoolFormat::OnOff => ("on", "off"),
+            BoolFormat::Custom(yes, no) => (yes, no),
+        };
+        if value {
+            yes
+        } else {
+            no
+        }
+    }
+}
+
+/// Parses a truth value written as `yes`/`no`, `true`/`false`, `y`/`n`, `on`/`off`,
+/// or `1`/`0`, ignoring case and surrounding whitespace.
+pub(crate) fn parse_bool(text: &str) -> Option<bool> {
+    match text.trim().to_lowercase().as_str() {
+        "yes" | "y" | "true" | "on" | "1" => Some(true),
+        "no" | "n" | "false" | "off" | "0" => Some(false),
+        _ => None,
+    }
+}
+
+impl Table {
+    /// Shows the truth values of the specified column with the labels of the format,
+    /// such as `✓` and `✗`. If `colored` is true, true values are printed in green
+    /// and false values in red. The stored cells keep their content.
+    pub fn set_bool_format<'a>(
+        &mut self,
+        column: impl Into<ColumnRef<'a>>,
+        format: BoolFormat,
+        colored: bool,
+    ) -> Result<(), TableError> {
+        let column_index = self.column_position(column)?;
+        self.columns[column_index].bool_format = Some((format, colored));
+        self.invalidate_widths();
+        Ok(())
+    }
+
+    /// Returns the content of a data cell as labeled by the boolean format of its column.
+    pub(crate) fn bool_label(&self, column_index: usize, cell: &Cell) -> Option<&'static str> {
+        let (format, _) = self.columns[column_index].bool_format?;
+        if cell.is_header {
+            return None;
+        }
+        parse_bool(cell.resolved_content()).map(|value| format.label(value))
+    }
+
+    /// Returns the color of a data cell in a colored boolean column, if any.
+    pub(crate) fn bool_color(&self, column_index: usize, cell: &Cell) -> Option<Color> {
+        match self.columns[column_index].bool_format {
+            Some((_, true)) if !cell.is_header => parse_bool(cell.resolved_content())
+                .map(|value| if value { Color::Green } else { Color::Red }),
+            _ => None,
+        }
+    }
+}
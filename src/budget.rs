@@ -0,0 +1,190 @@
+// SPDX-License-Identifier: MIT
+// Project: tabprinter
+// File: src/budget.rs
+// Author: Volker Schwaberow <volker@schwaberow.de>
+// Copyright (c) 2025 Volker Schwaberow
+
+use std::io::{self, Write};
+
+use crate::color::{ColorChoice, ColorSpec, StandardStream, WriteColor};
+use crate::Table;
+
+/// Limits on the output of a printed table. Output stops at the last whole line
+/// within the limits and a summary of what was left out is printed instead.
+/// Limits that are `None` are not checked.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RenderBudget {
+    /// The most lines printed, not counting the summary line.
+    pub max_lines: Option<usize>,
+    /// The most bytes of text printed, not counting the summary line or color codes.
+    pub max_bytes: Option<usize>,
+}
+
+/// A piece of output held back until its line is known to fit the budget.
+enum Pending {
+    Text(Vec<u8>),
+    Color(ColorSpec),
+    Reset,
+}
+
+/// Passes whole lines on to the inner writer while they fit the budget and
+/// fails the first write after the budget is exhausted, which stops the renderer.
+struct BudgetWriter<'a> {
+    inner: &'a mut dyn WriteColor,
+    budget: RenderBudget,
+    lines: usize,
+    bytes: usize,
+    line: Vec<Pending>,
+    line_bytes: usize,
+    exhausted: bool,
+}
+
+impl BudgetWriter<'_> {
+    /// Returns the error ending the rendering once the budget is exhausted.
+    fn exhausted_error() -> io::Error {
+        io::Error::other("render budget exhausted")
+    }
+
+    /// Writes the held back line if it fits the budget, or marks the budget exhausted.
+    fn finish_line(&mut self) -> io::Result<()> {
+        let fits = self.budget.max_lines.is_none_or(|max| self.lines < max)
+            && self
+                .budget
+                .max_bytes
+                .is_none_or(|max| self.bytes + self.line_bytes <= max);
+        if !fits {
+            self.exhausted = true;
+            return Err(Self::exhausted_error());
+        }
+        self.flush_pending()?;
+        self.lines += 1;
+        self.bytes += self.line_bytes;
+        self.line_bytes = 0;
+        Ok(())
+    }
+
+    /// Writes what was held back after the last line break, such as the final reset.
+    fn flush_pending(&mut self) -> io::Result<()> {
+        for pending in self.line.drain(..) {
+            match pending {
+                Pending::Text(text) => self.inner.write_all(&text)?,
+                Pending::Color(spec) => self.inner.set_color(&spec)?,
+                Pending::Reset => self.inner.reset()?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Write for BudgetWriter<'_> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.exhausted {
+            return Err(Self::exhausted_error());
+        }
+        let mut rest = buf;
+        while !rest.is_empty() {
+            let end = rest
+                .iter()
+                .position(|&byte| byte == b'\n')
+                .map_or(rest.len(), |i| i + 1);
+            self.line.push(Pending::Text(rest[..end].to_vec()));
+            self.line_bytes += end;
+            if rest[end - 1] == b'\n' {
+                self.finish_line()?;
+            }
+            rest = &rest[end..];
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+impl WriteColor for BudgetWriter<'_> {
+    fn supports_color(&self) -> bool {
+        self.inner.supports_color()
+    }
+
+    fn set_color(&mut self, spec: &ColorSpec) -> io::Result<()> {
+        self.line.push(Pending::Color(spec.clone()));
+        Ok(())
+    }
+
+    fn reset(&mut self) -> io::Result<()> {
+        self.line.push(Pending::Reset);
+        Ok(())
+    }
+}
+
+/// Formats a count with commas between groups of three digits.
+fn group_digits(count: usize) -> String {
+    let digits = count.to_string();
+    let mut grouped = String::new();
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            grouped.push(',');
+        }
+        grouped.push(digit);
+    }
+    grouped
+}
+
+impl Table {
+    /// Prints the table to the writer within the budget. When the output would
+    /// exceed it, printing stops after the last whole line that fits and a line such
+    /// as `… output truncated, 9,000 rows omitted` follows, counting the data rows
+    /// not printed in full.
+    pub fn print_with_budget_to_writer(
+        &self,
+        writer: &mut dyn WriteColor,
+        budget: RenderBudget,
+    ) -> io::Result<()> {
+        let mut budgeted = BudgetWriter {
+            inner: writer,
+            budget,
+            lines: 0,
+            bytes: 0,
+            line: Vec::new(),
+            line_bytes: 0,
+            exhausted: false,
+        };
+        match self.print_color(&mut budgeted) {
+            Ok(()) => return budgeted.flush_pending(),
+            Err(_) if budgeted.exhausted => {}
+            Err(error) => return Err(error),
+        }
+        let printed = budgeted.lines;
+        writer.reset()?;
+        // Rows starting after the cutoff were never rendered, so they are not
+        // measured, which would compute their lazy cells.
+        let complete = self
+            .layout_within(printed)
+            .rows
+            .iter()
+            .filter(|lines| lines.end <= printed)
+            .count();
+        let omitted = self.rows.len() - complete;
+        match omitted {
+            0 => writeln!(writer, "{} output truncated", self.ellipsis()),
+            1 => writeln!(
+                writer,
+                "{} output truncated, 1 row omitted",
+                self.ellipsis()
+            ),
+            _ => writeln!(
+                writer,
+                "{} output truncated, {} rows omitted",
+                self.ellipsis(),
+                group_digits(omitted)
+            ),
+        }
+    }
+
+    /// Prints the table to the standard output within the budget.
+    pub fn print_with_budget(&self, budget: RenderBudget) -> io::Result<()> {
+        let mut stdout = StandardStream::stdout(ColorChoice::Always);
+        self.print_with_budget_to_writer(&mut stdout, budget)
+    }
+}
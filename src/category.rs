@@ -0,0 +1,55 @@
+// SPDX-License-Identifier: MIT
+// Project: tabprinter
+// File: src/category.rs
+// Author: Volker Schwaberow <volker@schwaberow.de>
+// Copyright (c) 2025 Volker Schwaberow
+
+use std::collections::HashMap;
+
+use crate::color::{Color, ColorSpec};
+use crate::{Cell, CellStyle, ColumnRef, Table, TableError};
+
+impl Table {
+    /// Gives the cells of the specified column holding one of the values the text
+    /// attributes of its style, such as a green `PASS` and a red `FAIL`, wherever they
+    /// appear in the column. The color, bold, italic, and underline of the style
+    /// replace those of the cell; padding, alignment, and number formats are kept.
+    /// Values are matched exactly. Replaces the styles set before.
+    pub fn set_category_styles<'a>(
+        &mut self,
+        column: impl Into<ColumnRef<'a>>,
+        styles: HashMap<String, CellStyle>,
+    ) -> Result<(), TableError> {
+        let column_index = self.column_position(column)?;
+        self.columns[column_index].category_styles = styles;
+        Ok(())
+    }
+
+    /// Returns the text attributes of a cell in the specified column on the given
+    /// background, including those given by the category styles and boolean format
+    /// of the column.
+    pub(crate) fn display_spec(
+        &self,
+        column_index: usize,
+        cell: &Cell,
+        background: Option<Color>,
+    ) -> ColorSpec {
+        let mut spec = Self::cell_spec(cell, background);
+        if cell.is_header {
+            return spec;
+        }
+        if let Some(style) = self.columns[column_index]
+            .category_styles
+            .get(cell.resolved_content())
+        {
+            spec.set_fg(style.color)
+                .set_bold(style.bold)
+                .set_italic(style.italic)
+                .set_underline(style.underline);
+        }
+        if let Some(color) = self.bool_color(column_index, cell) {
+            spec.set_fg(Some(color));
+        }
+        spec
+    }
+}
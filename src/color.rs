@@ -0,0 +1,405 @@
+// SPDX-License-Identifier: MIT
+// Project: tabprinter
+// File: src/color.rs
+// Author: Volker Schwaberow <volker@schwaberow.de>
+// Copyright (c) 2025 Volker Schwaberow
+
+use std::fmt;
+use std::io::{self, Write};
+use std::str::FromStr;
+
+/// A text or background color.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Color {
+    /// Black.
+    Black,
+    /// Blue.
+    Blue,
+    /// Green.
+    Green,
+    /// Red.
+    Red,
+    /// Cyan.
+    Cyan,
+    /// Magenta.
+    Magenta,
+    /// Yellow.
+    Yellow,
+    /// White.
+    White,
+    /// A color of the 256-color palette.
+    Ansi256(u8),
+    /// A true color given by its red, green, and blue components.
+    Rgb(u8, u8, u8),
+}
+
+/// The error returned when a color name cannot be parsed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseColorError(String);
+
+impl fmt::Display for ParseColorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unrecognized color `{}`", self.0)
+    }
+}
+
+impl std::error::Error for ParseColorError {}
+
+impl FromStr for Color {
+    type Err = ParseColorError;
+
+    /// Parses a color name such as `red`, a palette index such as `208`, or
+    /// components such as `255,128,0`, as `termcolor` does.
+    fn from_str(s: &str) -> Result<Color, ParseColorError> {
+        let error = || ParseColorError(s.to_string());
+        match s.to_lowercase().as_str() {
+            "black" => Ok(Color::Black),
+            "blue" => Ok(Color::Blue),
+            "green" => Ok(Color::Green),
+            "red" => Ok(Color::Red),
+            "cyan" => Ok(Color::Cyan),
+            "magenta" => Ok(Color::Magenta),
+            "yellow" => Ok(Color::Yellow),
+            "white" => Ok(Color::White),
+            name => {
+                let parts: Vec<&str> = name.split(',').map(str::trim).collect();
+                match parts.as_slice() {
+                    [index] => index.parse().map(Color::Ansi256).map_err(|_| error()),
+                    [r, g, b] => {
+                        let component = |part: &str| part.parse::<u8>().map_err(|_| error());
+                        Ok(Color::Rgb(component(r)?, component(g)?, component(b)?))
+                    }
+                    _ => Err(error()),
+                }
+            }
+        }
+    }
+}
+
+/// The colors and attributes of text.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ColorSpec {
+    /// The text color, if any.
+    fg: Option<Color>,
+    /// The background color, if any.
+    bg: Option<Color>,
+    /// Whether the text is bold.
+    bold: bool,
+    /// Whether the text is italic.
+    italic: bool,
+    /// Whether the text is underlined.
+    underline: bool,
+    /// Whether the text is dimmed.
+    dimmed: bool,
+    /// Whether the colors are the intense variants.
+    intense: bool,
+}
+
+impl ColorSpec {
+    /// Creates a specification without colors or attributes.
+    pub fn new() -> ColorSpec {
+        ColorSpec::default()
+    }
+
+    /// Returns the text color, if any.
+    pub fn fg(&self) -> Option<&Color> {
+        self.fg.as_ref()
+    }
+
+    /// Sets the text color.
+    pub fn set_fg(&mut self, color: Option<Color>) -> &mut ColorSpec {
+        self.fg = color;
+        self
+    }
+
+    /// Returns the background color, if any.
+    pub fn bg(&self) -> Option<&Color> {
+        self.bg.as_ref()
+    }
+
+    /// Sets the background color.
+    pub fn set_bg(&mut self, color: Option<Color>) -> &mut ColorSpec {
+        self.bg = color;
+        self
+    }
+
+    /// Returns whether the text is bold.
+    pub fn bold(&self) -> bool {
+        self.bold
+    }
+
+    /// Sets whether the text is bold.
+    pub fn set_bold(&mut self, yes: bool) -> &mut ColorSpec {
+        self.bold = yes;
+        self
+    }
+
+    /// Returns whether the text is italic.
+    pub fn italic(&self) -> bool {
+        self.italic
+    }
+
+    /// Sets whether the text is italic.
+    pub fn set_italic(&mut self, yes: bool) -> &mut ColorSpec {
+        self.italic = yes;
+        self
+    }
+
+    /// Returns whether the text is underlined.
+    pub fn underline(&self) -> bool {
+        self.underline
+    }
+
+    /// Sets whether the text is underlined.
+    pub fn set_underline(&mut self, yes: bool) -> &mut ColorSpec {
+        self.underline = yes;
+        self
+    }
+
+    /// Returns whether the text is dimmed.
+    pub fn dimmed(&self) -> bool {
+        self.dimmed
+    }
+
+    /// Sets whether the text is dimmed.
+    pub fn set_dimmed(&mut self, yes: bool) -> &mut ColorSpec {
+        self.dimmed = yes;
+        self
+    }
+
+    /// Returns whether the colors are the intense variants.
+    pub fn intense(&self) -> bool {
+        self.intense
+    }
+
+    /// Sets whether the colors are the intense variants.
+    pub fn set_intense(&mut self, yes: bool) -> &mut ColorSpec {
+        self.intense = yes;
+        self
+    }
+
+    /// Returns whether the specification has no colors or attributes.
+    pub fn is_none(&self) -> bool {
+        *self == ColorSpec::default()
+    }
+
+    /// Removes all colors and attributes.
+    pub fn clear(&mut self) {
+        *self = ColorSpec::default();
+    }
+}
+
+/// Whether colors are written, accepted for compatibility with `termcolor`.
+/// Builds without the `color` feature never write colors.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ColorChoice {
+    /// Always write colors.
+    Always,
+    /// Always write colors as ANSI escape codes.
+    AlwaysAnsi,
+    /// Write colors when the output is a terminal.
+    Auto,
+    /// Never write colors.
+    Never,
+}
+
+/// A writer that can be told to change the colors of the text written next.
+/// Only the writers `termcolor` also accepts implement it, so code built without
+/// the `color` feature keeps compiling when another crate turns it on; wrap any
+/// other writer in `NoColor`.
+pub trait WriteColor: Write {
+    /// Returns whether the writer shows colors.
+    fn supports_color(&self) -> bool;
+
+    /// Sets the colors of the text written next.
+    fn set_color(&mut self, spec: &ColorSpec) -> io::Result<()>;
+
+    /// Restores the default colors.
+    fn reset(&mut self) -> io::Result<()>;
+}
+
+/// Implements `WriteColor` for writers that ignore colors.
+macro_rules! impl_plain_write_color {
+    ($($writer:ty),* $(,)?) => {
+        $(
+            impl WriteColor for $writer {
+                fn supports_color(&self) -> bool {
+                    false
+                }
+
+                fn set_color(&mut self, _spec: &ColorSpec) -> io::Result<()> {
+                    Ok(())
+                }
+
+                fn reset(&mut self) -> io::Result<()> {
+                    Ok(())
+                }
+            }
+        )*
+    };
+}
+
+impl_plain_write_color!(io::Sink, StandardStream, Buffer);
+
+impl<T: WriteColor + ?Sized> WriteColor for &mut T {
+    fn supports_color(&self) -> bool {
+        (**self).supports_color()
+    }
+
+    fn set_color(&mut self, spec: &ColorSpec) -> io::Result<()> {
+        (**self).set_color(spec)
+    }
+
+    fn reset(&mut self) -> io::Result<()> {
+        (**self).reset()
+    }
+}
+
+impl<T: WriteColor + ?Sized> WriteColor for Box<T> {
+    fn supports_color(&self) -> bool {
+        (**self).supports_color()
+    }
+
+    fn set_color(&mut self, spec: &ColorSpec) -> io::Result<()> {
+        (**self).set_color(spec)
+    }
+
+    fn reset(&mut self) -> io::Result<()> {
+        (**self).reset()
+    }
+}
+
+/// Makes any writer a `WriteColor` that ignores colors.
+#[derive(Clone, Debug)]
+pub struct NoColor<W>(W);
+
+impl<W: Write> NoColor<W> {
+    /// Wraps the writer.
+    pub fn new(writer: W) -> NoColor<W> {
+        NoColor(writer)
+    }
+
+    /// Returns the wrapped writer.
+    pub fn into_inner(self) -> W {
+        self.0
+    }
+
+    /// Returns a reference to the wrapped writer.
+    pub fn get_ref(&self) -> &W {
+        &self.0
+    }
+
+    /// Returns a mutable reference to the wrapped writer.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.0
+    }
+}
+
+impl<W: Write> Write for NoColor<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.0.flush()
+    }
+}
+
+impl<W: Write> WriteColor for NoColor<W> {
+    fn supports_color(&self) -> bool {
+        false
+    }
+
+    fn set_color(&mut self, _spec: &ColorSpec) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn reset(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// The standard output or error stream.
+pub struct StandardStream {
+    /// The stream written to.
+    inner: Box<dyn Write + Send>,
+}
+
+impl StandardStream {
+    /// Creates a writer to the standard output.
+    pub fn stdout(_choice: ColorChoice) -> StandardStream {
+        StandardStream {
+            inner: Box::new(io::stdout()),
+        }
+    }
+
+    /// Creates a writer to the standard error.
+    pub fn stderr(_choice: ColorChoice) -> StandardStream {
+        StandardStream {
+            inner: Box::new(io::stderr()),
+        }
+    }
+}
+
+impl Write for StandardStream {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.inner.write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// Text collected in memory.
+#[derive(Clone, Debug, Default)]
+pub struct Buffer(Vec<u8>);
+
+impl Buffer {
+    /// Creates an empty buffer.
+    pub fn no_color() -> Buffer {
+        Buffer::default()
+    }
+
+    /// Creates an empty buffer; without the `color` feature it holds no escape codes.
+    pub fn ansi() -> Buffer {
+        Buffer::default()
+    }
+
+    /// Returns the number of bytes written so far.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns whether nothing was written so far.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the text written so far.
+    pub fn as_slice(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// Returns the text written so far, consuming the buffer.
+    pub fn into_inner(self) -> Vec<u8> {
+        self.0
+    }
+
+    /// Removes the text written so far.
+    pub fn clear(&mut self) {
+        self.0.clear();
+    }
+}
+
+impl Write for Buffer {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
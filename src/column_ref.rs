@@ -0,0 +1,89 @@
+// SPDX-License-Identifier: MIT
+// Project: tabprinter
+// File: src/column_ref.rs
+// Author: Volker Schwaberow <volker@schwaberow.de>
+// Copyright (c) 2025 Volker Schwaberow
+
+use crate::{Table, TableError};
+
+/// Refers to a column by its position or by its header.
+/// Operations taking a column accept either, so `table.sum_column("Amount")`
+/// stays correct when columns are added or reordered. Setters and operations
+/// taking several columns fail with `TableError::UnknownColumn` on an unknown
+/// header; sorting, ranking and the aggregation shortcuts panic instead.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ColumnRef<'a> {
+    /// The position of the column, starting at 0.
+    Index(usize),
+    /// The header of the column. The first column with the header is used.
+    Name(&'a str),
+}
+
+impl From<usize> for ColumnRef<'_> {
+    fn from(index: usize) -> Self {
+        ColumnRef::Index(index)
+    }
+}
+
+impl From<&usize> for ColumnRef<'_> {
+    fn from(index: &usize) -> Self {
+        ColumnRef::Index(*index)
+    }
+}
+
+impl<'a> From<&'a str> for ColumnRef<'a> {
+    fn from(name: &'a str) -> Self {
+        ColumnRef::Name(name)
+    }
+}
+
+impl<'a> From<&'a String> for ColumnRef<'a> {
+    fn from(name: &'a String) -> Self {
+        ColumnRef::Name(name)
+    }
+}
+
+impl Table {
+    /// Returns the position of the referenced column, or an error if the index is
+    /// out of bounds or no column has the header.
+    pub fn column_position<'a>(
+        &self,
+        column: impl Into<ColumnRef<'a>>,
+    ) -> Result<usize, TableError> {
+        match column.into() {
+            ColumnRef::Index(index) => self.check_column(index).map(|()| index),
+            ColumnRef::Name(name) => self
+                .columns
+                .iter()
+                .position(|column| column.header == name)
+                .ok_or_else(|| TableError::UnknownColumn(name.to_string())),
+        }
+    }
+
+    /// Returns the positions of the referenced columns, in the order given, or the
+    /// error for the first column that does not exist.
+    pub(crate) fn column_positions<'a, C: Into<ColumnRef<'a>>>(
+        &self,
+        columns: impl IntoIterator<Item = C>,
+    ) -> Result<Vec<usize>, TableError> {
+        columns
+            .into_iter()
+            .map(|column| self.column_position(column))
+            .collect()
+    }
+
+    /// Returns the position of the referenced column for operations that panic on
+    /// a bad column. Indexes are returned unchecked.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no column has the header.
+    pub(crate) fn resolve_column<'a>(&self, column: impl Into<ColumnRef<'a>>) -> usize {
+        match column.into() {
+            ColumnRef::Index(index) => index,
+            ColumnRef::Name(name) => self
+                .column_position(name)
+                .unwrap_or_else(|error| panic!("{}", error)),
+        }
+    }
+}
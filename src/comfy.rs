@@ -0,0 +1,72 @@
+// SPDX-License-Identifier: MIT
+// Project: tabprinter
+// File: src/comfy.rs
+// Author: Volker Schwaberow <volker@schwaberow.de>
+// Copyright (c) 2025 Volker Schwaberow
+
+use comfy_table::{presets, CellAlignment};
+
+use crate::{Alignment, Cell, Table, TableStyle};
+
+impl From<Alignment> for CellAlignment {
+    fn from(alignment: Alignment) -> Self {
+        match alignment {
+            Alignment::Left => CellAlignment::Left,
+            Alignment::Center => CellAlignment::Center,
+            Alignment::Right => CellAlignment::Right,
+        }
+    }
+}
+
+impl Table {
+    /// Converts a row to comfy-table cells, keeping the alignment of cells that
+    /// differ from their column.
+    fn comfy_cells(&self, row: &[Cell]) -> Vec<comfy_table::Cell> {
+        row.iter()
+            .enumerate()
+            .map(|(i, cell)| {
+                let converted = comfy_table::Cell::new(self.cell_text(i, cell));
+                match cell.style.alignment {
+                    Some(alignment) => converted.set_alignment(alignment.into()),
+                    None => converted,
+                }
+            })
+            .collect()
+    }
+}
+
+/// Converts a table to a comfy-table table with the same headers, rows, footer,
+/// and column alignments, for code that still renders with comfy-table.
+/// Cells hold the text tabprinter would show, after masking and tab expansion.
+/// The style is mapped to the closest comfy-table preset; widths and colors
+/// are left to comfy-table.
+impl From<&Table> for comfy_table::Table {
+    fn from(table: &Table) -> Self {
+        let mut converted = comfy_table::Table::new();
+        converted.load_style(match table.style {
+            TableStyle::Markdown => presets::ASCII_MARKDOWN,
+            TableStyle::Simple | TableStyle::Accessible => presets::NOTHING,
+            TableStyle::Grid => presets::ASCII_FULL_CONDENSED,
+            _ => presets::UTF8_FULL_CONDENSED,
+        });
+        converted.set_header(
+            table
+                .columns
+                .iter()
+                .map(|column| column.header.clone())
+                .collect::<Vec<_>>(),
+        );
+        for row in &table.rows {
+            converted.add_row(table.comfy_cells(row));
+        }
+        if let Some(footer) = &table.footer {
+            converted.add_row(table.comfy_cells(footer));
+        }
+        for (i, column) in table.columns.iter().enumerate() {
+            if let Some(target) = converted.column_mut(i) {
+                target.set_cell_alignment(column.alignment.into());
+            }
+        }
+        converted
+    }
+}
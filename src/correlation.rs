@@ -0,0 +1,87 @@
+// SPDX-License-Identifier: MIT
+// Project: tabprinter
+// File: src/correlation.rs
+// Author: Volker Schwaberow <volker@schwaberow.de>
+// Copyright (c) 2025 Volker Schwaberow
+
+use crate::gradient::Rgb;
+use crate::{Alignment, Cell, ColumnRef, ColumnWidth, Table, TableError};
+
+/// The color of coefficients near zero.
+const NEUTRAL: Rgb = Rgb::new(160, 160, 160);
+/// The color of a perfect positive correlation.
+const POSITIVE: Rgb = Rgb::new(220, 50, 47);
+/// The color of a perfect negative correlation.
+const NEGATIVE: Rgb = Rgb::new(38, 139, 210);
+
+/// Returns the Pearson correlation coefficient of the finite pairs, or `None` when
+/// there are fewer than two of them or either side does not vary.
+fn pearson(pairs: &[(f64, f64)]) -> Option<f64> {
+    let pairs: Vec<(f64, f64)> = pairs
+        .iter()
+        .copied()
+        .filter(|(x, y)| x.is_finite() && y.is_finite())
+        .collect();
+    if pairs.len() < 2 {
+        return None;
+    }
+    let n = pairs.len() as f64;
+    let mean_x = pairs.iter().map(|(x, _)| x).sum::<f64>() / n;
+    let mean_y = pairs.iter().map(|(_, y)| y).sum::<f64>() / n;
+    let (mut covariance, mut variance_x, mut variance_y) = (0.0, 0.0, 0.0);
+    for (x, y) in &pairs {
+        covariance += (x - mean_x) * (y - mean_y);
+        variance_x += (x - mean_x).powi(2);
+        variance_y += (y - mean_y).powi(2);
+    }
+    if variance_x == 0.0 || variance_y == 0.0 {
+        return None;
+    }
+    Some((covariance / (variance_x * variance_y).sqrt()).clamp(-1.0, 1.0))
+}
+
+/// Returns the heatmap color of the coefficient.
+fn heat(coefficient: f64) -> Rgb {
+    if coefficient < 0.0 {
+        NEUTRAL.lerp(NEGATIVE, -coefficient)
+    } else {
+        NEUTRAL.lerp(POSITIVE, coefficient)
+    }
+}
+
+impl Table {
+    /// Returns a square table of the pairwise Pearson correlation coefficients of the
+    /// specified columns, given by index or header, with the coefficients colored from
+    /// blue for -1 through gray for 0 to red for 1. Each pair is computed over the rows
+    /// where both cells are numbers; pairs without enough varying values are left empty.
+    /// Fails if a column does not exist.
+    pub fn correlation_matrix<'a, C: Into<ColumnRef<'a>>>(
+        &self,
+        columns: impl IntoIterator<Item = C>,
+    ) -> Result<Table, TableError> {
+        let columns = self.column_positions(columns)?;
+        let mut table = Table::new(self.style);
+        table.add_column("", ColumnWidth::Auto, Alignment::Left);
+        for &column_index in &columns {
+            let header = &self.columns[column_index].header;
+            table.add_column(header, ColumnWidth::Auto, Alignment::Right);
+        }
+        for &a in &columns {
+            let mut row = vec![Cell::new(&self.columns[a].header)];
+            for &b in &columns {
+                let cell = match pearson(&self.numeric_pairs(a, b)) {
+                    Some(coefficient) => {
+                        let mut cell = Cell::new(&coefficient.to_string());
+                        cell.style.decimal_places = Some(2);
+                        cell.style.color = Some(heat(coefficient).into());
+                        cell
+                    }
+                    None => Cell::new(""),
+                };
+                row.push(cell);
+            }
+            table.add_row(row);
+        }
+        Ok(table)
+    }
+}
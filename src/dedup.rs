@@ -0,0 +1,81 @@
+// SPDX-License-Identifier: MIT
+// Project: tabprinter
+// File: src/dedup.rs
+// Author: Volker Schwaberow <volker@schwaberow.de>
+// Copyright (c) 2025 Volker Schwaberow
+
+use std::collections::{HashMap, HashSet};
+
+use crate::{Alignment, Cell, ColumnRef, ColumnWidth, Row, Table};
+
+impl Table {
+    /// Removes rows whose contents equal those of an earlier row.
+    /// The first occurrence of every row is kept in place.
+    pub fn dedup_rows(&mut self) {
+        let mut seen = HashSet::new();
+        let mut removed = Vec::new();
+        self.rows.retain(|row| {
+            let contents: Vec<String> = row.iter().map(|cell| cell.content.clone()).collect();
+            let keep = seen.insert(contents);
+            if !keep {
+                removed.push(row.id);
+            }
+            keep
+        });
+        for id in removed {
+            self.row_tags.remove(&id);
+        }
+        self.separators.clear();
+        self.merges.clear();
+        self.invalidate_widths();
+    }
+
+    /// Returns a new table with only the first row for every value of the specified column.
+    pub fn distinct_by_column<'a>(&self, column: impl Into<ColumnRef<'a>>) -> Self {
+        let column_index = self.resolve_column(column);
+        let mut seen = HashSet::new();
+        let rows: Vec<Row> = self
+            .rows
+            .iter()
+            .filter(|row| seen.insert(row[column_index].content.as_str()))
+            .cloned()
+            .collect();
+        self.derive_with_rows(rows)
+    }
+
+    /// Returns a two-column table with every distinct value of the specified column
+    /// and the number of rows holding it, most frequent first.
+    /// Values occurring equally often keep the order in which they first appear.
+    /// The values stay masked if the column is masked.
+    pub fn count_duplicates<'a>(&self, column: impl Into<ColumnRef<'a>>) -> Table {
+        let column_index = self.resolve_column(column);
+        let mut table = Table::new(self.style);
+        let column = &self.columns[column_index];
+        table.add_column(&column.header, ColumnWidth::Auto, column.alignment);
+        table.add_column("Count", ColumnWidth::Auto, Alignment::Right);
+        table.columns[0].mask = column.mask;
+        for (value, count) in self.distinct_counts(column_index) {
+            table.add_row(vec![Cell::new(value), Cell::new(&count.to_string())]);
+        }
+        table
+    }
+
+    /// Returns every distinct value of the specified column with the number of rows
+    /// holding it, most frequent first and otherwise in order of first appearance.
+    pub(crate) fn distinct_counts(&self, column_index: usize) -> Vec<(&str, usize)> {
+        let mut counts: Vec<(&str, usize)> = Vec::new();
+        let mut positions: HashMap<&str, usize> = HashMap::new();
+        for row in &self.rows {
+            let value = row[column_index].content.as_str();
+            match positions.get(value) {
+                Some(&position) => counts[position].1 += 1,
+                None => {
+                    positions.insert(value, counts.len());
+                    counts.push((value, 1));
+                }
+            }
+        }
+        counts.sort_by_key(|&(_, count)| std::cmp::Reverse(count));
+        counts
+    }
+}
@@ -0,0 +1,157 @@
+// SPDX-License-Identifier: MIT
+// Project: tabprinter
+// File: src/edit.rs
+// Author: Volker Schwaberow <volker@schwaberow.de>
+// Copyright (c) 2025 Volker Schwaberow
+
+use crate::{Cell, ColumnRef, Row, Table, TableError};
+
+impl Table {
+    /// Returns an error unless the row index is within the table.
+    pub(crate) fn check_row(&self, row_index: usize) -> Result<(), TableError> {
+        if row_index < self.rows.len() {
+            Ok(())
+        } else {
+            Err(TableError::RowOutOfBounds {
+                index: row_index,
+                len: self.rows.len(),
+            })
+        }
+    }
+
+    /// Returns an error unless the column index is within the table.
+    pub(crate) fn check_column(&self, column_index: usize) -> Result<(), TableError> {
+        if column_index < self.columns.len() {
+            Ok(())
+        } else {
+            Err(TableError::ColumnOutOfBounds {
+                index: column_index,
+                len: self.columns.len(),
+            })
+        }
+    }
+
+    /// Replaces the cell at the specified row and column.
+    /// Automatic column widths are measured again on the next render.
+    pub fn set_cell<'a>(
+        &mut self,
+        row_index: usize,
+        column: impl Into<ColumnRef<'a>>,
+        cell: impl Into<Cell>,
+    ) -> Result<(), TableError> {
+        self.check_row(row_index)?;
+        let column_index = self.column_position(column)?;
+        self.rows[row_index][column_index] = cell.into();
+        self.invalidate_widths();
+        Ok(())
+    }
+
+    /// Applies the function to every cell of the specified column, such as to convert
+    /// units or redact values after an import.
+    /// Automatic column widths are measured again on the next render.
+    pub fn map_column<'a, F>(
+        &mut self,
+        column: impl Into<ColumnRef<'a>>,
+        mut f: F,
+    ) -> Result<(), TableError>
+    where
+        F: FnMut(&mut Cell),
+    {
+        let column_index = self.column_position(column)?;
+        for row in self.rows.iter_mut() {
+            f(&mut row[column_index]);
+        }
+        self.invalidate_widths();
+        Ok(())
+    }
+
+    /// Inserts a row at the specified position, moving the rows after it down.
+    /// The position may be the number of rows, which appends the row. Fails, leaving
+    /// the table unchanged, if the position is beyond the end, or if the row does not
+    /// have one cell per column or is rejected by the row validator.
+    /// Separators stay with the rows they precede; merged blocks the row lands
+    /// inside are undone.
+    pub fn insert_row(&mut self, row_index: usize, row: Vec<Cell>) -> Result<(), TableError> {
+        if row_index > self.rows.len() {
+            return Err(TableError::RowOutOfBounds {
+                index: row_index,
+                len: self.rows.len(),
+            });
+        }
+        self.check_new_row(&row, row_index)?;
+        let appended = row_index == self.rows.len();
+        let id = self.allocate_row_id();
+        self.rows.insert(row_index, Row { id, cells: row });
+        for separator in &mut self.separators {
+            if *separator > row_index || (*separator == row_index && !appended) {
+                *separator += 1;
+            }
+        }
+        self.merges.retain_mut(|merge| {
+            if merge.rows.start >= row_index {
+                merge.rows = merge.rows.start + 1..merge.rows.end + 1;
+            }
+            !merge.rows.contains(&row_index)
+        });
+        self.invalidate_widths();
+        Ok(())
+    }
+
+    /// Removes the row at the specified position and returns its cells, moving the
+    /// rows after it up. The tag of the row is dropped and merged blocks containing it
+    /// are undone.
+    pub fn remove_row(&mut self, row_index: usize) -> Result<Vec<Cell>, TableError> {
+        self.check_row(row_index)?;
+        let row = self.rows.remove(row_index);
+        self.row_tags.remove(&row.id);
+        for separator in &mut self.separators {
+            if *separator > row_index {
+                *separator -= 1;
+            }
+        }
+        self.separators.dedup();
+        self.merges.retain_mut(|merge| {
+            if merge.rows.contains(&row_index) {
+                return false;
+            }
+            if merge.rows.start > row_index {
+                merge.rows = merge.rows.start - 1..merge.rows.end - 1;
+            }
+            true
+        });
+        self.invalidate_widths();
+        Ok(row.cells)
+    }
+
+    /// Swaps the rows at the specified positions. Separators stay in place and
+    /// merged blocks containing either row are undone.
+    pub fn swap_rows(&mut self, a: usize, b: usize) -> Result<(), TableError> {
+        self.check_row(a)?;
+        self.check_row(b)?;
+        if a != b {
+            self.rows.swap(a, b);
+            self.invalidate_numbers();
+            self.merges
+                .retain(|merge| !merge.rows.contains(&a) && !merge.rows.contains(&b));
+        }
+        Ok(())
+    }
+
+    /// Keeps the first `len` rows and removes the rest, with their tags, separators
+    /// and merged blocks. Fails if the table has fewer than `len` rows.
+    pub fn truncate_rows(&mut self, len: usize) -> Result<(), TableError> {
+        if len > self.rows.len() {
+            return Err(TableError::RowOutOfBounds {
+                index: len,
+                len: self.rows.len(),
+            });
+        }
+        for row in self.rows.drain(len..) {
+            self.row_tags.remove(&row.id);
+        }
+        self.separators.retain(|&separator| separator < len);
+        self.merges.retain(|merge| merge.rows.end <= len);
+        self.invalidate_widths();
+        Ok(())
+    }
+}
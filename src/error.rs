@@ -0,0 +1,234 @@
+// SPDX-License-Identifier: MIT
+// Project: tabprinter
+// File: src/error.rs
+// Author: Volker Schwaberow <volker@schwaberow.de>
+// Copyright (c) 2025 Volker Schwaberow
+
+use std::fmt;
+use std::io;
+
+/// Errors returned by fallible table operations.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TableError {
+    /// The row index is not within the table.
+    RowOutOfBounds {
+        /// The requested row index.
+        index: usize,
+        /// The number of rows in the table.
+        len: usize,
+    },
+    /// The column index is not within the table.
+    ColumnOutOfBounds {
+        /// The requested column index.
+        index: usize,
+        /// The number of columns in the table.
+        len: usize,
+    },
+    /// No column has the header.
+    UnknownColumn(String),
+    /// No aggregator is registered under the name.
+    UnknownAggregator(String),
+    /// A cell could not be parsed as the type of its column.
+    Parse {
+        /// The position of the row.
+        row: usize,
+        /// The header of the column.
+        column: String,
+        /// The content of the cell.
+        value: String,
+        /// Why the content could not be parsed.
+        reason: String,
+    },
+    /// An expression could not be parsed.
+    InvalidExpression {
+        /// The expression as given.
+        expression: String,
+        /// What is wrong with the expression.
+        reason: String,
+    },
+    /// A row was rejected by the row validator or does not fit the columns.
+    InvalidRow {
+        /// The position the row has or would have had.
+        row: usize,
+        /// Why the row was rejected.
+        reason: String,
+    },
+    /// A block of cells cannot be merged.
+    InvalidMerge(String),
+    /// Columns cannot be split or combined as requested.
+    InvalidColumns(String),
+    /// Content is wider than its column under `OverflowPolicy::Error`.
+    ContentTooWide {
+        /// The position of the data row, or `None` for the header or footer.
+        row: Option<usize>,
+        /// The header of the column.
+        column: String,
+        /// The display width of the content.
+        width: usize,
+        /// The width available inside the padding of the column.
+        available: usize,
+    },
+    /// A column width is too small to leave room for any content.
+    ColumnTooNarrow {
+        /// The header of the column.
+        column: String,
+        /// The requested width.
+        width: usize,
+        /// The smallest width the column accepts.
+        minimum: usize,
+    },
+    /// A style or theme could not be parsed.
+    InvalidStyle(String),
+    /// An environment variable read by the table has an invalid value.
+    InvalidEnvironment {
+        /// The name of the variable.
+        variable: String,
+        /// What is wrong with the value.
+        reason: String,
+    },
+    /// JSON input is malformed or not made of objects.
+    InvalidJson(String),
+    /// Markdown text is not a well-formed table.
+    InvalidMarkdown {
+        /// The line number, starting at 1.
+        line: usize,
+        /// What is wrong with the line.
+        reason: String,
+    },
+}
+
+impl fmt::Display for TableError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TableError::RowOutOfBounds { index, len } => {
+                write!(
+                    f,
+                    "row {} is out of bounds for a table with {} rows",
+                    index, len
+                )
+            }
+            TableError::ColumnOutOfBounds { index, len } => write!(
+                f,
+                "column {} is out of bounds for a table with {} columns",
+                index, len
+            ),
+            TableError::UnknownColumn(header) => write!(f, "no column has the header `{}`", header),
+            TableError::UnknownAggregator(name) => {
+                write!(f, "no aggregator is registered as `{}`", name)
+            }
+            TableError::Parse {
+                row,
+                column,
+                value,
+                reason,
+            } => write!(
+                f,
+                "row {}, column `{}`: cannot parse `{}`: {}",
+                row, column, value, reason
+            ),
+            TableError::InvalidExpression { expression, reason } => {
+                write!(f, "invalid expression `{}`: {}", expression, reason)
+            }
+            TableError::InvalidRow { row, reason } => {
+                write!(f, "row {} is invalid: {}", row, reason)
+            }
+            TableError::InvalidMerge(reason) => write!(f, "cannot merge cells: {}", reason),
+            TableError::InvalidColumns(reason) => write!(f, "invalid columns: {}", reason),
+            TableError::ContentTooWide {
+                row,
+                column,
+                width,
+                available,
+            } => {
+                let row = match row {
+                    Some(row) => format!("row {}", row),
+                    None => "the header or footer".to_string(),
+                };
+                write!(
+                    f,
+                    "{}, column `{}`: content is {} characters wide but only {} fit",
+                    row, column, width, available
+                )
+            }
+            TableError::ColumnTooNarrow {
+                column,
+                width,
+                minimum,
+            } => write!(
+                f,
+                "column `{}` cannot be {} characters wide, it needs at least {}",
+                column, width, minimum
+            ),
+            TableError::InvalidStyle(reason) => write!(f, "invalid style: {}", reason),
+            TableError::InvalidEnvironment { variable, reason } => {
+                write!(f, "invalid `{}`: {}", variable, reason)
+            }
+            TableError::InvalidJson(reason) => write!(f, "invalid JSON table: {}", reason),
+            TableError::InvalidMarkdown { line, reason } => {
+                write!(f, "invalid Markdown table on line {}: {}", line, reason)
+            }
+        }
+    }
+}
+
+impl std::error::Error for TableError {}
+
+/// Errors returned by strict column aggregations such as `try_sum_column`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AggregateError {
+    /// The column index is not within the table.
+    ColumnOutOfBounds {
+        /// The requested column index.
+        index: usize,
+        /// The number of columns in the table.
+        len: usize,
+    },
+    /// Cells could not be parsed as numbers.
+    Unparsable {
+        /// The position and content of every unparsable cell.
+        cells: Vec<(usize, String)>,
+    },
+    /// Cells hold `NaN`, which has no order and spoils sums.
+    NotANumber {
+        /// The positions of the rows.
+        rows: Vec<usize>,
+    },
+    /// No column has the header.
+    UnknownColumn(String),
+    /// The column has no values to aggregate.
+    Empty,
+}
+
+impl fmt::Display for AggregateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AggregateError::ColumnOutOfBounds { index, len } => write!(
+                f,
+                "column {} is out of bounds for a table with {} columns",
+                index, len
+            ),
+            AggregateError::Unparsable { cells } => {
+                write!(f, "{} cells are not numbers:", cells.len())?;
+                for (row, value) in cells {
+                    write!(f, " row {} `{}`", row, value)?;
+                }
+                Ok(())
+            }
+            AggregateError::NotANumber { rows } => {
+                write!(f, "{} cells are NaN: rows {:?}", rows.len(), rows)
+            }
+            AggregateError::UnknownColumn(header) => {
+                write!(f, "no column has the header `{}`", header)
+            }
+            AggregateError::Empty => write!(f, "the column has no values"),
+        }
+    }
+}
+
+impl std::error::Error for AggregateError {}
+
+impl From<TableError> for io::Error {
+    fn from(error: TableError) -> Self {
+        io::Error::new(io::ErrorKind::InvalidInput, error)
+    }
+}
@@ -0,0 +1,613 @@
+// SPDX-License-Identifier: MIT
+// Project: tabprinter
+// File: src/export.rs
+// Author: Volker Schwaberow <volker@schwaberow.de>
+// Copyright (c) 2025 Volker Schwaberow
+
+use crate::color::Color;
+use crate::text::{text_width, truncate};
+use crate::{Alignment, Cell, ColumnWidth, Table, TableError, TableStyle};
+
+/// The number of rows above which `to_github_summary` collapses the table.
+pub const GITHUB_SUMMARY_COLLAPSE_ROWS: usize = 20;
+
+/// Escapes text for use inside a Markdown table cell.
+fn escape_markdown(text: &str) -> String {
+    text.replace('|', "\\|").replace('\n', "<br>")
+}
+
+/// Returns the Markdown delimiter cell for the alignment.
+fn markdown_delimiter(alignment: Alignment) -> &'static str {
+    match alignment {
+        Alignment::Left => ":---",
+        Alignment::Center => ":---:",
+        Alignment::Right => "---:",
+    }
+}
+
+/// Splits a Markdown table row into its cells, undoing the escapes of `escape_markdown`.
+/// The outer pipes are optional.
+fn split_markdown_row(line: &str) -> Vec<String> {
+    let line = line.trim();
+    let line = line.strip_prefix('|').unwrap_or(line);
+    let line = match line.strip_suffix('|') {
+        Some(rest) if !rest.ends_with('\\') => rest,
+        _ => line,
+    };
+    let mut cells = Vec::new();
+    let mut cell = String::new();
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' if chars.peek() == Some(&'|') => {
+                cell.push('|');
+                chars.next();
+            }
+            '|' => cells.push(std::mem::take(&mut cell)),
+            _ => cell.push(c),
+        }
+    }
+    cells.push(cell);
+    cells
+        .iter()
+        .map(|cell| cell.trim().replace("<br>", "\n"))
+        .collect()
+}
+
+/// Parses a Markdown delimiter cell such as `:---:` into its alignment.
+fn parse_markdown_delimiter(cell: &str) -> Option<Alignment> {
+    let dashes = cell.trim_start_matches(':').trim_end_matches(':');
+    if dashes.is_empty() || !dashes.chars().all(|c| c == '-') {
+        return None;
+    }
+    Some(match (cell.starts_with(':'), cell.ends_with(':')) {
+        (true, true) => Alignment::Center,
+        (false, true) => Alignment::Right,
+        _ => Alignment::Left,
+    })
+}
+
+/// Formats cells as the lines of a reStructuredText simple table row. Every cell is
+/// padded to its width by its alignment; an empty first cell is written as an empty
+/// comment, since a blank first column would continue the previous row.
+fn rst_row(cells: &[String], widths: &[usize], alignments: &[Alignment]) -> Vec<String> {
+    let line_count = cells
+        .iter()
+        .map(|cell| cell.lines().count())
+        .max()
+        .unwrap_or(0);
+    (0..line_count.max(1))
+        .map(|line_index| {
+            let line: Vec<String> = cells
+                .iter()
+                .zip(widths.iter().zip(alignments))
+                .enumerate()
+                .map(|(i, (cell, (&width, alignment)))| {
+                    let text = cell.lines().nth(line_index).unwrap_or("");
+                    if i == 0 && line_index == 0 && text.trim().is_empty() {
+                        alignment.pad("..", width)
+                    } else {
+                        alignment.pad(text, width)
+                    }
+                })
+                .collect();
+            line.join("  ").trim_end().to_string()
+        })
+        .collect()
+}
+
+/// Normalizes text for the canonical export: whitespace runs within a line become
+/// one space, lines are trimmed, and backslashes, pipes, and line breaks are escaped.
+fn canonical_field(text: &str) -> String {
+    text.lines()
+        .map(|line| {
+            line.split_whitespace()
+                .collect::<Vec<_>>()
+                .join(" ")
+                .replace('\\', "\\\\")
+                .replace('|', "\\|")
+        })
+        .collect::<Vec<_>>()
+        .join("\\n")
+}
+
+/// Escapes text for use inside HTML.
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\n', "<br>")
+}
+
+/// Returns the CSS `text-align` value for the alignment.
+fn html_alignment(alignment: Alignment) -> &'static str {
+    match alignment {
+        Alignment::Left => "left",
+        Alignment::Center => "center",
+        Alignment::Right => "right",
+    }
+}
+
+/// Options controlling how `to_html_with_options` styles the table.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HtmlOptions {
+    /// Whether alignments are written as inline `style` attributes, as `to_html`
+    /// does, instead of semantic classes.
+    pub inline_styles: bool,
+    /// The prefix of every class name, such as `tp-` for `tp-num`.
+    pub class_prefix: String,
+}
+
+impl Default for HtmlOptions {
+    fn default() -> Self {
+        Self {
+            inline_styles: true,
+            class_prefix: "tp-".to_string(),
+        }
+    }
+}
+
+impl HtmlOptions {
+    /// Returns a stylesheet giving the classes written without inline styles the
+    /// look of the terminal output, to be placed in a `<style>` element or served
+    /// as a file. The column classes have no rules and are left to the page.
+    pub fn stylesheet(&self) -> String {
+        let rules = [
+            ("table", "border-collapse: collapse;"),
+            ("left", "text-align: left;"),
+            ("center", "text-align: center;"),
+            ("right", "text-align: right;"),
+            ("num", "font-variant-numeric: tabular-nums;"),
+            ("bold", "font-weight: bold;"),
+            ("italic", "font-style: italic;"),
+            ("underline", "text-decoration: underline;"),
+        ];
+        rules
+            .iter()
+            .map(|(class, declaration)| {
+                format!(".{}{} {{ {} }}\n", self.class_prefix, class, declaration)
+            })
+            .collect()
+    }
+}
+
+/// Returns the BBCode `[color]` value of the color, or `None` for palette colors,
+/// which BBCode cannot name.
+fn bbcode_color(color: Color) -> Option<String> {
+    let name = match color {
+        Color::Black => "black",
+        Color::Blue => "blue",
+        Color::Green => "green",
+        Color::Red => "red",
+        Color::Cyan => "cyan",
+        Color::Magenta => "magenta",
+        Color::Yellow => "yellow",
+        Color::White => "white",
+        Color::Rgb(r, g, b) => return Some(format!("#{:02x}{:02x}{:02x}", r, g, b)),
+        _ => return None,
+    };
+    Some(name.to_string())
+}
+
+/// Formats cells as a Markdown table row.
+fn markdown_row<'a>(cells: impl Iterator<Item = &'a str>) -> String {
+    let cells: Vec<String> = cells.map(escape_markdown).collect();
+    format!("| {} |", cells.join(" | "))
+}
+
+impl Table {
+    /// Makes Markdown and HTML exports apply the column widths and overflow policies.
+    /// Wrapped lines become line breaks and Markdown cells are padded to their width,
+    /// so exported tables keep the shape of the terminal output.
+    pub fn set_fit_exports(&mut self, fit: bool) {
+        self.fit_exports = fit;
+    }
+
+    /// Returns the exported text of every data and footer row.
+    /// Lines of a cell are joined with newlines; when exports are fitted, the
+    /// lines are wrapped or truncated and, if `pad` is set, padded to the width.
+    fn export_rows(&self, pad: bool) -> Vec<Vec<String>> {
+        let widths = self.fit_exports.then(|| self.column_widths());
+        self.rows
+            .iter()
+            .map(|row| &row.cells)
+            .chain(&self.footer)
+            .map(|row| {
+                row.iter()
+                    .enumerate()
+                    .map(|(i, cell)| match &widths {
+                        Some(widths) => self.export_cell(i, cell, widths[i], pad),
+                        None => self.cell_content(i, cell),
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Returns the fitted text of a cell for exports.
+    fn export_cell(&self, column_index: usize, cell: &Cell, width: usize, pad: bool) -> String {
+        let inner = self.inner_width(cell, width);
+        let lines = self.fitted_lines(column_index, cell, inner);
+        if !pad {
+            return lines.join("\n");
+        }
+        (0..lines.len().max(1))
+            .map(|line_index| self.render_cell_line(column_index, cell, line_index, inner))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Exports the table as a GitHub-flavored Markdown table.
+    /// The delimiter row encodes the column alignments.
+    pub fn to_markdown(&self) -> String {
+        let mut lines = Vec::with_capacity(self.rows.len() + 2);
+        lines.push(markdown_row(
+            self.columns.iter().map(|column| column.header.as_str()),
+        ));
+        let delimiters: Vec<&str> = self
+            .columns
+            .iter()
+            .map(|column| markdown_delimiter(column.alignment))
+            .collect();
+        lines.push(format!("| {} |", delimiters.join(" | ")));
+        for row in self.export_rows(true) {
+            lines.push(markdown_row(row.iter().map(String::as_str)));
+        }
+        let mut markdown = lines.join("\n");
+        markdown.push('\n');
+        markdown
+    }
+
+    /// Parses a GitHub-flavored Markdown table, such as one produced by `to_markdown`.
+    /// The delimiter row sets the column alignments; columns are sized automatically
+    /// and the table uses the Markdown style until restyled with `set_style`.
+    /// Rows with too few cells are padded with empty cells and extra cells are dropped.
+    pub fn from_markdown(markdown: &str) -> Result<Self, TableError> {
+        let mut lines = markdown
+            .lines()
+            .enumerate()
+            .map(|(index, line)| (index + 1, line))
+            .filter(|(_, line)| !line.trim().is_empty());
+        let (header_line, header) = lines.next().ok_or(TableError::InvalidMarkdown {
+            line: 1,
+            reason: "missing header row".to_string(),
+        })?;
+        let headers = split_markdown_row(header);
+        let (delimiter_line, delimiter) = lines.next().ok_or(TableError::InvalidMarkdown {
+            line: header_line + 1,
+            reason: "missing delimiter row".to_string(),
+        })?;
+        let alignments = split_markdown_row(delimiter)
+            .iter()
+            .map(|cell| parse_markdown_delimiter(cell))
+            .collect::<Option<Vec<Alignment>>>()
+            .ok_or_else(|| TableError::InvalidMarkdown {
+                line: delimiter_line,
+                reason: "delimiter cells must look like `---`, `:---`, `---:`, or `:---:`"
+                    .to_string(),
+            })?;
+        if alignments.len() != headers.len() {
+            return Err(TableError::InvalidMarkdown {
+                line: delimiter_line,
+                reason: format!(
+                    "{} delimiter cells for {} columns",
+                    alignments.len(),
+                    headers.len()
+                ),
+            });
+        }
+
+        let mut table = Table::new(TableStyle::Markdown);
+        for (header, alignment) in headers.iter().zip(alignments) {
+            table.add_column(header, ColumnWidth::Auto, alignment);
+        }
+        for (_, line) in lines {
+            let mut cells = split_markdown_row(line);
+            cells.resize(headers.len(), String::new());
+            table.add_row(cells.into_iter().map(Cell::from).collect());
+        }
+        Ok(table)
+    }
+
+    /// Exports the table as a reStructuredText simple table, with `=` borders above
+    /// and below the header and below the last row. Columns are as wide as the
+    /// printed columns without their padding, widened where needed so every line
+    /// fits its column as docutils requires, and cells are aligned within them.
+    /// The footer row, if any, follows the data rows.
+    pub fn to_rst_simple(&self) -> String {
+        let headers: Vec<String> = self.columns.iter().map(|c| c.header.clone()).collect();
+        let rows = self.export_rows(false);
+        let padding = self.auto_padding();
+        let widths: Vec<usize> = self
+            .column_widths()
+            .iter()
+            .enumerate()
+            .map(|(i, width)| {
+                std::iter::once(&headers[i])
+                    .chain(rows.iter().map(|row| &row[i]))
+                    .flat_map(|cell| cell.lines())
+                    .map(text_width)
+                    .fold(width.saturating_sub(padding), usize::max)
+                    .max(2)
+            })
+            .collect();
+        let border = widths
+            .iter()
+            .map(|&width| "=".repeat(width))
+            .collect::<Vec<_>>()
+            .join("  ");
+        let header_alignments: Vec<Alignment> = self
+            .columns
+            .iter()
+            .map(|column| column.header_alignment.unwrap_or(column.alignment))
+            .collect();
+        let alignments: Vec<Alignment> = self.columns.iter().map(|c| c.alignment).collect();
+        let mut lines = vec![border.clone()];
+        lines.extend(rst_row(&headers, &widths, &header_alignments));
+        lines.push(border.clone());
+        for row in &rows {
+            lines.extend(rst_row(row, &widths, &alignments));
+        }
+        lines.push(border);
+        let mut rst = lines.join("\n");
+        rst.push('\n');
+        rst
+    }
+
+    /// Exports the table as an HTML `<table>` element.
+    /// Column alignments become `text-align` styles and the footer row, if any,
+    /// is placed in a `<tfoot>` section.
+    pub fn to_html(&self) -> String {
+        self.to_html_with_options(&HtmlOptions::default())
+    }
+
+    /// Exports the table as an HTML `<table>` element styled as the options say.
+    /// Without inline styles, the table has the class `tp-table` and every cell has
+    /// classes for its column such as `tp-col-2`, counted from zero, its alignment
+    /// such as `tp-right`, and its text attributes such as `tp-bold`; data cells
+    /// holding numbers also have `tp-num`. Web pages can then restyle the table,
+    /// starting from `HtmlOptions::stylesheet`.
+    pub fn to_html_with_options(&self, options: &HtmlOptions) -> String {
+        let html_row = |tag: &str, cells: &[Cell], contents: &[String]| {
+            let cells: Vec<String> = cells
+                .iter()
+                .zip(contents)
+                .zip(&self.columns)
+                .enumerate()
+                .map(|(i, ((cell, content), column))| {
+                    let alignment = match tag {
+                        "th" => column.header_alignment.unwrap_or(column.alignment),
+                        _ => column.alignment,
+                    };
+                    let attribute = if options.inline_styles {
+                        format!("style=\"text-align: {}\"", html_alignment(alignment))
+                    } else {
+                        let classes = self.html_classes(i, cell, alignment, tag == "td");
+                        let classes: Vec<String> = classes
+                            .iter()
+                            .map(|class| format!("{}{}", options.class_prefix, class))
+                            .collect();
+                        format!("class=\"{}\"", classes.join(" "))
+                    };
+                    format!("<{tag} {}>{}</{tag}>", attribute, escape_html(content))
+                })
+                .collect();
+            format!("    <tr>{}</tr>\n", cells.join(""))
+        };
+        let header = self.header_cells();
+        let headers: Vec<String> = self.columns.iter().map(|c| c.header.clone()).collect();
+        let mut rows = self.export_rows(false);
+        let footer = self
+            .footer
+            .as_ref()
+            .and_then(|cells| rows.pop().map(|contents| (cells, contents)));
+        let mut html = if options.inline_styles {
+            String::from("<table>\n")
+        } else {
+            format!("<table class=\"{}table\">\n", options.class_prefix)
+        };
+        html.push_str("  <thead>\n");
+        html.push_str(&html_row("th", &header, &headers));
+        html.push_str("  </thead>\n  <tbody>\n");
+        for (row, contents) in self.rows.iter().zip(&rows) {
+            html.push_str(&html_row("td", row, contents));
+        }
+        html.push_str("  </tbody>\n");
+        if let Some((cells, contents)) = footer {
+            html.push_str("  <tfoot>\n");
+            html.push_str(&html_row("td", cells, &contents));
+            html.push_str("  </tfoot>\n");
+        }
+        html.push_str("</table>\n");
+        html
+    }
+
+    /// Returns the class names of a cell in HTML exports, without the prefix.
+    fn html_classes(
+        &self,
+        column_index: usize,
+        cell: &Cell,
+        alignment: Alignment,
+        data: bool,
+    ) -> Vec<String> {
+        let mut classes = vec![
+            format!("col-{}", column_index),
+            html_alignment(alignment).to_string(),
+        ];
+        if data && cell.resolved_content().trim().parse::<f64>().is_ok() {
+            classes.push("num".to_string());
+        }
+        let spec = self.display_spec(column_index, cell, None);
+        for (set, class) in [
+            (spec.bold(), "bold"),
+            (spec.italic(), "italic"),
+            (spec.underline(), "underline"),
+        ] {
+            if set {
+                classes.push(class.to_string());
+            }
+        }
+        classes
+    }
+
+    /// Exports the table as a BBCode `[table]` for forums. Bold, italic, and
+    /// underlined cells, including those styled by category, are wrapped in `[b]`,
+    /// `[i]`, and `[u]`, and colored text in `[color]` with the color name or
+    /// `#rrggbb`. The footer row, if any, follows the data rows.
+    pub fn to_bbcode(&self) -> String {
+        let bbcode_row = |tag: &str, cells: &[Cell], contents: &[String]| {
+            let cells: String = cells
+                .iter()
+                .zip(contents)
+                .enumerate()
+                .map(|(i, (cell, content))| {
+                    format!("[{tag}]{}[/{tag}]", self.bbcode_text(i, cell, content))
+                })
+                .collect();
+            format!("[tr]{}[/tr]\n", cells)
+        };
+        let header = self.header_cells();
+        let headers: Vec<String> = self.columns.iter().map(|c| c.header.clone()).collect();
+        let rows = self.export_rows(false);
+        let cells = self
+            .rows
+            .iter()
+            .map(|row| &row.cells[..])
+            .chain(self.footer.as_deref());
+        let mut bbcode = String::from("[table]\n");
+        bbcode.push_str(&bbcode_row("th", &header, &headers));
+        for (cells, contents) in cells.zip(&rows) {
+            bbcode.push_str(&bbcode_row("td", cells, contents));
+        }
+        bbcode.push_str("[/table]\n");
+        bbcode
+    }
+
+    /// Returns the text of a cell wrapped in the BBCode tags of its attributes.
+    fn bbcode_text(&self, column_index: usize, cell: &Cell, content: &str) -> String {
+        let spec = self.display_spec(column_index, cell, None);
+        let mut text = content.to_string();
+        if let Some(color) = spec.fg().and_then(|color| bbcode_color(*color)) {
+            text = format!("[color={}]{}[/color]", color, text);
+        }
+        for (set, tag) in [
+            (spec.underline(), "u"),
+            (spec.italic(), "i"),
+            (spec.bold(), "b"),
+        ] {
+            if set {
+                text = format!("[{tag}]{}[/{tag}]", text);
+            }
+        }
+        text
+    }
+
+    /// Renders the table without colors inside a fenced code block, such as
+    /// `` ``` `` or `` ```text ``, so it keeps its shape when pasted into chat.
+    /// Tables wider than `max_width` are narrowed as `auto_adjust_widths_within`
+    /// does, and lines still too wide are truncated, so no line of the block is
+    /// wider than `max_width`. The table itself is not changed.
+    pub fn to_code_block(&self, fence: &str, max_width: usize) -> String {
+        let mut text = self.render();
+        if text.lines().any(|line| text_width(line) > max_width) {
+            let mut fitted = self.duplicate();
+            fitted.auto_adjust_widths_within(max_width);
+            text = fitted.render();
+        }
+        let closing: String = fence
+            .chars()
+            .take_while(|&c| c == '`' || c == '~')
+            .collect();
+        let mut block = format!("{}\n", fence);
+        for line in text.lines() {
+            block.push_str(&truncate(line, max_width, self.ellipsis()));
+            block.push('\n');
+        }
+        block.push_str(&closing);
+        block.push('\n');
+        block
+    }
+
+    /// Exports the table for `$GITHUB_STEP_SUMMARY`.
+    /// Tables with more than `GITHUB_SUMMARY_COLLAPSE_ROWS` rows are wrapped in a
+    /// collapsible `<details>` block.
+    pub fn to_github_summary(&self) -> String {
+        self.to_github_summary_collapsed_after(GITHUB_SUMMARY_COLLAPSE_ROWS)
+    }
+
+    /// Exports the table for `$GITHUB_STEP_SUMMARY`, collapsing it when it has
+    /// more than `max_rows` rows.
+    pub fn to_github_summary_collapsed_after(&self, max_rows: usize) -> String {
+        let markdown = self.to_markdown();
+        if self.rows.len() <= max_rows {
+            return markdown;
+        }
+        format!(
+            "<details>\n<summary>{} rows</summary>\n\n{}\n</details>\n",
+            self.rows.len(),
+            markdown
+        )
+    }
+
+    /// Renders the table without colors as a `///` doc-comment block.
+    pub fn to_doc_comment(&self) -> String {
+        self.to_prefixed("/// ")
+    }
+
+    /// Renders the table without colors with every line prefixed by `prefix`.
+    /// Trailing whitespace is removed from every line, including the prefix on
+    /// blank lines, so the text can be pasted into formatted source files.
+    pub fn to_prefixed(&self, prefix: &str) -> String {
+        self.render()
+            .lines()
+            .map(|line| format!("{}{}", prefix, line).trim_end().to_string() + "\n")
+            .collect()
+    }
+
+    /// Exports the table in a plain, style-independent form meant for committing
+    /// generated data to version control: one line per row with the fields separated
+    /// by ` | `, no padding or borders, normalized whitespace, no trailing spaces,
+    /// and LF line endings.
+    /// Pipes, backslashes, and line breaks in cells are escaped as `\|`, `\\`, and
+    /// `\n`. The footer row, if any, follows a `--` line.
+    pub fn to_canonical_text(&self) -> String {
+        self.canonical_text((0..self.columns.len()).collect())
+    }
+
+    /// Exports the table like `to_canonical_text`, with the columns ordered by their
+    /// headers, so reordering columns does not change the output.
+    pub fn to_canonical_text_with_sorted_columns(&self) -> String {
+        let mut order: Vec<usize> = (0..self.columns.len()).collect();
+        order.sort_by(|&a, &b| self.columns[a].header.cmp(&self.columns[b].header));
+        self.canonical_text(order)
+    }
+
+    /// Writes the canonical form with the columns in the given order.
+    fn canonical_text(&self, order: Vec<usize>) -> String {
+        let line = |fields: Vec<String>| {
+            let fields: Vec<String> = order.iter().map(|&i| canonical_field(&fields[i])).collect();
+            fields.join(" | ").trim_end().to_string() + "\n"
+        };
+        let mut text = line(self.columns.iter().map(|c| c.header.clone()).collect());
+        for row in &self.rows {
+            text.push_str(&line(
+                row.iter()
+                    .enumerate()
+                    .map(|(i, cell)| self.cell_content(i, cell))
+                    .collect(),
+            ));
+        }
+        if let Some(footer) = &self.footer {
+            text.push_str("--\n");
+            text.push_str(&line(
+                footer
+                    .iter()
+                    .enumerate()
+                    .map(|(i, cell)| self.cell_content(i, cell))
+                    .collect(),
+            ));
+        }
+        text
+    }
+}
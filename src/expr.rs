@@ -0,0 +1,250 @@
+// SPDX-License-Identifier: MIT
+// Project: tabprinter
+// File: src/expr.rs
+// Author: Volker Schwaberow <volker@schwaberow.de>
+// Copyright (c) 2025 Volker Schwaberow
+
+use crate::text::format_number;
+use crate::{Alignment, Cell, Column, ColumnWidth, Table, TableError};
+
+/// A token of an arithmetic expression.
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    Number(f64),
+    Column(String),
+    Operator(char),
+    Open,
+    Close,
+}
+
+/// Splits the expression into tokens.
+/// Column names are words or bracketed names such as `[Unit Price]`.
+fn tokenize(source: &str) -> Result<Vec<Token>, TableError> {
+    let mut tokens = Vec::new();
+    let mut chars = source.char_indices().peekable();
+    while let Some(&(start, c)) = chars.peek() {
+        match c {
+            c if c.is_whitespace() => {
+                chars.next();
+            }
+            '+' | '-' | '*' | '/' => {
+                tokens.push(Token::Operator(c));
+                chars.next();
+            }
+            '(' => {
+                tokens.push(Token::Open);
+                chars.next();
+            }
+            ')' => {
+                tokens.push(Token::Close);
+                chars.next();
+            }
+            '[' => {
+                chars.next();
+                let mut name = String::new();
+                loop {
+                    match chars.next() {
+                        Some((_, ']')) => break,
+                        Some((_, c)) => name.push(c),
+                        None => return Err(invalid(source, "unclosed `[`")),
+                    }
+                }
+                tokens.push(Token::Column(name));
+            }
+            c if c.is_ascii_digit() || c == '.' => {
+                let mut end = start;
+                while let Some(&(i, c)) = chars.peek() {
+                    if !(c.is_ascii_digit() || c == '.') {
+                        break;
+                    }
+                    end = i + c.len_utf8();
+                    chars.next();
+                }
+                let number = source[start..end]
+                    .parse()
+                    .map_err(|_| invalid(source, "malformed number"))?;
+                tokens.push(Token::Number(number));
+            }
+            c if c.is_alphanumeric() || c == '_' => {
+                let mut name = String::new();
+                while let Some(&(_, c)) = chars.peek() {
+                    if !(c.is_alphanumeric() || c == '_') {
+                        break;
+                    }
+                    name.push(c);
+                    chars.next();
+                }
+                tokens.push(Token::Column(name));
+            }
+            c => return Err(invalid(source, &format!("unexpected character `{}`", c))),
+        }
+    }
+    Ok(tokens)
+}
+
+/// Returns an error describing why the expression is invalid.
+fn invalid(source: &str, reason: &str) -> TableError {
+    TableError::InvalidExpression {
+        expression: source.to_string(),
+        reason: reason.to_string(),
+    }
+}
+
+/// A parsed arithmetic expression over the numeric columns of a row.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum Expr {
+    /// A constant.
+    Number(f64),
+    /// The value of the column at the index.
+    Column(usize),
+    /// The negated value of the operand.
+    Negate(Box<Expr>),
+    /// An operator applied to two operands.
+    Binary(char, Box<Expr>, Box<Expr>),
+}
+
+impl Expr {
+    /// Parses an expression such as `Price * Qty` or `([Unit Price] - Discount) / 2`.
+    /// Column names must match a header exactly.
+    pub(crate) fn parse(source: &str, columns: &[Column]) -> Result<Self, TableError> {
+        let tokens = tokenize(source)?;
+        let mut parser = Parser {
+            source,
+            columns,
+            tokens: &tokens,
+            position: 0,
+        };
+        let expr = parser.sum()?;
+        if parser.position < tokens.len() {
+            return Err(invalid(source, "unexpected trailing input"));
+        }
+        Ok(expr)
+    }
+
+    /// Evaluates the expression for a row.
+    /// Returns `None` if a referenced cell is not a number or the result is not finite.
+    pub(crate) fn eval(&self, row: &[Cell]) -> Option<f64> {
+        let value = match self {
+            Expr::Number(value) => *value,
+            Expr::Column(index) => row[*index].content.trim().parse().ok()?,
+            Expr::Negate(operand) => -operand.eval(row)?,
+            Expr::Binary(op, left, right) => {
+                let (left, right) = (left.eval(row)?, right.eval(row)?);
+                match op {
+                    '+' => left + right,
+                    '-' => left - right,
+                    '*' => left * right,
+                    _ => left / right,
+                }
+            }
+        };
+        value.is_finite().then_some(value)
+    }
+}
+
+/// A recursive descent parser over the tokens of an expression.
+struct Parser<'a> {
+    source: &'a str,
+    columns: &'a [Column],
+    tokens: &'a [Token],
+    position: usize,
+}
+
+impl Parser<'_> {
+    /// Returns the next token if it is one of the operators.
+    fn operator(&mut self, operators: &[char]) -> Option<char> {
+        match self.tokens.get(self.position) {
+            Some(Token::Operator(op)) if operators.contains(op) => {
+                self.position += 1;
+                Some(*op)
+            }
+            _ => None,
+        }
+    }
+
+    /// Parses terms joined by `+` and `-`.
+    fn sum(&mut self) -> Result<Expr, TableError> {
+        let mut expr = self.product()?;
+        while let Some(op) = self.operator(&['+', '-']) {
+            expr = Expr::Binary(op, Box::new(expr), Box::new(self.product()?));
+        }
+        Ok(expr)
+    }
+
+    /// Parses factors joined by `*` and `/`.
+    fn product(&mut self) -> Result<Expr, TableError> {
+        let mut expr = self.factor()?;
+        while let Some(op) = self.operator(&['*', '/']) {
+            expr = Expr::Binary(op, Box::new(expr), Box::new(self.factor()?));
+        }
+        Ok(expr)
+    }
+
+    /// Parses a number, a column, a negation, or a parenthesized expression.
+    fn factor(&mut self) -> Result<Expr, TableError> {
+        if self.operator(&['-']).is_some() {
+            return Ok(Expr::Negate(Box::new(self.factor()?)));
+        }
+        let token = self
+            .tokens
+            .get(self.position)
+            .ok_or_else(|| invalid(self.source, "unexpected end of expression"))?;
+        self.position += 1;
+        match token {
+            Token::Number(value) => Ok(Expr::Number(*value)),
+            Token::Column(name) => self
+                .columns
+                .iter()
+                .position(|column| column.header == *name)
+                .map(Expr::Column)
+                .ok_or_else(|| TableError::UnknownColumn(name.clone())),
+            Token::Open => {
+                let expr = self.sum()?;
+                match self.tokens.get(self.position) {
+                    Some(Token::Close) => {
+                        self.position += 1;
+                        Ok(expr)
+                    }
+                    _ => Err(invalid(self.source, "missing `)`")),
+                }
+            }
+            _ => Err(invalid(self.source, "expected a number or column")),
+        }
+    }
+}
+
+impl Table {
+    /// Appends a right-aligned column whose cells are computed from each row.
+    /// The footer, if any, gets an empty cell in the new column.
+    pub fn add_computed_column<F, C>(&mut self, header: &str, f: F)
+    where
+        F: Fn(&[Cell]) -> C,
+        C: Into<Cell>,
+    {
+        for row in self.rows.iter_mut() {
+            let cell = f(&row.cells).into();
+            row.cells.push(cell);
+        }
+        if let Some(footer) = &mut self.footer {
+            footer.push(Cell::new(""));
+        }
+        self.add_column(header, ColumnWidth::Auto, Alignment::Right);
+        self.invalidate_widths();
+    }
+
+    /// Appends a column computed from an arithmetic expression over numeric columns,
+    /// such as `Price * Qty`. The expression supports `+`, `-`, `*`, `/`, parentheses,
+    /// numbers, and column headers; headers with spaces are written as `[Unit Price]`.
+    /// Rows where a referenced cell is not a number get an empty cell.
+    pub fn add_computed_column_expr(
+        &mut self,
+        header: &str,
+        expression: &str,
+    ) -> Result<(), TableError> {
+        let expr = Expr::parse(expression, &self.columns)?;
+        self.add_computed_column(header, |row| {
+            expr.eval(row).map(format_number).unwrap_or_default()
+        });
+        Ok(())
+    }
+}
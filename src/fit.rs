@@ -0,0 +1,69 @@
+// SPDX-License-Identifier: MIT
+// Project: tabprinter
+// File: src/fit.rs
+// Author: Volker Schwaberow <volker@schwaberow.de>
+// Copyright (c) 2025 Volker Schwaberow
+
+use crate::text::text_width;
+use crate::{Cell, ColumnWidth, OverflowPolicy, Table};
+
+impl Table {
+    /// Returns the width of the longest word of the header or any cell of the column,
+    /// or `None` if no cell has more than one word, so wrapping would not help.
+    fn longest_word(&self, column_index: usize) -> Option<usize> {
+        let header = Cell::new(&self.columns[column_index].header);
+        let texts: Vec<String> = std::iter::once(&header)
+            .chain(self.rows.iter().map(|row| &row[column_index]))
+            .map(|cell| self.cell_text(column_index, cell))
+            .collect();
+        texts
+            .iter()
+            .any(|text| text.split_whitespace().nth(1).is_some())
+            .then(|| {
+                texts
+                    .iter()
+                    .flat_map(|text| text.split_whitespace())
+                    .map(text_width)
+                    .max()
+                    .unwrap_or(0)
+            })
+    }
+
+    /// Sizes the columns to their content like `auto_adjust_widths`, then narrows the
+    /// widest columns holding several words, such as descriptions, one character at a
+    /// time until the table fits in `total_width`. Narrowed columns get a fixed width
+    /// and wrap at word boundaries; no column becomes narrower than its longest word.
+    /// Returns the columns that will wrap. The table may stay wider than `total_width`
+    /// when no column can be narrowed further.
+    pub fn auto_adjust_widths_within(&mut self, total_width: usize) -> Vec<usize> {
+        self.auto_adjust_widths();
+        let mut widths = self.column_widths();
+        let padding = self.auto_padding();
+        let minimums: Vec<Option<usize>> = (0..self.columns.len())
+            .map(|i| self.longest_word(i).map(|word| (word + padding).max(1)))
+            .collect();
+        let total = |widths: &[usize]| match self.style.config() {
+            Some(_) => widths.iter().sum::<usize>() + self.border_overhead(),
+            None => widths.iter().sum::<usize>().saturating_sub(1),
+        };
+        let mut wrapped = Vec::new();
+        while total(&widths) > total_width {
+            let widest = (0..widths.len())
+                .filter(|&i| minimums[i].is_some_and(|minimum| widths[i] > minimum))
+                .max_by_key(|&i| (widths[i], std::cmp::Reverse(i)));
+            let Some(i) = widest else {
+                break;
+            };
+            widths[i] -= 1;
+            if !wrapped.contains(&i) {
+                wrapped.push(i);
+            }
+        }
+        wrapped.sort_unstable();
+        for &i in &wrapped {
+            self.columns[i].width = ColumnWidth::Fixed(widths[i]);
+            self.columns[i].overflow = Some(OverflowPolicy::Wrap);
+        }
+        wrapped
+    }
+}
@@ -0,0 +1,104 @@
+// SPDX-License-Identifier: MIT
+// Project: tabprinter
+// File: src/fluent.rs
+// Author: Volker Schwaberow <volker@schwaberow.de>
+// Copyright (c) 2025 Volker Schwaberow
+
+use crate::color::Buffer;
+use crate::text::strip_ansi;
+use crate::{Alignment, Cell, ColumnRef, ColumnWidth, Table, TableStyle};
+
+impl Table {
+    /// Adds a column and returns the table, for building tables in one expression.
+    pub fn with_column(
+        mut self,
+        header: &str,
+        width: impl Into<ColumnWidth>,
+        alignment: Alignment,
+    ) -> Self {
+        self.add_column(header, width, alignment);
+        self
+    }
+
+    /// Adds a row and returns the table.
+    /// The length of the row must match the number of columns.
+    pub fn with_row(mut self, row: Vec<Cell>) -> Self {
+        self.add_row(row);
+        self
+    }
+
+    /// Sorts the rows by the specified column and returns the table.
+    pub fn sorted_by<'a>(mut self, column: impl Into<ColumnRef<'a>>, ascending: bool) -> Self {
+        self.sort_by_column(column, ascending);
+        self
+    }
+
+    /// Keeps only the rows matching the predicate and returns the table.
+    /// Unlike `filter_rows`, the table is not copied. Separators and merged cells are removed.
+    pub fn filtered<F>(mut self, predicate: F) -> Self
+    where
+        F: Fn(&Vec<Cell>) -> bool,
+    {
+        self.rows.retain(|row| predicate(&row.cells));
+        self.separators.clear();
+        self.merges.clear();
+        self.invalidate_widths();
+        self
+    }
+
+    /// Sets the style and returns the table.
+    pub fn with_style(mut self, style: TableStyle) -> Self {
+        self.set_style(style);
+        self
+    }
+
+    /// Renders the table as text without colors, as it would be printed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if content does not fit a column with `OverflowPolicy::Error`;
+    /// use `check_widths` first or print to a writer to handle the error.
+    pub fn render(&self) -> String {
+        let mut buffer = Buffer::no_color();
+        self.print_color(&mut buffer)
+            .unwrap_or_else(|error| panic!("cannot render the table: {}", error));
+        String::from_utf8_lossy(buffer.as_slice()).into_owned()
+    }
+
+    /// Renders the table as text with ANSI color codes, as it would be printed
+    /// to a color terminal.
+    ///
+    /// # Panics
+    ///
+    /// Panics under the same conditions as `render`.
+    pub fn render_ansi(&self) -> String {
+        let mut buffer = Buffer::ansi();
+        self.print_color(&mut buffer)
+            .unwrap_or_else(|error| panic!("cannot render the table: {}", error));
+        String::from_utf8_lossy(buffer.as_slice()).into_owned()
+    }
+
+    /// Checks that colors never change the layout: the colored rendering without its
+    /// escape codes, the plain rendering, and the output of `print_to_writer` must be
+    /// identical. Meant for tests of code building tables.
+    ///
+    /// # Panics
+    ///
+    /// Panics with both renderings if they differ.
+    pub fn assert_layout_consistent(&self) {
+        let plain = self.render();
+        let colored = strip_ansi(&self.render_ansi());
+        assert_eq!(
+            colored, plain,
+            "the colored rendering differs from the plain rendering"
+        );
+        let mut buffer = Buffer::no_color();
+        self.print_to_writer(&mut buffer)
+            .expect("writing to memory cannot fail");
+        let printed = String::from_utf8_lossy(buffer.as_slice());
+        assert_eq!(
+            printed, plain,
+            "print_to_writer differs from the plain rendering"
+        );
+    }
+}
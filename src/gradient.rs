@@ -0,0 +1,68 @@
+// SPDX-License-Identifier: MIT
+// Project: tabprinter
+// File: src/gradient.rs
+// Author: Volker Schwaberow <volker@schwaberow.de>
+// Copyright (c) 2025 Volker Schwaberow
+
+use crate::color::Color;
+use crate::Table;
+
+/// A true color given by its red, green, and blue components.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Rgb {
+    /// The red component.
+    pub r: u8,
+    /// The green component.
+    pub g: u8,
+    /// The blue component.
+    pub b: u8,
+}
+
+impl Rgb {
+    /// Creates a color from its components.
+    pub const fn new(r: u8, g: u8, b: u8) -> Self {
+        Self { r, g, b }
+    }
+
+    /// Returns the color at `t` between this color (0.0) and `other` (1.0).
+    pub fn lerp(self, other: Rgb, t: f64) -> Rgb {
+        let t = t.clamp(0.0, 1.0);
+        let mix = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * t).round() as u8;
+        Rgb::new(
+            mix(self.r, other.r),
+            mix(self.g, other.g),
+            mix(self.b, other.b),
+        )
+    }
+}
+
+impl From<Rgb> for Color {
+    fn from(rgb: Rgb) -> Self {
+        Color::Rgb(rgb.r, rgb.g, rgb.b)
+    }
+}
+
+impl Table {
+    /// Colors the background of the data rows with a gradient running from `from`
+    /// on the first row to `to` on the last, as an indicator of the position in
+    /// long tables. Requires a terminal with true-color support; the header, the
+    /// footer, and rows pushed to a `StreamPrinter` are not colored.
+    pub fn set_row_gradient(&mut self, from: Rgb, to: Rgb) {
+        self.row_gradient = Some((from, to));
+    }
+
+    /// Removes the row background gradient.
+    pub fn clear_row_gradient(&mut self) {
+        self.row_gradient = None;
+    }
+
+    /// Returns the background color of the data row, if a gradient is set.
+    pub(crate) fn row_background(&self, row_index: usize) -> Option<Color> {
+        let (from, to) = self.row_gradient?;
+        let t = match self.rows.len() {
+            0 | 1 => 0.0,
+            len => row_index as f64 / (len - 1) as f64,
+        };
+        Some(from.lerp(to, t).into())
+    }
+}
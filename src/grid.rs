@@ -0,0 +1,116 @@
+// SPDX-License-Identifier: MIT
+// Project: tabprinter
+// File: src/grid.rs
+// Author: Volker Schwaberow <volker@schwaberow.de>
+// Copyright (c) 2025 Volker Schwaberow
+
+use std::io::{self, Write};
+
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::color::{ColorSpec, WriteColor};
+use crate::text::text_width;
+use crate::Table;
+
+/// The content and style of one screen cell of a rendered table.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct StyledChar {
+    /// The grapheme cluster shown in the cell. The cell after a double-width
+    /// character holds an empty string.
+    pub symbol: String,
+    /// The colors and attributes of the cell.
+    pub style: ColorSpec,
+}
+
+/// Collects the output of the renderer as lines of styled screen cells.
+#[derive(Default)]
+struct GridWriter {
+    /// The finished lines and the line being written.
+    lines: Vec<Vec<StyledChar>>,
+    /// The style of the text being written.
+    style: ColorSpec,
+    /// Bytes written with the current style and not yet decoded.
+    pending: Vec<u8>,
+}
+
+impl GridWriter {
+    /// Decodes the pending bytes into screen cells with the current style.
+    fn decode(&mut self) {
+        if self.pending.is_empty() {
+            return;
+        }
+        let text = String::from_utf8_lossy(&self.pending).into_owned();
+        self.pending.clear();
+        if self.lines.is_empty() {
+            self.lines.push(Vec::new());
+        }
+        for grapheme in text.graphemes(true) {
+            if grapheme == "\n" {
+                self.lines.push(Vec::new());
+                continue;
+            }
+            let line = self.lines.last_mut().expect("a line is always open");
+            line.push(StyledChar {
+                symbol: grapheme.to_string(),
+                style: self.style.clone(),
+            });
+            for _ in 1..text_width(grapheme) {
+                line.push(StyledChar {
+                    symbol: String::new(),
+                    style: self.style.clone(),
+                });
+            }
+        }
+    }
+
+    /// Returns the lines, without the empty line after the last line break.
+    fn finish(mut self) -> Vec<Vec<StyledChar>> {
+        self.decode();
+        if self.lines.last().is_some_and(Vec::is_empty) {
+            self.lines.pop();
+        }
+        self.lines
+    }
+}
+
+impl Write for GridWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.pending.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl WriteColor for GridWriter {
+    fn supports_color(&self) -> bool {
+        true
+    }
+
+    fn set_color(&mut self, spec: &ColorSpec) -> io::Result<()> {
+        self.decode();
+        self.style = spec.clone();
+        Ok(())
+    }
+
+    fn reset(&mut self) -> io::Result<()> {
+        self.decode();
+        self.style = ColorSpec::new();
+        Ok(())
+    }
+}
+
+impl Table {
+    /// Renders the table into lines of screen cells, each with its character and
+    /// style, so graphical front ends can draw the table without parsing ANSI codes.
+    /// Lines are as wide as the printed lines, which may differ when content
+    /// overflows its column.
+    pub fn render_grid(&self) -> Vec<Vec<StyledChar>> {
+        let mut writer = GridWriter::default();
+        self.print_color(&mut writer)
+            .expect("writing to memory cannot fail");
+        writer.finish()
+    }
+}
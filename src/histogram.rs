@@ -0,0 +1,90 @@
+// SPDX-License-Identifier: MIT
+// Project: tabprinter
+// File: src/histogram.rs
+// Author: Volker Schwaberow <volker@schwaberow.de>
+// Copyright (c) 2025 Volker Schwaberow
+
+use crate::text::format_number;
+use crate::{Alignment, Cell, ColumnRef, ColumnWidth, Table};
+
+impl Table {
+    /// Builds a frequency table with the header of the specified column, `Count`,
+    /// and `Percent` columns from the given values and their counts, in the order
+    /// given. The values are masked like the column.
+    fn frequency_table(&self, column_index: usize, counts: Vec<(String, usize)>) -> Table {
+        let total: usize = counts.iter().map(|(_, count)| count).sum();
+        let column = &self.columns[column_index];
+        let mut table = Table::new(self.style);
+        table.add_column(&column.header, ColumnWidth::Auto, Alignment::Left);
+        table.add_column("Count", ColumnWidth::Auto, Alignment::Right);
+        table.add_column("Percent", ColumnWidth::Auto, Alignment::Right);
+        table.columns[0].mask = column.mask;
+        for (value, count) in counts {
+            let percent = count as f64 * 100.0 / total as f64;
+            table.add_row(vec![
+                Cell::new(&value),
+                Cell::new(&count.to_string()),
+                Cell::new(&format!("{:.1}%", percent)),
+            ]);
+        }
+        table
+    }
+
+    /// Returns a table of the distinct values of the specified column with the
+    /// number and percentage of rows holding each, most frequent first.
+    pub fn value_counts<'a>(&self, column: impl Into<ColumnRef<'a>>) -> Table {
+        let column_index = self.resolve_column(column);
+        let counts = self
+            .distinct_counts(column_index)
+            .into_iter()
+            .map(|(value, count)| (value.to_string(), count))
+            .collect();
+        self.frequency_table(column_index, counts)
+    }
+
+    /// Returns a histogram of the numeric values of the specified column, divided
+    /// into `bins` ranges of equal width between the smallest and largest value.
+    /// Each row shows a range, the number of values in it, and their percentage.
+    /// Cells that are not numbers are left out.
+    pub fn value_counts_binned<'a>(&self, column: impl Into<ColumnRef<'a>>, bins: usize) -> Table {
+        let column_index = self.resolve_column(column);
+        let values: Vec<f64> = self
+            .rows
+            .iter()
+            .filter_map(|row| row[column_index].content.trim().parse::<f64>().ok())
+            .filter(|value| value.is_finite())
+            .collect();
+        if values.is_empty() || bins == 0 {
+            return self.frequency_table(column_index, Vec::new());
+        }
+        let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+        let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        let bin_width = (max - min) / bins as f64;
+        let mut counts = vec![0; bins];
+        for value in &values {
+            let bin = if bin_width == 0.0 {
+                0
+            } else {
+                (((value - min) / bin_width) as usize).min(bins - 1)
+            };
+            counts[bin] += 1;
+        }
+        let counts = counts
+            .into_iter()
+            .enumerate()
+            .map(|(i, count)| {
+                let start = min + bin_width * i as f64;
+                let end = if i + 1 == bins {
+                    max
+                } else {
+                    min + bin_width * (i + 1) as f64
+                };
+                (
+                    format!("{} – {}", format_number(start), format_number(end)),
+                    count,
+                )
+            })
+            .collect();
+        self.frequency_table(column_index, counts)
+    }
+}
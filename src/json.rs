@@ -0,0 +1,113 @@
+// SPDX-License-Identifier: MIT
+// Project: tabprinter
+// File: src/json.rs
+// Author: Volker Schwaberow <volker@schwaberow.de>
+// Copyright (c) 2025 Volker Schwaberow
+
+use serde_json::{Map, Value};
+
+use crate::{Alignment, Cell, ColumnWidth, Table, TableError, TableStyle};
+
+/// Determines the order of the columns created from the keys of JSON objects.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum ColumnOrderPolicy {
+    /// Keys become columns in the order they first appear across the records.
+    #[default]
+    FirstSeen,
+    /// Keys become columns in alphabetical order.
+    Sorted,
+    /// Only the given keys become columns, in the given order; other keys are ignored.
+    Explicit(Vec<String>),
+}
+
+/// Returns the text shown for a JSON value: strings without quotes,
+/// `null` as an empty cell, and arrays and objects as compact JSON.
+fn value_text(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(text) => text.clone(),
+        other => other.to_string(),
+    }
+}
+
+/// Builds a table from JSON objects. Keys missing from a record give empty cells.
+fn table_from_objects(records: &[Map<String, Value>], order: &ColumnOrderPolicy) -> Table {
+    let headers: Vec<String> = match order {
+        ColumnOrderPolicy::Explicit(headers) => headers.clone(),
+        _ => {
+            let mut headers: Vec<String> = Vec::new();
+            for key in records.iter().flat_map(Map::keys) {
+                if !headers.contains(key) {
+                    headers.push(key.clone());
+                }
+            }
+            if *order == ColumnOrderPolicy::Sorted {
+                headers.sort();
+            }
+            headers
+        }
+    };
+    let mut table = Table::new(TableStyle::Simple);
+    for header in &headers {
+        table.add_column(header, ColumnWidth::Auto, Alignment::Left);
+    }
+    for record in records {
+        table.add_row(
+            headers
+                .iter()
+                .map(|header| Cell::from(record.get(header).map(value_text).unwrap_or_default()))
+                .collect(),
+        );
+    }
+    table
+}
+
+/// Returns the object of a record or an error naming where it was found.
+fn into_object(value: Value, location: &str) -> Result<Map<String, Value>, TableError> {
+    match value {
+        Value::Object(object) => Ok(object),
+        other => Err(TableError::InvalidJson(format!(
+            "{}: expected an object, found `{}`",
+            location, other
+        ))),
+    }
+}
+
+impl Table {
+    /// Creates a table from a JSON array of objects, such as `[{"name": "Alice"}]`.
+    /// Every key becomes a column ordered by the policy; records without a key get
+    /// an empty cell, so records with differing keys share one stable set of columns.
+    pub fn from_json(json: &str, order: ColumnOrderPolicy) -> Result<Self, TableError> {
+        let value: Value = serde_json::from_str(json)
+            .map_err(|error| TableError::InvalidJson(error.to_string()))?;
+        let Value::Array(values) = value else {
+            return Err(TableError::InvalidJson(
+                "expected an array of objects".to_string(),
+            ));
+        };
+        let records = values
+            .into_iter()
+            .enumerate()
+            .map(|(i, value)| into_object(value, &format!("element {}", i)))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(table_from_objects(&records, &order))
+    }
+
+    /// Creates a table from newline-delimited JSON with one object per line,
+    /// such as a structured log. Blank lines are skipped; columns are formed as
+    /// by `from_json`.
+    pub fn from_ndjson(ndjson: &str, order: ColumnOrderPolicy) -> Result<Self, TableError> {
+        let records = ndjson
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(i, line)| {
+                let location = format!("line {}", i + 1);
+                let value: Value = serde_json::from_str(line)
+                    .map_err(|error| TableError::InvalidJson(format!("{}: {}", location, error)))?;
+                into_object(value, &location)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(table_from_objects(&records, &order))
+    }
+}
@@ -0,0 +1,217 @@
+// SPDX-License-Identifier: MIT
+// Project: tabprinter
+// File: src/layout.rs
+// Author: Volker Schwaberow <volker@schwaberow.de>
+// Copyright (c) 2025 Volker Schwaberow
+
+use std::ops::Range;
+
+use crate::text::text_width;
+use crate::{EmptyPolicy, LineStyle, Table, TableStyle};
+
+/// The geometry of a printed table, as computed by the renderer.
+/// Horizontal positions count terminal cells from the left edge and vertical
+/// positions count lines from the first printed line. Footnotes are not included.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Layout {
+    /// The rendered width of every column, including its cell padding.
+    pub column_widths: Vec<usize>,
+    /// The horizontal span of every column, including the padding next to its borders.
+    pub columns: Vec<Range<usize>>,
+    /// The horizontal spans of the vertical borders, including the outer edges.
+    pub vertical_borders: Vec<Range<usize>>,
+    /// The lines of the header row.
+    pub header: Range<usize>,
+    /// The lines of every repeated header row.
+    pub repeated_headers: Vec<Range<usize>>,
+    /// The lines of every data row.
+    pub rows: Vec<Range<usize>>,
+    /// The lines of the footer row, if any.
+    pub footer: Option<Range<usize>>,
+    /// The lines drawn as horizontal borders and separators.
+    pub horizontal_borders: Vec<usize>,
+    /// The width of the printed lines.
+    pub width: usize,
+    /// The number of printed lines.
+    pub height: usize,
+}
+
+impl Layout {
+    /// Returns the column at the horizontal position, if it is not on a border.
+    pub fn column_at(&self, x: usize) -> Option<usize> {
+        self.columns.iter().position(|span| span.contains(&x))
+    }
+
+    /// Returns the data row at the line, if it is not on a border, header, or footer.
+    pub fn row_at(&self, y: usize) -> Option<usize> {
+        self.rows.iter().position(|span| span.contains(&y))
+    }
+}
+
+/// Tracks the next line and the drawn rules while the vertical layout is built.
+#[derive(Default)]
+struct Lines {
+    next: usize,
+    rules: Vec<usize>,
+}
+
+impl Lines {
+    /// Claims the next `count` lines.
+    fn take(&mut self, count: usize) -> Range<usize> {
+        let start = self.next;
+        self.next += count;
+        start..self.next
+    }
+
+    /// Claims the next line for a horizontal rule, if it is drawn.
+    fn rule(&mut self, drawn: bool) {
+        if drawn {
+            let line = self.take(1).start;
+            self.rules.push(line);
+        }
+    }
+}
+
+/// Claims the horizontal span of a border drawn at `x`.
+fn border(x: &mut usize, text: &str) -> Range<usize> {
+    let span = *x..*x + text_width(text);
+    *x = span.end;
+    span
+}
+
+impl Table {
+    /// Computes the layout the table is printed with: column widths and positions,
+    /// the lines of every row after wrapping, and where borders are drawn.
+    /// A table that is not printed at all has an empty layout.
+    pub fn layout(&self) -> Layout {
+        self.layout_within(usize::MAX)
+    }
+
+    /// Computes the layout of the rows starting before line `max_lines`. Later rows
+    /// are left out without measuring their cells, such as rows cut off by a render
+    /// budget, so the footer, width, and height are only right if no row is left out.
+    pub(crate) fn layout_within(&self, max_lines: usize) -> Layout {
+        if self.skips_printing() {
+            return Layout::default();
+        }
+        let widths = self.column_widths();
+        let mut layout = Layout {
+            column_widths: widths.clone(),
+            ..Layout::default()
+        };
+        let mut lines = Lines::default();
+        let placeholder =
+            self.rows.is_empty() && matches!(self.empty_policy, EmptyPolicy::Placeholder(_));
+
+        if self.style == TableStyle::Accessible {
+            layout.rows = (0..self.rows.len().min(max_lines))
+                .map(|i| i..i + 1)
+                .collect();
+            if layout.rows.len() < self.rows.len() {
+                return layout;
+            }
+            layout.footer = self
+                .footer
+                .as_ref()
+                .map(|_| self.rows.len()..self.rows.len() + 1);
+            let text = self.to_linear_text();
+            layout.width = text.lines().map(text_width).max().unwrap_or(0);
+            layout.height = text.lines().count();
+            return layout;
+        }
+
+        match self.style.config() {
+            Some(style) => {
+                let padding = style.cell_padding;
+                let mut x = 0;
+                layout
+                    .vertical_borders
+                    .push(border(&mut x, style.row.begin));
+                for (i, width) in widths.iter().enumerate() {
+                    if i > 0 {
+                        layout.vertical_borders.push(border(&mut x, style.row.sep));
+                    }
+                    layout.columns.push(x..x + width + 2 * padding);
+                    x += width + 2 * padding;
+                }
+                layout.vertical_borders.push(border(&mut x, style.row.end));
+                layout.vertical_borders.retain(|span| !span.is_empty());
+                layout.width = x;
+
+                if self.has_title_line(&style.top) {
+                    lines.take(1);
+                }
+                lines.rule(!style.top.is_empty());
+                let header_lines = self.row_line_count(&self.header_cells(), &widths, 0)
+                    + usize::from(self.header_underline().is_some());
+                layout.header = lines.take(header_lines);
+                lines.rule(!style.below_header.is_empty());
+                let merged_counts = (!self.merges.is_empty())
+                    .then(|| self.merged_line_counts(&widths, style.row.sep, padding));
+                for (i, row) in self.rows.iter().enumerate() {
+                    if self.repeats_header_before(i) {
+                        lines.rule(!style.below_header.is_empty());
+                        layout.repeated_headers.push(lines.take(header_lines));
+                        lines.rule(!style.below_header.is_empty());
+                    } else if self.has_separator_before(i) {
+                        lines.rule(!style.below_header.is_empty());
+                    }
+                    if lines.next >= max_lines {
+                        break;
+                    }
+                    let count = match &merged_counts {
+                        Some(counts) => counts[i],
+                        None => self.row_line_count(row, &widths, 0),
+                    };
+                    layout.rows.push(lines.take(count));
+                }
+                if placeholder {
+                    lines.take(1);
+                }
+                if let Some(footer) = &self.footer {
+                    lines.rule(!style.above_footer.is_empty());
+                    layout.footer = Some(lines.take(self.row_line_count(footer, &widths, 0)));
+                }
+                lines.rule(!style.bottom.is_empty());
+            }
+            None => {
+                let mut x = 0;
+                for width in &widths {
+                    layout.columns.push(x..x + width - 1);
+                    x += width;
+                }
+                layout.width = x.saturating_sub(1);
+
+                if self.has_title_line(&LineStyle::EMPTY) {
+                    lines.take(1);
+                }
+                let header_lines = self.row_line_count(&self.header_cells(), &widths, 1);
+                layout.header = lines.take(header_lines);
+                for (i, row) in self.rows.iter().enumerate() {
+                    if self.repeats_header_before(i) {
+                        lines.rule(true);
+                        layout.repeated_headers.push(lines.take(header_lines));
+                    } else if self.has_separator_before(i) {
+                        lines.rule(true);
+                    }
+                    if lines.next >= max_lines {
+                        break;
+                    }
+                    layout
+                        .rows
+                        .push(lines.take(self.row_line_count(row, &widths, 1)));
+                }
+                if placeholder {
+                    lines.take(1);
+                }
+                if let Some(footer) = &self.footer {
+                    lines.rule(true);
+                    layout.footer = Some(lines.take(self.row_line_count(footer, &widths, 1)));
+                }
+            }
+        }
+        layout.horizontal_borders = lines.rules;
+        layout.height = lines.next;
+        layout
+    }
+}
@@ -0,0 +1,81 @@
+// SPDX-License-Identifier: MIT
+// Project: tabprinter
+// File: src/lazy.rs
+// Author: Volker Schwaberow <volker@schwaberow.de>
+// Copyright (c) 2025 Volker Schwaberow
+
+use std::fmt;
+use std::sync::{Arc, OnceLock};
+
+use crate::Cell;
+
+/// Content computed the first time a cell is displayed and reused afterwards.
+pub(crate) struct LazyContent {
+    /// Produces the content.
+    provider: Box<dyn Fn() -> String + Send + Sync>,
+    /// The content, once computed.
+    value: OnceLock<String>,
+}
+
+impl LazyContent {
+    /// Returns the content, computing it on first use.
+    pub(crate) fn get(&self) -> &str {
+        self.value.get_or_init(|| (self.provider)())
+    }
+}
+
+/// Lazy contents are equal only if they are the same, since providers cannot be compared.
+impl PartialEq for LazyContent {
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::eq(self, other)
+    }
+}
+
+impl Eq for LazyContent {}
+
+impl fmt::Debug for LazyContent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.value.get() {
+            Some(value) => f.debug_tuple("LazyContent").field(value).finish(),
+            None => f.write_str("LazyContent(<pending>)"),
+        }
+    }
+}
+
+impl Cell {
+    /// Creates a cell whose content is produced by `provider` only when the cell is
+    /// displayed, such as a large blob formatted or hashed on demand. The value is
+    /// computed at most once, shared by clones of the cell.
+    /// Measuring a column with `ColumnWidth::Auto` computes all of its cells, so give
+    /// lazy columns a fixed, percent, or ratio width; cells of rows that are then never
+    /// printed or exported, such as rows outside a slice or past a render budget, never
+    /// call the provider.
+    /// Until then `content` is empty, so sorting, filtering, and aggregation do not
+    /// see the value.
+    pub fn lazy<F>(provider: F) -> Self
+    where
+        F: Fn() -> String + Send + Sync + 'static,
+    {
+        let mut cell = Cell::new("");
+        cell.lazy = Some(Arc::new(LazyContent {
+            provider: Box::new(provider),
+            value: OnceLock::new(),
+        }));
+        cell
+    }
+
+    /// Returns whether the cell is lazy and its content has been computed.
+    pub fn is_evaluated(&self) -> bool {
+        self.lazy
+            .as_ref()
+            .is_some_and(|lazy| lazy.value.get().is_some())
+    }
+
+    /// Returns the content shown by the cell, computing lazy content if needed.
+    pub(crate) fn resolved_content(&self) -> &str {
+        match &self.lazy {
+            Some(lazy) => lazy.get(),
+            None => &self.content,
+        }
+    }
+}
@@ -4,11 +4,125 @@
 // Author: Volker Schwaberow <volker@schwaberow.de>
 // Copyright (c) 2024 Volker Schwaberow
 
+mod aggregate;
+#[cfg(feature = "bidi")]
+mod bidi;
+mod boolean;
+mod budget;
+mod category;
+// Without the `color` feature, plain stand-ins for the `termcolor` types that
+// never write escape codes.
+#[cfg(not(feature = "color"))]
+mod color;
+mod column_ref;
+#[cfg(feature = "comfy-table")]
+mod comfy;
+mod correlation;
+mod dedup;
+mod edit;
+mod error;
+mod export;
+mod expr;
+mod fit;
+mod fluent;
+mod gradient;
+mod grid;
+mod histogram;
+#[cfg(feature = "json")]
+mod json;
+mod layout;
+mod lazy;
+mod linear;
+#[cfg(feature = "fs")]
+mod listing;
+mod mask;
+mod merge;
+#[cfg(feature = "json")]
+mod meta;
+mod normalize;
+mod outlier;
+#[cfg(feature = "sysinfo")]
+mod processes;
+mod rank;
+mod record;
+#[cfg(feature = "regex")]
+mod replace;
+mod reporter;
+mod reshape;
+mod rich;
+mod running;
+mod sample;
+mod schema;
+mod shared;
+mod side_by_side;
+mod skeleton;
+mod sort;
+mod split;
+mod stream;
 mod styles;
+mod summary;
+#[cfg(feature = "tabled")]
+mod tabled;
+#[cfg(feature = "csv")]
+mod tail;
+mod template;
+mod text;
+mod theme;
+mod title;
+mod validate;
+mod view;
+mod watch;
 
+use std::any::Any;
+use std::collections::HashMap;
 use std::io::{self, Write};
+use std::ops::{Deref, DerefMut, Range};
+use std::sync::{Arc, Mutex};
 use styles::STYLES;
-use termcolor::{Color, ColorChoice, ColorSpec, StandardStream, WriteColor};
+#[cfg(feature = "color")]
+use termcolor as color;
+use text::{
+    escape_unprintable, expand_tabs, format_bytes, format_duration, mark_edge_whitespace,
+    text_width, truncate, vertical_lines, wrap,
+};
+
+pub use aggregate::{Aggregator, AggregatorRegistry};
+#[cfg(feature = "bidi")]
+pub use bidi::BidiMode;
+pub use boolean::BoolFormat;
+pub use budget::RenderBudget;
+pub use color::{Buffer, Color, ColorChoice, ColorSpec, NoColor, StandardStream, WriteColor};
+pub use column_ref::ColumnRef;
+#[cfg(feature = "csv")]
+pub use csv_support::{CsvLineTerminator, CsvQuoteStyle, CsvWriteOptions};
+pub use error::{AggregateError, TableError};
+pub use export::{HtmlOptions, GITHUB_SUMMARY_COLLAPSE_ROWS};
+pub use gradient::Rgb;
+pub use grid::StyledChar;
+#[cfg(feature = "json")]
+pub use json::ColumnOrderPolicy;
+pub use layout::Layout;
+pub use mask::MaskStyle;
+pub use merge::CellRange;
+pub use normalize::Normalize;
+pub use outlier::OutlierMethod;
+pub use rank::RankMethod;
+pub use record::RecordView;
+pub use reporter::{Outcome, Reporter};
+pub use rich::Span;
+pub use running::Aggregation;
+pub use schema::{ColumnDef, ColumnType, Schema};
+pub use shared::SharedTable;
+pub use side_by_side::{print_side_by_side, print_side_by_side_to_writer};
+pub use sort::{Locale, SortKind, SortOptions};
+pub use stream::{StreamPrinter, WidthPolicy};
+pub use summary::Summary;
+#[cfg(feature = "csv")]
+pub use tail::CsvFollower;
+pub use template::TableTemplate;
+pub use theme::Theme;
+pub use view::TableView;
+pub use watch::{watch, watch_to_writer};
 
 #[cfg(test)]
 mod tests;
@@ -35,7 +149,7 @@ pub enum TableStyle {
     Amiga,
     /// Minimal table style with thin borders.
     Minimal,
-    /// Compact table style with thin borders.
+    /// Compact table style with thin borders and no padding inside the cells.
     Compact,
     /// Markdown style table with markdown syntax.
     Markdown,
@@ -43,8 +157,11 @@ pub enum TableStyle {
     Dotted,
     /// Heavy table style with thick borders.
     Heavy,
-    /// Neon table style with neon-like borders.
+    /// Neon table style with bright magenta and cyan borders.
     Neon,
+    /// Accessible style printing each row as a line of `Header: value` fields,
+    /// readable by screen readers.
+    Accessible,
 }
 
 impl TableStyle {
@@ -70,7 +187,7 @@ impl TableStyle {
 }
 
 /// Represents text alignment within a table cell.
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Alignment {
     /// Left-aligned text.
     Left,
@@ -80,6 +197,131 @@ pub enum Alignment {
     Right,
 }
 
+impl Alignment {
+    /// Splits the fill around aligned text into the parts left and right of it.
+    fn fill(&self, fill: usize) -> (usize, usize) {
+        match self {
+            Alignment::Left => (0, fill),
+            Alignment::Center => (fill / 2, fill - fill / 2),
+            Alignment::Right => (fill, 0),
+        }
+    }
+
+    /// Pads the text to the specified display width according to the alignment.
+    fn pad(&self, text: &str, width: usize) -> String {
+        self.pad_with(text, width, ' ')
+    }
+
+    /// Pads the text to the specified display width with the fill character.
+    fn pad_with(&self, text: &str, width: usize, fill: char) -> String {
+        let (left, right) = self.fill(width.saturating_sub(text_width(text)));
+        let fill = fill.to_string();
+        format!("{}{}{}", fill.repeat(left), text, fill.repeat(right))
+    }
+}
+
+/// Determines what happens to content wider than its column.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OverflowPolicy {
+    /// The content is printed in full and pushes the following borders out.
+    #[default]
+    Overflow,
+    /// The content is cut at the column width and ends with an ellipsis.
+    Truncate,
+    /// The content is broken into several lines at word boundaries.
+    Wrap,
+    /// Printing fails with `TableError::ContentTooWide` instead of misaligning the
+    /// borders, for fixed-format reports.
+    Error,
+}
+
+/// Determines how the header text is laid out.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum HeaderOrientation {
+    /// The header text runs from left to right.
+    #[default]
+    Horizontal,
+    /// The header characters are stacked, one per line, so the header is no wider
+    /// than a single character, as over the columns of a boolean matrix.
+    Vertical,
+}
+
+/// Determines how numeric cell content is displayed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CellFormat {
+    /// The number is shown with the decimal places and thousand separators of the style.
+    #[default]
+    Number,
+    /// The number is a count of bytes, shown with a binary unit such as `12.3 MiB`.
+    /// The decimal places of the style apply to scaled values and default to one.
+    Bytes,
+    /// The number is a count of seconds, shown with its two largest units such as `1m 32s`.
+    Duration,
+}
+
+/// Determines how a table without rows is printed.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum EmptyPolicy {
+    /// The headers and borders are printed without any rows.
+    #[default]
+    ShowHeadersOnly,
+    /// A row spanning all columns shows the text centered, such as `"(no data)"`.
+    Placeholder(String),
+    /// Nothing is printed.
+    Skip,
+}
+
+/// Determines whether the header row is printed again in long tables.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum HeaderRepeat {
+    /// The header is printed once at the top.
+    #[default]
+    Never,
+    /// The header is printed again, between separator lines, before every
+    /// multiple of the given number of data rows.
+    Every(usize),
+}
+
+/// Determines which characters the rendered table may contain.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RenderSafety {
+    /// Text is printed as it is.
+    #[default]
+    Raw,
+    /// Text is limited to printable ASCII; every other character, including control
+    /// characters, is escaped as `\u{...}`. Only the border characters of the style
+    /// are printed beyond ASCII, so output can be embedded in logs and protocols.
+    Strict,
+}
+
+/// Describes how the width of a column is determined.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ColumnWidth {
+    /// A fixed width in characters.
+    Fixed(usize),
+    /// A width that follows the widest header or cell content.
+    Auto,
+    /// A percentage of the width available to the table.
+    Percent(u8),
+    /// A share of the width left over by all other columns.
+    Ratio(u32),
+}
+
+impl From<usize> for ColumnWidth {
+    fn from(width: usize) -> Self {
+        ColumnWidth::Fixed(width)
+    }
+}
+
+/// Returns the width of the terminal, taken from the `COLUMNS` environment variable.
+/// Falls back to 80 characters when the variable is missing or invalid.
+fn terminal_width() -> usize {
+    std::env::var("COLUMNS")
+        .ok()
+        .and_then(|value| value.trim().parse().ok())
+        .unwrap_or(80)
+}
+
 struct LineStyle {
     begin: &'static str,
     hline: &'static str,
@@ -87,11 +329,38 @@ struct LineStyle {
     end: &'static str,
 }
 
+impl LineStyle {
+    /// A line without any characters.
+    const EMPTY: LineStyle = LineStyle {
+        begin: "",
+        hline: "",
+        sep: "",
+        end: "",
+    };
+
+    /// Returns whether the line has no characters at all and is not drawn.
+    fn is_empty(&self) -> bool {
+        self.begin.is_empty() && self.hline.is_empty() && self.sep.is_empty() && self.end.is_empty()
+    }
+}
+
 struct TableStyleConfig {
     top: LineStyle,
     below_header: LineStyle,
+    above_footer: LineStyle,
     bottom: LineStyle,
     row: LineStyle,
+    /// Spaces between the vertical borders and each cell.
+    cell_padding: usize,
+    /// Whether the padding of the individual cells is ignored.
+    compact: bool,
+    /// Bright color of the horizontal lines, if any.
+    line_color: Option<Color>,
+    /// Bright color of the vertical borders of each row, if any.
+    edge_color: Option<Color>,
+    /// The character underlining the header text when header underlines are enabled,
+    /// or an empty string if the style has none.
+    header_underline: &'static str,
 }
 
 /// Represents a column in the table.
@@ -100,9 +369,66 @@ pub struct Column {
     /// The header text of the column.
     header: String,
     /// The width of the column.
-    width: usize,
+    width: ColumnWidth,
     /// The alignment of the text within the column.
     alignment: Alignment,
+    /// The alignment of the header, defaulting to the alignment of the data.
+    header_alignment: Option<Alignment>,
+    /// The overflow policy of the column, overriding the table policy when set.
+    overflow: Option<OverflowPolicy>,
+    /// The overflow policy of the header, overriding the column policy when set.
+    header_overflow: Option<OverflowPolicy>,
+    /// How the values of the column are masked when shown, if at all.
+    mask: Option<MaskStyle>,
+    /// How the truth values of the column are shown and whether they are colored, if set.
+    bool_format: Option<(BoolFormat, bool)>,
+    /// The text attributes given to the cells holding each value.
+    category_styles: HashMap<String, CellStyle>,
+    /// The marker ending truncated text, overriding the table marker when set.
+    truncation_indicator: Option<String>,
+}
+
+/// Tracks the measured content width of every column.
+/// Appended rows are folded in incrementally; other mutations mark the cache dirty.
+#[derive(Debug, Default)]
+struct WidthCache {
+    /// The widest header or cell content per column.
+    measured: Vec<usize>,
+    /// The number of leading rows already folded into `measured`.
+    measured_rows: usize,
+    /// Whether a mutation invalidated the measured widths.
+    dirty: bool,
+}
+
+impl WidthCache {
+    /// Brings the measured widths up to date and returns them.
+    /// Only rows added since the last call are scanned unless the cache is dirty.
+    /// `cell_width` returns the display width of a cell in the column with the given index.
+    fn measure<F>(&mut self, columns: &[Column], rows: &[Row], cell_width: F) -> Vec<usize>
+    where
+        F: Fn(usize, &Cell) -> usize,
+    {
+        if self.dirty || self.measured.len() != columns.len() || self.measured_rows > rows.len() {
+            self.measured = columns
+                .iter()
+                .enumerate()
+                .map(|(i, col)| {
+                    let mut header = Cell::new(&col.header);
+                    header.is_header = true;
+                    cell_width(i, &header)
+                })
+                .collect();
+            self.measured_rows = 0;
+            self.dirty = false;
+        }
+        for row in &rows[self.measured_rows..] {
+            for (i, (width, cell)) in self.measured.iter_mut().zip(row.iter()).enumerate() {
+                *width = (*width).max(cell_width(i, cell));
+            }
+        }
+        self.measured_rows = rows.len();
+        self.measured.clone()
+    }
 }
 
 /// Represents the style of a cell.
@@ -120,6 +446,15 @@ pub struct CellStyle {
     pub decimal_places: Option<usize>,
     /// Whether to use thousand separators for number formatting.
     pub thousand_separator: bool,
+    /// The alignment of the cell, overriding the column alignment when set.
+    pub alignment: Option<Alignment>,
+    /// The character filling the room around the aligned text, such as `.` for
+    /// `Name ....... value` leaders. Control and wide characters fall back to a space.
+    pub fill_char: char,
+    /// How numeric content is displayed.
+    pub format: CellFormat,
+    /// The color of the text, if any.
+    pub color: Option<Color>,
 }
 
 impl CellStyle {
@@ -132,10 +467,20 @@ impl CellStyle {
             padding: 1,
             decimal_places: None,
             thousand_separator: false,
+            alignment: None,
+            fill_char: ' ',
+            format: CellFormat::Number,
+            color: None,
         }
     }
 }
 
+impl Default for CellStyle {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// Represents a cell in the table.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Cell {
@@ -143,6 +488,34 @@ pub struct Cell {
     pub content: String,
     /// The style of the cell.
     pub style: CellStyle,
+    /// The label of the footnote referenced by the cell.
+    pub footnote: Option<String>,
+    /// The styled parts of a rich cell, which together make up its content.
+    pub spans: Vec<Span>,
+    /// Whether the cell shows a column header, which is never masked.
+    is_header: bool,
+    /// The content computed when the cell is first displayed, if any.
+    lazy: Option<Arc<lazy::LazyContent>>,
+}
+
+/// Converts a footnote label into superscript characters where possible.
+fn superscript(label: &str) -> String {
+    label
+        .chars()
+        .map(|c| match c {
+            '0' => '⁰',
+            '1' => '¹',
+            '2' => '²',
+            '3' => '³',
+            '4' => '⁴',
+            '5' => '⁵',
+            '6' => '⁶',
+            '7' => '⁷',
+            '8' => '⁸',
+            '9' => '⁹',
+            other => other,
+        })
+        .collect()
 }
 
 impl Cell {
@@ -151,17 +524,39 @@ impl Cell {
         Self {
             content: content.to_string(),
             style: CellStyle::new(),
+            footnote: None,
+            spans: Vec::new(),
+            is_header: false,
+            lazy: None,
         }
     }
 
-    /// Splits the cell content into lines.
-    fn lines(&self) -> Vec<&str> {
-        self.content.lines().collect()
+    /// Attaches a footnote marker to the cell.
+    /// The label refers to the footnote with the same number set on the table.
+    pub fn with_footnote(mut self, label: &str) -> Self {
+        self.footnote = Some(label.to_string());
+        self
+    }
+
+    /// Returns the text shown in the cell: the formatted content and the footnote marker.
+    fn display_content(&self) -> String {
+        match &self.footnote {
+            Some(label) => format!("{}{}", self.formatted_content(), superscript(label)),
+            None => self.formatted_content(),
+        }
     }
 
     /// Formats the cell content based on the style.
     fn formatted_content(&self) -> String {
-        if let Ok(number) = self.content.parse::<f64>() {
+        let content = self.resolved_content();
+        if let Ok(number) = content.parse::<f64>() {
+            match self.style.format {
+                CellFormat::Bytes if number.is_finite() => {
+                    return format_bytes(number, self.style.decimal_places.unwrap_or(1));
+                }
+                CellFormat::Duration if number.is_finite() => return format_duration(number),
+                _ => {}
+            }
             let mut formatted = if let Some(decimal_places) = self.style.decimal_places {
                 format!("{:.1$}", number, decimal_places)
             } else {
@@ -185,19 +580,115 @@ impl Cell {
             }
             formatted
         } else {
-            self.content.clone()
+            content.to_string()
         }
     }
 }
 
+impl From<&str> for Cell {
+    fn from(content: &str) -> Self {
+        Cell::new(content)
+    }
+}
+
+impl From<String> for Cell {
+    fn from(content: String) -> Self {
+        Cell::new(&content)
+    }
+}
+
+/// Identifies a row independently of its position in the table.
+/// The identifier stays with the row when rows are sorted or filtered.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct RowId(u64);
+
+/// Represents a row of cells together with its identifier.
+#[derive(Clone, Debug)]
+struct Row {
+    /// The identifier of the row.
+    id: RowId,
+    /// The cells of the row.
+    cells: Vec<Cell>,
+}
+
+impl Deref for Row {
+    type Target = Vec<Cell>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.cells
+    }
+}
+
+impl DerefMut for Row {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.cells
+    }
+}
+
 /// Represents a table with columns and rows.
 pub struct Table {
     /// The columns of the table.
     columns: Vec<Column>,
     /// The rows of the table.
-    rows: Vec<Vec<Cell>>,
+    rows: Vec<Row>,
+    /// The identifier assigned to the next added row.
+    next_row_id: u64,
+    /// The application metadata attached to rows.
+    row_tags: HashMap<RowId, Arc<dyn Any + Send + Sync>>,
+    /// The check run on every added row, if any.
+    row_validator: Option<validate::RowValidator>,
+    /// The row positions before which a horizontal rule is drawn.
+    separators: Vec<usize>,
+    /// The blocks of data cells printed as a single cell.
+    merges: Vec<CellRange>,
+    /// The footer row printed after the data rows, such as totals.
+    footer: Option<Vec<Cell>>,
+    /// The footnotes printed below the table, numbered from one.
+    footnotes: Vec<String>,
     /// The style of the table.
     style: TableStyle,
+    /// The colors replacing those of the style.
+    theme: Theme,
+    /// The background colors of the first and last data rows, blended in between.
+    row_gradient: Option<(Rgb, Rgb)>,
+    /// The overflow policy for columns without their own policy.
+    overflow: OverflowPolicy,
+    /// The tab stop interval used to expand tabs in cells, if enabled.
+    tab_width: Option<usize>,
+    /// How bidirectional text is rendered.
+    #[cfg(feature = "bidi")]
+    bidi: BidiMode,
+    /// The total width available to the table, used by relative column widths.
+    /// Defaults to the terminal width when not set.
+    target_width: Option<usize>,
+    /// Whether exports apply the column widths and overflow policies like the terminal.
+    fit_exports: bool,
+    /// How the table is printed when it has no rows.
+    empty_policy: EmptyPolicy,
+    /// The text between the fields of a row in linear output.
+    linear_separator: String,
+    /// Whether whitespace at the start and end of cell lines is made visible.
+    show_whitespace: bool,
+    /// The title with placeholders drawn in the top border, if any.
+    title_template: Option<String>,
+    /// The marker ending truncated text, if not the default ellipsis.
+    truncation_indicator: Option<String>,
+    /// Which characters the rendered text may contain.
+    render_safety: RenderSafety,
+    /// Whether the header is printed again between the data rows.
+    header_repeat: HeaderRepeat,
+    /// Whether the header text is underlined with the style's emphasis character.
+    header_underline: bool,
+    /// Whether the header text runs across or down the columns.
+    header_orientation: HeaderOrientation,
+    /// The columns repeated at the left of every part of a split table.
+    sticky_columns: Vec<usize>,
+    /// The named aggregators available to subtotals and summaries.
+    aggregators: AggregatorRegistry,
+    /// The cached content widths used by automatic column widths.
+    width_cache: Mutex<WidthCache>,
+    /// The cached numeric values used by the aggregations.
+    numeric_cache: Mutex<aggregate::NumericCache>,
 }
 
 impl Table {
@@ -206,55 +697,834 @@ impl Table {
         Self {
             columns: Vec::new(),
             rows: Vec::new(),
+            next_row_id: 0,
+            row_tags: HashMap::new(),
+            row_validator: None,
+            separators: Vec::new(),
+            merges: Vec::new(),
+            footer: None,
+            footnotes: Vec::new(),
             style,
+            theme: Theme::default(),
+            row_gradient: None,
+            overflow: OverflowPolicy::default(),
+            tab_width: None,
+            #[cfg(feature = "bidi")]
+            bidi: BidiMode::default(),
+            target_width: None,
+            fit_exports: false,
+            empty_policy: EmptyPolicy::default(),
+            linear_separator: linear::DEFAULT_LINEAR_SEPARATOR.to_string(),
+            show_whitespace: false,
+            title_template: None,
+            truncation_indicator: None,
+            render_safety: RenderSafety::default(),
+            header_repeat: HeaderRepeat::default(),
+            header_underline: false,
+            header_orientation: HeaderOrientation::default(),
+            sticky_columns: Vec::new(),
+            aggregators: AggregatorRegistry::new(),
+            width_cache: Mutex::new(WidthCache::default()),
+            numeric_cache: Mutex::new(aggregate::NumericCache::default()),
+        }
+    }
+
+    /// Creates a table with the same columns and settings but the given rows.
+    /// Row identifiers and tags are preserved.
+    fn derive_with_rows(&self, rows: Vec<Row>) -> Self {
+        Self {
+            columns: self.columns.clone(),
+            rows,
+            next_row_id: self.next_row_id,
+            row_tags: self.row_tags.clone(),
+            row_validator: self.row_validator.clone(),
+            separators: Vec::new(),
+            merges: Vec::new(),
+            footer: self.footer.clone(),
+            footnotes: self.footnotes.clone(),
+            style: self.style,
+            theme: self.theme,
+            row_gradient: self.row_gradient,
+            overflow: self.overflow,
+            tab_width: self.tab_width,
+            #[cfg(feature = "bidi")]
+            bidi: self.bidi,
+            target_width: self.target_width,
+            fit_exports: self.fit_exports,
+            empty_policy: self.empty_policy.clone(),
+            linear_separator: self.linear_separator.clone(),
+            show_whitespace: self.show_whitespace,
+            title_template: self.title_template.clone(),
+            truncation_indicator: self.truncation_indicator.clone(),
+            render_safety: self.render_safety,
+            header_repeat: self.header_repeat,
+            header_underline: self.header_underline,
+            header_orientation: self.header_orientation,
+            sticky_columns: self.sticky_columns.clone(),
+            aggregators: self.aggregators.clone(),
+            width_cache: Mutex::new(WidthCache::default()),
+            numeric_cache: Mutex::new(aggregate::NumericCache::default()),
+        }
+    }
+
+    /// Creates a full copy of the table, including its separators and merged cells.
+    fn duplicate(&self) -> Self {
+        let mut table = self.derive_with_rows(self.rows.clone());
+        table.separators = self.separators.clone();
+        table.merges = self.merges.clone();
+        table
+    }
+
+    /// Returns the number of characters taken up by borders and spacing around the columns.
+    fn border_overhead(&self) -> usize {
+        let count = self.columns.len();
+        match self.style.config() {
+            Some(style) => {
+                style.row.begin.chars().count()
+                    + style.row.end.chars().count()
+                    + style.row.sep.chars().count() * count.saturating_sub(1)
+                    + 2 * style.cell_padding * count
+            }
+            None => count.saturating_sub(1),
+        }
+    }
+
+    /// Returns the widths used to render each column.
+    /// Automatic widths measure the rows changed since the last render first;
+    /// relative widths share what is left of the target width after the borders.
+    fn column_widths(&self) -> Vec<usize> {
+        let measured = if self
+            .columns
+            .iter()
+            .any(|col| col.width == ColumnWidth::Auto)
+        {
+            let mut cache = self
+                .width_cache
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner());
+            let mut measured = cache.measure(&self.columns, &self.rows, |i, cell| {
+                self.cell_width(i, cell)
+            });
+            if let Some(footer) = &self.footer {
+                for (i, (width, cell)) in measured.iter_mut().zip(footer).enumerate() {
+                    *width = (*width).max(self.cell_width(i, cell));
+                }
+            }
+            measured
+        } else {
+            Vec::new()
+        };
+        let relative = self
+            .columns
+            .iter()
+            .any(|col| matches!(col.width, ColumnWidth::Percent(_) | ColumnWidth::Ratio(_)));
+        let available = if relative {
+            self.target_width
+                .unwrap_or_else(terminal_width)
+                .saturating_sub(self.border_overhead())
+        } else {
+            0
+        };
+        let mut widths: Vec<usize> = self
+            .columns
+            .iter()
+            .enumerate()
+            .map(|(i, col)| match col.width {
+                ColumnWidth::Fixed(width) => width,
+                ColumnWidth::Auto => measured[i] + self.auto_padding(),
+                ColumnWidth::Percent(percent) => (available * percent as usize / 100).max(1),
+                ColumnWidth::Ratio(_) => 0,
+            })
+            .collect();
+        let total_ratio: u32 = self
+            .columns
+            .iter()
+            .map(|col| match col.width {
+                ColumnWidth::Ratio(ratio) => ratio,
+                _ => 0,
+            })
+            .sum();
+        if total_ratio > 0 {
+            let remaining = available.saturating_sub(widths.iter().sum());
+            for (width, col) in widths.iter_mut().zip(&self.columns) {
+                if let ColumnWidth::Ratio(ratio) = col.width {
+                    *width = (remaining * ratio as usize / total_ratio as usize).max(1);
+                }
+            }
         }
+        widths
+    }
+
+    /// Marks the cached content widths and numeric values as stale after a
+    /// non-append mutation.
+    fn invalidate_widths(&mut self) {
+        self.width_cache
+            .get_mut()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .dirty = true;
+        self.invalidate_numbers();
+    }
+
+    /// Sets the total width available to the table.
+    /// Percentage and ratio column widths are resolved against this width.
+    pub fn set_target_width(&mut self, width: usize) {
+        self.target_width = Some(width);
+    }
+
+    /// Resolves percentage and ratio column widths against the terminal width.
+    pub fn fit_to_terminal(&mut self) {
+        self.target_width = None;
+    }
+
+    /// Sets the overflow policy for columns without their own policy.
+    pub fn set_overflow_policy(&mut self, policy: OverflowPolicy) {
+        self.overflow = policy;
+    }
+
+    /// Sets the overflow policy of the specified column.
+    /// Fails if the column does not exist.
+    pub fn set_column_overflow<'a>(
+        &mut self,
+        column: impl Into<ColumnRef<'a>>,
+        policy: OverflowPolicy,
+    ) -> Result<(), TableError> {
+        let column_index = self.column_position(column)?;
+        self.columns[column_index].overflow = Some(policy);
+        Ok(())
+    }
+
+    /// Sets the marker ending truncated text, such as `...` or `>`, instead of `…`.
+    /// The marker counts towards the column width; if it does not fit, text is cut
+    /// without it.
+    pub fn set_truncation_indicator(&mut self, indicator: &str) {
+        self.truncation_indicator = Some(indicator.to_string());
+    }
+
+    /// Sets the marker ending truncated text in the specified column, overriding
+    /// the marker of the table. Fails if the column does not exist.
+    pub fn set_column_truncation_indicator<'a>(
+        &mut self,
+        column: impl Into<ColumnRef<'a>>,
+        indicator: &str,
+    ) -> Result<(), TableError> {
+        let column_index = self.column_position(column)?;
+        self.columns[column_index].truncation_indicator = Some(indicator.to_string());
+        Ok(())
+    }
+
+    /// Returns the marker ending truncated text in the specified column.
+    fn truncation_indicator(&self, column_index: usize) -> String {
+        let indicator = self.columns[column_index]
+            .truncation_indicator
+            .as_ref()
+            .or(self.truncation_indicator.as_ref());
+        match indicator {
+            Some(indicator) => self.safe_text(indicator.clone()),
+            None => self.ellipsis().to_string(),
+        }
+    }
+
+    /// Sets the overflow policy of the header of the specified column, independently
+    /// of its data. A wrapped header, such as `Transactions per Second` over a narrow
+    /// numeric column, is broken into lines at word boundaries and no longer widens an
+    /// automatically sized column beyond its longest word; a truncated header does not
+    /// widen it at all. Fails if the column does not exist.
+    pub fn set_header_overflow<'a>(
+        &mut self,
+        column: impl Into<ColumnRef<'a>>,
+        policy: OverflowPolicy,
+    ) -> Result<(), TableError> {
+        let column_index = self.column_position(column)?;
+        self.columns[column_index].header_overflow = Some(policy);
+        self.invalidate_widths();
+        Ok(())
+    }
+
+    /// Returns the overflow policy applied to the cell in the specified column.
+    fn overflow_policy(&self, column_index: usize, cell: &Cell) -> OverflowPolicy {
+        let column = &self.columns[column_index];
+        match column.header_overflow {
+            Some(policy) if cell.is_header => policy,
+            _ => column.overflow.unwrap_or(self.overflow),
+        }
+    }
+
+    /// Checks that the content of every cell with `OverflowPolicy::Error` fits its
+    /// column, as printing does. Merged cells and accessible output are not checked.
+    pub fn check_widths(&self) -> Result<(), TableError> {
+        self.check_column_widths(&self.column_widths(), 0..self.columns.len())
+    }
+
+    /// Checks the cells of the columns in the range as `check_widths` does,
+    /// against the given widths.
+    pub(crate) fn check_column_widths(
+        &self,
+        widths: &[usize],
+        columns: Range<usize>,
+    ) -> Result<(), TableError> {
+        let header = self.header_cells();
+        let rows = std::iter::once((None, header.as_slice()))
+            .chain(
+                self.rows
+                    .iter()
+                    .enumerate()
+                    .map(|(i, row)| (Some(i), &row[..])),
+            )
+            .chain(self.footer.iter().map(|footer| (None, footer.as_slice())));
+        for (row, cells) in rows {
+            self.check_row_widths(row, cells, widths, columns.clone())?;
+        }
+        Ok(())
+    }
+
+    /// Checks the cells of a row, given by its position or `None` for the header
+    /// and footer, in the columns in the range against the given widths.
+    pub(crate) fn check_row_widths(
+        &self,
+        row: Option<usize>,
+        cells: &[Cell],
+        widths: &[usize],
+        columns: Range<usize>,
+    ) -> Result<(), TableError> {
+        if self.style == TableStyle::Accessible {
+            return Ok(());
+        }
+        for (i, cell) in cells.iter().enumerate() {
+            if !columns.contains(&i)
+                || self.overflow_policy(i, cell) != OverflowPolicy::Error
+                || row.is_some_and(|row| self.merge_at(row, i).is_some())
+            {
+                continue;
+            }
+            let width = self.cell_width(i, cell);
+            let available = self.inner_width(cell, widths[i]);
+            if width > available {
+                return Err(TableError::ContentTooWide {
+                    row,
+                    column: self.columns[i].header.clone(),
+                    width,
+                    available,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Sets the alignment of the header of the specified column, such as centered
+    /// headers over right-aligned numbers. The data alignment is unchanged.
+    /// Fails if the column does not exist.
+    pub fn set_header_alignment<'a>(
+        &mut self,
+        column: impl Into<ColumnRef<'a>>,
+        alignment: Alignment,
+    ) -> Result<(), TableError> {
+        let column_index = self.column_position(column)?;
+        self.columns[column_index].header_alignment = Some(alignment);
+        Ok(())
+    }
+
+    /// Sets how the table is printed when it has no rows.
+    pub fn set_empty_policy(&mut self, policy: EmptyPolicy) {
+        self.empty_policy = policy;
+    }
+
+    /// Sets whether the header is printed again every few rows, so it stays in
+    /// view while long output scrolls past.
+    pub fn set_header_repeat(&mut self, repeat: HeaderRepeat) {
+        self.header_repeat = repeat;
+    }
+
+    /// Sets whether the header text is underlined, as in `Name` over `════`,
+    /// for a report heading look. The underline is drawn inside the header cells,
+    /// below their text, so the borders of the table stay the same.
+    /// Only the bordered styles with an emphasis character draw underlines.
+    pub fn set_header_underline(&mut self, underline: bool) {
+        self.header_underline = underline;
+    }
+
+    /// Sets whether the header text runs across or down the columns. Vertical headers
+    /// stack their characters, so narrow columns keep their width and the header block
+    /// grows as tall as the longest header.
+    pub fn set_header_orientation(&mut self, orientation: HeaderOrientation) {
+        self.header_orientation = orientation;
+        self.invalidate_widths();
+    }
+
+    /// Returns the character underlining the header text, if the header is underlined.
+    fn header_underline(&self) -> Option<&'static str> {
+        let config = self.style.config().filter(|_| self.header_underline)?;
+        match config.header_underline {
+            "" => None,
+            underline if self.render_safety == RenderSafety::Strict && !underline.is_ascii() => {
+                Some("=")
+            }
+            underline => Some(underline),
+        }
+    }
+
+    /// Sets which characters the rendered text may contain.
+    pub fn set_render_safety(&mut self, safety: RenderSafety) {
+        self.render_safety = safety;
+        self.invalidate_widths();
+    }
+
+    /// Returns the text with unprintable characters escaped under strict render safety.
+    fn safe_text(&self, text: String) -> String {
+        match self.render_safety {
+            RenderSafety::Strict => escape_unprintable(&text),
+            RenderSafety::Raw => text,
+        }
+    }
+
+    /// Returns the marker ending truncated text.
+    fn ellipsis(&self) -> &'static str {
+        match self.render_safety {
+            RenderSafety::Strict => "...",
+            RenderSafety::Raw => "…",
+        }
+    }
+
+    /// Returns whether the header is printed again before the specified row.
+    fn repeats_header_before(&self, row_index: usize) -> bool {
+        match self.header_repeat {
+            HeaderRepeat::Every(interval) => row_index > 0 && row_index.is_multiple_of(interval),
+            HeaderRepeat::Never => false,
+        }
+    }
+
+    /// Expands tabs in cell content to the next multiple of `tab_width` columns.
+    pub fn set_tab_width(&mut self, tab_width: usize) {
+        self.tab_width = Some(tab_width);
+        self.invalidate_widths();
+    }
+
+    /// Makes whitespace at the start and end of every line of the data cells visible
+    /// as `·`, or `~` with strict render safety, to find stray spaces in imported data.
+    /// Only printing is affected; exports show the content as it is.
+    pub fn set_show_whitespace(&mut self, show: bool) {
+        self.show_whitespace = show;
+        self.invalidate_widths();
+    }
+
+    /// Returns the text shown for a cell in the specified column.
+    fn cell_text(&self, column_index: usize, cell: &Cell) -> String {
+        let content = self.cell_content(column_index, cell);
+        let content = match self.render_safety {
+            _ if !self.show_whitespace || cell.is_header => content,
+            RenderSafety::Strict => mark_edge_whitespace(&content, '~'),
+            RenderSafety::Raw => mark_edge_whitespace(&content, '·'),
+        };
+        let content = match self.tab_width {
+            Some(tab_width) => expand_tabs(&content, tab_width),
+            None => content,
+        };
+        #[cfg(feature = "bidi")]
+        let content = match self.bidi {
+            BidiMode::Off => content,
+            _ => bidi::reorder(&content),
+        };
+        self.safe_text(content)
+    }
+
+    /// Returns the display width of the widest line of a cell in the specified column.
+    /// Headers with their own overflow policy only count as wide as wrapping or
+    /// truncating them allows, and vertical headers as wide as their widest character.
+    fn cell_width(&self, column_index: usize, cell: &Cell) -> usize {
+        let text = self.cell_text(column_index, cell);
+        if cell.is_header && self.header_orientation == HeaderOrientation::Vertical {
+            return vertical_lines(&text)
+                .iter()
+                .map(|line| text_width(line))
+                .max()
+                .unwrap_or(0);
+        }
+        match self.columns[column_index].header_overflow {
+            Some(OverflowPolicy::Wrap) if cell.is_header => {
+                text.split_whitespace().map(text_width).max().unwrap_or(0)
+            }
+            Some(OverflowPolicy::Truncate) if cell.is_header => 0,
+            _ => text.lines().map(text_width).max().unwrap_or(0),
+        }
+    }
+
+    /// Returns the padding applied on each side of the cell.
+    /// Compact styles ignore the padding of the individual cells.
+    fn cell_padding(&self, cell: &Cell) -> usize {
+        match self.style.config() {
+            Some(style) if style.compact => 0,
+            _ => cell.style.padding,
+        }
+    }
+
+    /// Returns the room added to measured content for automatic widths.
+    fn auto_padding(&self) -> usize {
+        2 * self.cell_padding(&Cell::new(""))
+    }
+
+    /// Returns the lines of the cell after applying the overflow policy for the width.
+    fn fitted_lines(&self, column_index: usize, cell: &Cell, width: usize) -> Vec<String> {
+        let content = self.cell_text(column_index, cell);
+        if cell.is_header && self.header_orientation == HeaderOrientation::Vertical {
+            return vertical_lines(&content);
+        }
+        match self.overflow_policy(column_index, cell) {
+            OverflowPolicy::Overflow | OverflowPolicy::Error => {
+                content.lines().map(str::to_string).collect()
+            }
+            OverflowPolicy::Truncate => {
+                let indicator = self.truncation_indicator(column_index);
+                content
+                    .lines()
+                    .map(|line| truncate(line, width, &indicator))
+                    .collect()
+            }
+            OverflowPolicy::Wrap => wrap(&content, width),
+        }
+    }
+
+    /// Returns the width left for the content of the cell inside its padding.
+    fn inner_width(&self, cell: &Cell, width: usize) -> usize {
+        width.saturating_sub(2 * self.cell_padding(cell))
+    }
+
+    /// Returns the number of printed lines needed by the tallest cell of the row.
+    /// `spacing` is the part of each width reserved for the gap between columns.
+    fn row_line_count(&self, row: &[Cell], widths: &[usize], spacing: usize) -> usize {
+        row.iter()
+            .zip(widths)
+            .enumerate()
+            .map(|(i, (cell, width))| {
+                let inner = self.inner_width(cell, width.saturating_sub(spacing));
+                self.fitted_lines(i, cell, inner).len()
+            })
+            .max()
+            .unwrap_or(1)
+            .max(1)
+    }
+
+    /// Returns the specified line of the cell with its padding, filling exactly the width.
+    fn render_padded_cell_line(
+        &self,
+        column_index: usize,
+        cell: &Cell,
+        line_index: usize,
+        width: usize,
+    ) -> String {
+        let inner = self.inner_width(cell, width);
+        let padding = " ".repeat(self.cell_padding(cell).min(width / 2));
+        format!(
+            "{}{}{}",
+            padding,
+            self.render_cell_line(column_index, cell, line_index, inner),
+            padding
+        )
+    }
+
+    /// Writes border characters in the given bright color, if any.
+    fn write_border(
+        writer: &mut dyn WriteColor,
+        text: &str,
+        color: Option<Color>,
+    ) -> io::Result<()> {
+        match color {
+            Some(color) => {
+                let mut spec = ColorSpec::new();
+                spec.set_fg(Some(color)).set_intense(true);
+                writer.set_color(&spec)?;
+                write!(writer, "{}", text)?;
+                writer.reset()
+            }
+            None => write!(writer, "{}", text),
+        }
+    }
+
+    /// Returns the specified line of the cell, fitted to the width and aligned.
+    fn render_cell_line(
+        &self,
+        column_index: usize,
+        cell: &Cell,
+        line_index: usize,
+        width: usize,
+    ) -> String {
+        let lines = self.fitted_lines(column_index, cell, width);
+        let line = lines.get(line_index).map_or("", String::as_str);
+        let fill = match cell.style.fill_char {
+            fill if fill.is_control() => ' ',
+            fill if self.render_safety == RenderSafety::Strict && !fill.is_ascii_graphic() => ' ',
+            fill if text_width(&fill.to_string()) == 1 => fill,
+            _ => ' ',
+        };
+        self.cell_alignment(column_index, cell)
+            .pad_with(line, width, fill)
+    }
+
+    /// Returns the alignment the cell is printed with.
+    fn cell_alignment(&self, column_index: usize, cell: &Cell) -> Alignment {
+        let alignment = cell
+            .style
+            .alignment
+            .unwrap_or(self.columns[column_index].alignment);
+        #[cfg(feature = "bidi")]
+        let alignment = self.bidi.align(alignment);
+        alignment
+    }
+
+    /// Sets the style used to render the table.
+    pub fn set_style(&mut self, style: TableStyle) {
+        self.style = style;
     }
 
     /// Adds a column to the table.
-    pub fn add_column(&mut self, header: &str, width: usize, alignment: Alignment) {
+    /// The width is either a fixed number of characters or any `ColumnWidth`.
+    pub fn add_column(
+        &mut self,
+        header: &str,
+        width: impl Into<ColumnWidth>,
+        alignment: Alignment,
+    ) {
         self.columns.push(Column {
             header: header.to_string(),
-            width,
+            width: width.into(),
             alignment,
+            header_alignment: None,
+            overflow: None,
+            header_overflow: None,
+            mask: None,
+            bool_format: None,
+            category_styles: HashMap::new(),
+            truncation_indicator: None,
         });
     }
 
     /// Adds a row to the table.
     /// The length of the row must match the number of columns.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length does not match or the row validator rejects the row;
+    /// `try_add_row` returns an error instead.
     pub fn add_row(&mut self, row: Vec<Cell>) {
         assert_eq!(
             self.columns.len(),
             row.len(),
             "Row length must match number of columns"
         );
-        self.rows.push(row);
+        if let Err(error) = self.check_new_row(&row, self.rows.len()) {
+            panic!("{}", error);
+        }
+        let id = self.allocate_row_id();
+        self.rows.push(Row { id, cells: row });
+    }
+
+    /// Returns a fresh row identifier.
+    fn allocate_row_id(&mut self) -> RowId {
+        let id = RowId(self.next_row_id);
+        self.next_row_id += 1;
+        id
+    }
+
+    /// Returns the identifier of the row at the specified position.
+    pub fn row_id(&self, row_index: usize) -> Option<RowId> {
+        self.rows.get(row_index).map(|row| row.id)
+    }
+
+    /// Returns the current position of the row with the specified identifier.
+    pub fn row_index(&self, id: RowId) -> Option<usize> {
+        self.rows.iter().position(|row| row.id == id)
+    }
+
+    /// Attaches application metadata to a row, replacing any previous tag.
+    pub fn set_row_tag<T: Any + Send + Sync>(&mut self, id: RowId, tag: T) {
+        self.row_tags.insert(id, Arc::new(tag));
+    }
+
+    /// Returns the metadata attached to a row if it has the requested type.
+    pub fn row_tag<T: Any + Send + Sync>(&self, id: RowId) -> Option<&T> {
+        self.row_tags.get(&id)?.downcast_ref()
+    }
+
+    /// Returns the number of rows and columns of the table.
+    pub fn shape(&self) -> (usize, usize) {
+        (self.rows.len(), self.columns.len())
+    }
+
+    /// Returns whether the table has no rows.
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    /// Returns the header text of every column.
+    pub fn column_headers(&self) -> Vec<&str> {
+        self.columns
+            .iter()
+            .map(|column| column.header.as_str())
+            .collect()
+    }
+
+    /// Returns an estimate of the heap and inline memory used by the table in bytes.
+    pub fn memory_footprint(&self) -> usize {
+        let columns: usize = self
+            .columns
+            .iter()
+            .map(|column| column.header.capacity())
+            .sum();
+        let cells = |cells: &Vec<Cell>| {
+            cells.capacity() * std::mem::size_of::<Cell>()
+                + cells
+                    .iter()
+                    .map(|cell| {
+                        cell.content.capacity() + cell.footnote.as_ref().map_or(0, String::capacity)
+                    })
+                    .sum::<usize>()
+        };
+        let cells: usize = self
+            .rows
+            .iter()
+            .map(|row| &row.cells)
+            .chain(&self.footer)
+            .map(cells)
+            .sum();
+        std::mem::size_of::<Table>()
+            + self.columns.capacity() * std::mem::size_of::<Column>()
+            + columns
+            + self.rows.capacity() * std::mem::size_of::<Row>()
+            + cells
+            + self.footnotes.iter().map(String::capacity).sum::<usize>()
+    }
+
+    /// Returns the width in characters of the rendered table with the current style.
+    /// Content wider than its column under the `Overflow` policy is not included.
+    pub fn content_width(&self) -> usize {
+        let widths = self.column_widths();
+        let total: usize = widths.iter().sum();
+        match self.style.config() {
+            Some(_) => total + self.border_overhead(),
+            None => total.saturating_sub(1),
+        }
+    }
+
+    /// Adds a horizontal rule after the rows added so far.
+    /// The rule uses the line characters of the table style.
+    /// Separators are dropped when the rows are reordered or regrouped.
+    pub fn add_separator(&mut self) {
+        self.separators.push(self.rows.len());
+    }
+
+    /// Returns whether a separator is drawn before the specified row.
+    fn has_separator_before(&self, row_index: usize) -> bool {
+        self.separators.contains(&row_index)
+    }
+
+    /// Sets the footer row, such as totals, printed after the data rows.
+    /// The footer is separated from the data by the style's footer line.
+    /// The length of the footer must match the number of columns.
+    pub fn set_footer(&mut self, footer: Vec<Cell>) {
+        assert_eq!(
+            self.columns.len(),
+            footer.len(),
+            "Footer length must match number of columns"
+        );
+        self.footer = Some(footer);
+    }
+
+    /// Sets the footnotes printed below the table.
+    /// Footnotes are numbered from one, matching the labels passed to `Cell::with_footnote`.
+    pub fn set_footnotes(&mut self, footnotes: &[&str]) {
+        self.footnotes = footnotes.iter().map(|note| note.to_string()).collect();
+    }
+
+    /// Prints the numbered footnote block.
+    fn print_footnotes(&self, writer: &mut dyn WriteColor) -> io::Result<()> {
+        for (i, note) in self.footnotes.iter().enumerate() {
+            let line = format!("{} {}", superscript(&(i + 1).to_string()), note);
+            writeln!(writer, "{}", self.safe_text(line))?;
+        }
+        Ok(())
     }
 
     /// Auto-adjusts the widths of the columns based on the content.
+    /// Widths keep following the content as rows change until `freeze_widths` is called.
+    /// Use `auto_adjust_widths_within` to keep the table within a total width.
     pub fn auto_adjust_widths(&mut self) {
-        for (i, col) in self.columns.iter_mut().enumerate() {
-            let header_len = col.header.len();
-            let max_cell = self
-                .rows
-                .iter()
-                .map(|row| row[i].content.len())
-                .max()
-                .unwrap_or(0);
-            col.width = header_len.max(max_cell) + 2;
+        for col in self.columns.iter_mut() {
+            col.width = ColumnWidth::Auto;
         }
     }
 
-    /// Sorts the rows by the specified column index.
-    /// If `ascending` is true, sorts in ascending order; otherwise, sorts in descending order.
-    pub fn sort_by_column(&mut self, column_index: usize, ascending: bool) {
-        self.rows.sort_by(|a, b| {
-            let ord = a[column_index].content.cmp(&b[column_index].content);
-            if ascending {
-                ord
-            } else {
-                ord.reverse()
+    /// Fixes every column at its current rendered width.
+    pub fn freeze_widths(&mut self) {
+        let widths = self.column_widths();
+        for (col, width) in self.columns.iter_mut().zip(widths) {
+            col.width = ColumnWidth::Fixed(width);
+        }
+    }
+
+    /// Fixes the width of the specified column, including its padding, such as
+    /// when the user of an interactive tool drags a column border. Wrapped and
+    /// truncated content is laid out again for the new width on the next render.
+    /// Fails without changing the table if the width leaves no room for content
+    /// or the content no longer fits a column with `OverflowPolicy::Error`.
+    pub fn set_column_width<'a>(
+        &mut self,
+        column: impl Into<ColumnRef<'a>>,
+        width: usize,
+    ) -> Result<(), TableError> {
+        let column_index = self.column_position(column)?;
+        let minimum = self.auto_padding() + 1;
+        if width < minimum {
+            return Err(TableError::ColumnTooNarrow {
+                column: self.columns[column_index].header.clone(),
+                width,
+                minimum,
+            });
+        }
+        let previous = std::mem::replace(
+            &mut self.columns[column_index].width,
+            ColumnWidth::Fixed(width),
+        );
+        let widths = self.column_widths();
+        if let Err(error) = self.check_column_widths(&widths, column_index..column_index + 1) {
+            self.columns[column_index].width = previous;
+            return Err(error);
+        }
+        Ok(())
+    }
+
+    /// Widens the specified column by `delta` characters, or narrows it for a
+    /// negative `delta`, starting from its current rendered width. The column
+    /// keeps the new fixed width, and columns sized by percent or ratio are fixed
+    /// at their current widths so the other columns stay where they are; see
+    /// `set_column_width` for when this fails.
+    pub fn resize_column<'a>(
+        &mut self,
+        column: impl Into<ColumnRef<'a>>,
+        delta: isize,
+    ) -> Result<(), TableError> {
+        let column_index = self.column_position(column)?;
+        let widths = self.column_widths();
+        self.set_column_width(
+            column_index,
+            widths[column_index].saturating_add_signed(delta),
+        )?;
+        for (i, (col, width)) in self.columns.iter_mut().zip(widths).enumerate() {
+            if i != column_index
+                && matches!(col.width, ColumnWidth::Percent(_) | ColumnWidth::Ratio(_))
+            {
+                col.width = ColumnWidth::Fixed(width);
             }
-        });
+        }
+        Ok(())
+    }
+
+    /// Sorts the rows by the specified column, given by index or header.
+    /// If `ascending` is true, sorts in ascending order; otherwise, sorts in descending order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no column has the header.
+    pub fn sort_by_column<'a>(&mut self, column: impl Into<ColumnRef<'a>>, ascending: bool) {
+        let column_index = self.resolve_column(column);
+        self.sort_by_column_with(column_index, ascending, &SortOptions::default());
     }
 
     /// Filters the rows using a predicate function.
@@ -263,59 +1533,86 @@ impl Table {
     where
         F: Fn(&Vec<Cell>) -> bool,
     {
-        let filtered = self.rows.iter().cloned().filter(predicate).collect();
-        Self {
-            columns: self.columns.clone(),
-            rows: filtered,
-            style: self.style,
-        }
+        let filtered = self
+            .rows
+            .iter()
+            .filter(|row| predicate(&row.cells))
+            .cloned()
+            .collect();
+        self.derive_with_rows(filtered)
+    }
+
+    /// Groups rows by the specified column, given by index or header, and adds subtotals.
+    pub fn group_by_column_with_subtotals<'a>(&mut self, column: impl Into<ColumnRef<'a>>) {
+        let column_index = self.resolve_column(column);
+        self.group_with(column_index, "Subtotal", &aggregate::sum_aggregator);
     }
 
-    /// Groups rows by the specified column index and adds subtotals.
-    pub fn group_by_column_with_subtotals(&mut self, column_index: usize) {
-        let mut grouped_rows: Vec<Vec<Cell>> = Vec::new();
+    /// Groups rows by the specified column and adds a row labeled `label` after every
+    /// group, computing numeric columns with a fresh aggregator from `create`.
+    fn group_with(
+        &mut self,
+        column_index: usize,
+        label: &str,
+        create: &dyn Fn() -> Box<dyn Aggregator>,
+    ) {
+        let mut grouped_rows: Vec<Row> = Vec::new();
         let mut current_group: Vec<Vec<Cell>> = Vec::new();
         let mut current_value: Option<String> = None;
 
-        for row in &self.rows {
+        for row in std::mem::take(&mut self.rows) {
             let value = &row[column_index].content;
             if current_value.is_none() || current_value.as_ref().unwrap() != value {
                 if !current_group.is_empty() {
-                    let subtotal_row = self.calculate_subtotal(&current_group);
-                    grouped_rows.push(subtotal_row);
+                    let subtotal_row = self.calculate_subtotal(&current_group, label, create);
+                    let id = self.allocate_row_id();
+                    grouped_rows.push(Row {
+                        id,
+                        cells: subtotal_row,
+                    });
                 }
                 current_value = Some(value.clone());
-                grouped_rows.push(row.clone());
                 current_group = Vec::new();
-            } else {
-                grouped_rows.push(row.clone());
             }
-            current_group.push(row.clone());
+            current_group.push(row.cells.clone());
+            grouped_rows.push(row);
         }
 
         if !current_group.is_empty() {
-            let subtotal_row = self.calculate_subtotal(&current_group);
-            grouped_rows.push(subtotal_row);
+            let subtotal_row = self.calculate_subtotal(&current_group, label, create);
+            let id = self.allocate_row_id();
+            grouped_rows.push(Row {
+                id,
+                cells: subtotal_row,
+            });
         }
 
         self.rows = grouped_rows;
+        self.separators.clear();
+        self.merges.clear();
+        self.invalidate_widths();
     }
 
     /// Calculates the subtotal for a group of rows.
-    fn calculate_subtotal(&self, group: &[Vec<Cell>]) -> Vec<Cell> {
+    fn calculate_subtotal(
+        &self,
+        group: &[Vec<Cell>],
+        label: &str,
+        create: &dyn Fn() -> Box<dyn Aggregator>,
+    ) -> Vec<Cell> {
         let mut subtotal_row: Vec<Cell> = Vec::new();
         for (i, _column) in self.columns.iter().enumerate() {
             if i == 0 {
-                subtotal_row.push(Cell::new("Subtotal"));
+                subtotal_row.push(Cell::new(label));
             } else if group
                 .iter()
                 .all(|row| row[i].content.parse::<f64>().is_ok())
             {
-                let subtotal: f64 = group
-                    .iter()
-                    .map(|row| row[i].content.parse::<f64>().unwrap())
-                    .sum();
-                subtotal_row.push(Cell::new(&subtotal.to_string()));
+                let mut aggregator = create();
+                for row in group {
+                    aggregator.feed(row[i].content.parse::<f64>().unwrap());
+                }
+                subtotal_row.push(Cell::new(&aggregator.finish().to_string()));
             } else {
                 subtotal_row.push(Cell::new(""));
             }
@@ -325,6 +1622,13 @@ impl Table {
 
     /// Prints the table to the specified writer.
     pub fn print_to_writer(&self, writer: &mut dyn WriteColor) -> io::Result<()> {
+        if self.skips_printing() {
+            return Ok(());
+        }
+        self.check_widths()?;
+        if self.style == TableStyle::Accessible {
+            return self.print_linear(writer);
+        }
         if let Some(style_cfg) = self.style.config() {
             self.print_styled(writer, style_cfg)
         } else {
@@ -334,8 +1638,13 @@ impl Table {
 
     /// Prints the table with color support.
     pub fn print_color<W: Write + WriteColor>(&self, writer: &mut W) -> io::Result<()> {
+        if self.skips_printing() {
+            return Ok(());
+        }
+        self.check_widths()?;
         match self.style {
             TableStyle::Amiga => self.print_amiga_color(writer),
+            TableStyle::Accessible => self.print_linear(writer),
             _ => {
                 if let Some(style_cfg) = self.style.config() {
                     self.print_styled(writer, style_cfg)
@@ -346,82 +1655,69 @@ impl Table {
         }
     }
 
+    /// Returns whether nothing is printed, either because the table has no columns or
+    /// because it has no rows and the empty policy skips it.
+    fn skips_printing(&self) -> bool {
+        self.columns.is_empty() || (self.rows.is_empty() && self.empty_policy == EmptyPolicy::Skip)
+    }
+
+    /// Prints the placeholder of an empty table centered across the whole table width.
+    /// `begin` and `end` are the borders drawn at either side.
+    fn print_placeholder(
+        &self,
+        writer: &mut dyn WriteColor,
+        begin: &str,
+        end: &str,
+    ) -> io::Result<()> {
+        let EmptyPolicy::Placeholder(text) = &self.empty_policy else {
+            return Ok(());
+        };
+        if !self.rows.is_empty() {
+            return Ok(());
+        }
+        let inner = self
+            .content_width()
+            .saturating_sub(text_width(begin) + text_width(end));
+        writeln!(
+            writer,
+            "{}{}{}",
+            begin,
+            Alignment::Center.pad(&self.safe_text(text.clone()), inner),
+            end
+        )
+    }
+
     /// Prints headers of the table.
-    fn print_headers(&self, writer: &mut dyn WriteColor) -> io::Result<()> {
-        for (i, column) in self.columns.iter().enumerate() {
-            match column.alignment {
-                Alignment::Left => write!(
-                    writer,
-                    "{:<width$}",
-                    column.header,
-                    width = column.width - 1
-                )?,
-                Alignment::Center => write!(
-                    writer,
-                    "{:^width$}",
-                    column.header,
-                    width = column.width - 1
-                )?,
-                Alignment::Right => write!(
+    fn print_headers(&self, writer: &mut dyn WriteColor, widths: &[usize]) -> io::Result<()> {
+        let header = self.header_cells();
+        for line_index in 0..self.row_line_count(&header, widths, 1) {
+            for (i, (cell, width)) in header.iter().zip(widths).enumerate() {
+                write!(
                     writer,
-                    "{:>width$}",
-                    column.header,
-                    width = column.width - 1
-                )?,
-            }
-            if i < self.columns.len() - 1 {
-                write!(writer, " ")?;
+                    "{}",
+                    self.render_padded_cell_line(i, cell, line_index, width - 1)
+                )?;
+                if i < self.columns.len() - 1 {
+                    write!(writer, " ")?;
+                }
             }
+            writeln!(writer)?;
         }
-        writeln!(writer)
+        Ok(())
     }
 
     /// Prints a row of the table.
-    fn print_row(&self, writer: &mut dyn WriteColor, row: &[Cell]) -> io::Result<()> {
-        let max_lines = row.iter().map(|cell| cell.lines().len()).max().unwrap_or(1);
+    fn print_row(
+        &self,
+        writer: &mut dyn WriteColor,
+        row: &[Cell],
+        widths: &[usize],
+        background: Option<Color>,
+    ) -> io::Result<()> {
+        let max_lines = self.row_line_count(row, widths, 1);
         for line_index in 0..max_lines {
-            for (column, cell) in self.columns.iter().zip(row.iter()) {
-                let lines = cell.lines();
-                let _line = lines.get(line_index).unwrap_or(&"");
-                let mut spec = ColorSpec::new();
-                if cell.style.bold {
-                    spec.set_bold(true);
-                }
-                if cell.style.italic {
-                    spec.set_italic(true);
-                }
-                if cell.style.underline {
-                    spec.set_underline(true);
-                }
-                writer.set_color(&spec)?;
-                let padding = " ".repeat(cell.style.padding);
-                let formatted_line = cell.formatted_content();
-                match column.alignment {
-                    Alignment::Left => write!(
-                        writer,
-                        "{}{:width$}{}",
-                        padding,
-                        formatted_line,
-                        padding,
-                        width = column.width - 1
-                    )?,
-                    Alignment::Center => write!(
-                        writer,
-                        "{}{:^width$}{}",
-                        padding,
-                        formatted_line,
-                        padding,
-                        width = column.width - 1
-                    )?,
-                    Alignment::Right => write!(
-                        writer,
-                        "{}{:>width$}{}",
-                        padding,
-                        formatted_line,
-                        padding,
-                        width = column.width - 1
-                    )?,
-                }
+            for (i, (width, cell)) in widths.iter().zip(row.iter()).enumerate() {
+                self.write_cell_line(writer, i, cell, line_index, width - 1, background)?;
                 writer.reset()?;
                 write!(writer, " ")?;
             }
@@ -431,15 +1727,67 @@ impl Table {
     }
 
     /// Prints a line of the table.
-    fn print_line(&self, writer: &mut dyn WriteColor, style: &LineStyle) -> io::Result<()> {
-        write!(writer, "{}", style.begin)?;
-        for (i, column) in self.columns.iter().enumerate() {
+    fn print_line(
+        &self,
+        writer: &mut dyn WriteColor,
+        style: &LineStyle,
+        widths: &[usize],
+    ) -> io::Result<()> {
+        self.print_line_between(writer, style, widths, None, None)
+    }
+
+    /// Prints a horizontal line between the data rows `above` and `below`, or between
+    /// a border and a header or footer where they are `None`. Where a merged block
+    /// hides the vertical border on one side, the junction is drawn without it.
+    fn print_line_between(
+        &self,
+        writer: &mut dyn WriteColor,
+        style: &LineStyle,
+        widths: &[usize],
+        above: Option<usize>,
+        below: Option<usize>,
+    ) -> io::Result<()> {
+        if style.is_empty() {
+            return Ok(());
+        }
+        let color = self.line_color();
+        let line = self.line_text(style, widths, above, below);
+        Self::write_border(writer, &line, color)?;
+        writeln!(writer)
+    }
+
+    /// Returns the text of a horizontal line between the data rows `above` and `below`,
+    /// as printed by `print_line_between`.
+    fn line_text(
+        &self,
+        style: &LineStyle,
+        widths: &[usize],
+        above: Option<usize>,
+        below: Option<usize>,
+    ) -> String {
+        let padding = self.style.config().map_or(1, |config| config.cell_padding);
+        let mut line = style.begin.to_string();
+        for (i, width) in widths.iter().enumerate() {
             if i > 0 {
-                write!(writer, "{}", style.sep)?;
+                let up = self.splits(above, i);
+                let down = self.splits(below, i);
+                line.push_str(styles::junction(style.sep, style.hline, up, down));
             }
-            write!(writer, "{}", style.hline.repeat(column.width + 2))?;
+            line.push_str(&style.hline.repeat(width + 2 * padding));
         }
-        writeln!(writer, "{}", style.end)
+        line.push_str(style.end);
+        line
+    }
+
+    /// Returns the text attributes of the cell on the given background.
+    fn cell_spec(cell: &Cell, background: Option<Color>) -> ColorSpec {
+        let mut spec = ColorSpec::new();
+        spec.set_bg(background)
+            .set_fg(cell.style.color)
+            .set_bold(cell.style.bold)
+            .set_italic(cell.style.italic)
+            .set_underline(cell.style.underline);
+        spec
     }
 
     /// Prints a row of the table with a specific style.
@@ -448,107 +1796,170 @@ impl Table {
         writer: &mut dyn WriteColor,
         row: &[Cell],
         style: &LineStyle,
+        widths: &[usize],
+        background: Option<Color>,
     ) -> io::Result<()> {
-        let max_lines = row.iter().map(|cell| cell.lines().len()).max().unwrap_or(1);
+        let padding = self.style.config().map_or(1, |config| config.cell_padding);
+        let color = self.edge_color();
+        let padding = " ".repeat(padding);
+        let max_lines = self.row_line_count(row, widths, 0);
         for line_index in 0..max_lines {
-            write!(writer, "{}", style.begin)?;
-            for (i, (cell, column)) in row.iter().zip(self.columns.iter()).enumerate() {
+            Self::write_border(writer, style.begin, color)?;
+            for (i, (cell, width)) in row.iter().zip(widths).enumerate() {
                 if i > 0 {
-                    write!(writer, "{}", style.sep)?;
-                }
-                let lines = cell.lines();
-                let _line = lines.get(line_index).unwrap_or(&"");
-                let mut spec = ColorSpec::new();
-                if cell.style.bold {
-                    spec.set_bold(true);
-                }
-                if cell.style.italic {
-                    spec.set_italic(true);
-                }
-                if cell.style.underline {
-                    spec.set_underline(true);
-                }
-                writer.set_color(&spec)?;
-                let padding = " ".repeat(cell.style.padding);
-                let formatted_line = cell.formatted_content();
-                match column.alignment {
-                    Alignment::Left => write!(
-                        writer,
-                        " {}{:width$}{} ",
-                        padding,
-                        formatted_line,
-                        padding,
-                        width = column.width
-                    )?,
-                    Alignment::Center => write!(
-                        writer,
-                        " {}{:^width$}{} ",
-                        padding,
-                        formatted_line,
-                        padding,
-                        width = column.width
-                    )?,
-                    Alignment::Right => write!(
-                        writer,
-                        " {}{:>width$}{} ",
-                        padding,
-                        formatted_line,
-                        padding,
-                        width = column.width
-                    )?,
+                    Self::write_border(writer, style.sep, color)?;
                 }
+                writer.set_color(&Self::cell_spec(cell, background))?;
+                write!(writer, "{}", padding)?;
+                self.write_cell_line(writer, i, cell, line_index, *width, background)?;
+                write!(writer, "{}", padding)?;
                 writer.reset()?;
             }
-            writeln!(writer, "{}", style.end)?;
+            Self::write_border(writer, style.end, color)?;
+            writeln!(writer)?;
         }
         Ok(())
     }
 
+    /// Prints the header row of a bordered table, followed by its underline if enabled.
+    fn print_header_styled(
+        &self,
+        writer: &mut dyn WriteColor,
+        style: &LineStyle,
+        widths: &[usize],
+    ) -> io::Result<()> {
+        let header = self.header_cells();
+        self.print_row_styled(writer, &header, style, widths, None)?;
+        let Some(underline) = self.header_underline() else {
+            return Ok(());
+        };
+        let padding = self.style.config().map_or(1, |config| config.cell_padding);
+        let color = self.line_color();
+        let padding = " ".repeat(padding);
+        let mut line = style.begin.to_string();
+        for (i, (cell, width)) in header.iter().zip(widths).enumerate() {
+            if i > 0 {
+                line.push_str(style.sep);
+            }
+            let inner = self.inner_width(cell, *width);
+            let text = self
+                .fitted_lines(i, cell, inner)
+                .iter()
+                .map(|line| text_width(line))
+                .max()
+                .unwrap_or(0);
+            let cell_padding = " ".repeat(self.cell_padding(cell).min(width / 2));
+            let underline = self
+                .cell_alignment(i, cell)
+                .pad(&underline.repeat(text), inner);
+            line.push_str(&format!(
+                "{padding}{cell_padding}{underline}{cell_padding}{padding}"
+            ));
+        }
+        line.push_str(style.end);
+        Self::write_border(writer, &line, color)?;
+        writeln!(writer)
+    }
+
     /// Prints the table to the specified writer with simple style.
     fn print_simple(&self, writer: &mut dyn WriteColor) -> io::Result<()> {
-        self.print_headers(writer)?;
-        for row in &self.rows {
-            self.print_row(writer, row)?;
+        let widths = self.column_widths();
+        self.print_top(writer, &LineStyle::EMPTY, &widths)?;
+        self.print_headers(writer, &widths)?;
+        for (i, row) in self.rows.iter().enumerate() {
+            if self.repeats_header_before(i) {
+                self.print_simple_separator(writer, &widths)?;
+                self.print_headers(writer, &widths)?;
+            } else if self.has_separator_before(i) {
+                self.print_simple_separator(writer, &widths)?;
+            }
+            self.print_row(writer, row, &widths, self.row_background(i))?;
+        }
+        self.print_placeholder(writer, "", "")?;
+        self.print_simple_footer(writer, &widths)?;
+        self.print_footnotes(writer)
+    }
+
+    /// Prints the footer row below a dashed separator for styles without line characters.
+    fn print_simple_footer(&self, writer: &mut dyn WriteColor, widths: &[usize]) -> io::Result<()> {
+        if let Some(footer) = &self.footer {
+            self.print_simple_separator(writer, widths)?;
+            self.print_row(writer, footer, widths, None)?;
         }
         Ok(())
     }
 
+    /// Prints a dashed separator for styles without line characters.
+    fn print_simple_separator(
+        &self,
+        writer: &mut dyn WriteColor,
+        widths: &[usize],
+    ) -> io::Result<()> {
+        let rule: Vec<String> = widths.iter().map(|width| "-".repeat(width - 1)).collect();
+        writeln!(writer, "{}", rule.join(" "))
+    }
+
+    /// Returns the column headers as cells.
+    fn header_cells(&self) -> Vec<Cell> {
+        self.columns
+            .iter()
+            .map(|c| {
+                let mut cell = Cell::new(&c.header);
+                cell.is_header = true;
+                cell.style.color = self.theme.header_color;
+                cell.style.alignment = Some(c.header_alignment.unwrap_or(c.alignment));
+                cell
+            })
+            .collect()
+    }
+
     /// Prints the table to the specified writer with styled style.
     fn print_styled(
         &self,
         writer: &mut dyn WriteColor,
         style: &TableStyleConfig,
     ) -> io::Result<()> {
-        self.print_line(writer, &style.top)?;
-        self.print_row_styled(
-            writer,
-            &self
-                .columns
-                .iter()
-                .map(|c| Cell::new(&c.header))
-                .collect::<Vec<_>>(),
-            &style.row,
-        )?;
-        self.print_line(writer, &style.below_header)?;
-        for row in &self.rows {
-            self.print_row_styled(writer, row, &style.row)?;
+        let widths = self.column_widths();
+        self.print_top(writer, &style.top, &widths)?;
+        self.print_header_styled(writer, &style.row, &widths)?;
+        let first = (!self.rows.is_empty()).then_some(0);
+        let last = self.rows.len().checked_sub(1);
+        self.print_line_between(writer, &style.below_header, &widths, None, first)?;
+        self.print_data_rows_styled(writer, &style.row, &style.below_header, &widths)?;
+        self.print_placeholder(writer, style.row.begin, style.row.end)?;
+        if let Some(footer) = &self.footer {
+            self.print_line_between(writer, &style.above_footer, &widths, last, None)?;
+            self.print_row_styled(writer, footer, &style.row, &widths, None)?;
+            self.print_line(writer, &style.bottom, &widths)?;
+        } else {
+            self.print_line_between(writer, &style.bottom, &widths, last, None)?;
         }
-        self.print_line(writer, &style.bottom)
+        self.print_footnotes(writer)
     }
 
     /// Prints the table to the standard output with simple style.
     fn print_amiga_color<W: Write + WriteColor>(&self, writer: &mut W) -> io::Result<()> {
+        let widths = self.column_widths();
+        self.print_top(writer, &LineStyle::EMPTY, &widths)?;
         let mut spec = ColorSpec::new();
         spec.set_fg(Some(Color::Blue));
         writer.set_color(&spec)?;
-        self.print_headers(writer)?;
+        self.print_headers(writer, &widths)?;
         spec.set_fg(Some(Color::White));
         writer.set_color(&spec)?;
-        for row in &self.rows {
-            self.print_row(writer, row)?;
+        for (i, row) in self.rows.iter().enumerate() {
+            if self.repeats_header_before(i) {
+                self.print_simple_separator(writer, &widths)?;
+                self.print_headers(writer, &widths)?;
+            } else if self.has_separator_before(i) {
+                self.print_simple_separator(writer, &widths)?;
+            }
+            self.print_row(writer, row, &widths, self.row_background(i))?;
         }
+        self.print_placeholder(writer, "", "")?;
+        self.print_simple_footer(writer, &widths)?;
         writer.reset()?;
-        Ok(())
+        self.print_footnotes(writer)
     }
 
     /// Prints the table to the standard output with color support.
@@ -558,10 +1969,15 @@ impl Table {
     }
 
     /// Aggregates the specified column using the provided aggregation function.
-    pub fn aggregate_column<F>(&self, column_index: usize, aggregation_fn: F) -> Option<f64>
+    pub fn aggregate_column<'a, F>(
+        &self,
+        column: impl Into<ColumnRef<'a>>,
+        aggregation_fn: F,
+    ) -> Option<f64>
     where
         F: Fn(Vec<f64>) -> f64,
     {
+        let column_index = self.resolve_column(column);
         let values: Vec<f64> = self
             .rows
             .iter()
@@ -574,36 +1990,32 @@ impl Table {
         }
     }
 
-    /// Calculates the sum of the specified column.
-    pub fn sum_column(&self, column_index: usize) -> Option<f64> {
-        self.aggregate_column(column_index, |values| values.iter().sum())
+    /// Calculates the sum of the specified column, skipping cells that are not numbers.
+    /// Same as `sum_column_lossy`; use `try_sum_column` to detect unparsable cells.
+    pub fn sum_column<'a>(&self, column: impl Into<ColumnRef<'a>>) -> Option<f64> {
+        let column_index = self.resolve_column(column);
+        self.sum_column_lossy(column_index)
     }
 
-    /// Calculates the average of the specified column.
-    pub fn average_column(&self, column_index: usize) -> Option<f64> {
-        self.aggregate_column(column_index, |values| {
-            values.iter().sum::<f64>() / values.len() as f64
-        })
+    /// Calculates the average of the specified column, skipping cells that are not numbers.
+    /// Same as `average_column_lossy`; use `try_average_column` to detect unparsable cells.
+    pub fn average_column<'a>(&self, column: impl Into<ColumnRef<'a>>) -> Option<f64> {
+        let column_index = self.resolve_column(column);
+        self.average_column_lossy(column_index)
     }
 
-    /// Finds the minimum value in the specified column.
-    pub fn min_column(&self, column_index: usize) -> Option<f64> {
-        self.aggregate_column(column_index, |values| {
-            *values
-                .iter()
-                .min_by(|a, b| a.partial_cmp(b).unwrap())
-                .unwrap()
-        })
+    /// Finds the minimum value in the specified column, skipping cells that are not numbers.
+    /// Same as `min_column_lossy`; use `try_min_column` to detect unparsable cells.
+    pub fn min_column<'a>(&self, column: impl Into<ColumnRef<'a>>) -> Option<f64> {
+        let column_index = self.resolve_column(column);
+        self.min_column_lossy(column_index)
     }
 
-    /// Finds the maximum value in the specified column.
-    pub fn max_column(&self, column_index: usize) -> Option<f64> {
-        self.aggregate_column(column_index, |values| {
-            *values
-                .iter()
-                .max_by(|a, b| a.partial_cmp(b).unwrap())
-                .unwrap()
-        })
+    /// Finds the maximum value in the specified column, skipping cells that are not numbers.
+    /// Same as `max_column_lossy`; use `try_max_column` to detect unparsable cells.
+    pub fn max_column<'a>(&self, column: impl Into<ColumnRef<'a>>) -> Option<f64> {
+        let column_index = self.resolve_column(column);
+        self.max_column_lossy(column_index)
     }
 }
 
@@ -612,30 +2024,124 @@ mod csv_support {
     use super::*;
     pub use csv;
 
+    /// Determines which fields are quoted when writing CSV.
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+    pub enum CsvQuoteStyle {
+        /// Only fields containing delimiters, quotes, or line breaks are quoted.
+        #[default]
+        Necessary,
+        /// Every field is quoted.
+        Always,
+        /// Every field that is not a number is quoted.
+        NonNumeric,
+        /// No field is quoted.
+        Never,
+    }
+
+    /// The line ending written after every CSV record.
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+    pub enum CsvLineTerminator {
+        /// A line feed (`\n`).
+        #[default]
+        Lf,
+        /// A carriage return and line feed (`\r\n`).
+        Crlf,
+    }
+
+    /// Options controlling how a table is written as CSV.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct CsvWriteOptions {
+        /// Whether the column headers are written as the first record.
+        pub write_headers: bool,
+        /// The byte separating fields.
+        pub delimiter: u8,
+        /// Which fields are quoted.
+        pub quote_style: CsvQuoteStyle,
+        /// The line ending of every record.
+        pub line_terminator: CsvLineTerminator,
+    }
+
+    impl Default for CsvWriteOptions {
+        fn default() -> Self {
+            Self {
+                write_headers: true,
+                delimiter: b',',
+                quote_style: CsvQuoteStyle::default(),
+                line_terminator: CsvLineTerminator::default(),
+            }
+        }
+    }
+
     impl Table {
         /// Creates a table from a CSV file.
         /// The first row of the CSV file is used as the header.
         pub fn from_csv(path: &str) -> io::Result<Self> {
+            Table::from_csv_with_progress(path, |_, _| {})
+        }
+
+        /// Creates a table from a CSV file, calling `progress` with the number of bytes
+        /// read and rows loaded so far after every row, so long imports can report progress.
+        pub fn from_csv_with_progress<F>(path: &str, mut progress: F) -> io::Result<Self>
+        where
+            F: FnMut(u64, usize),
+        {
             let mut reader = csv::Reader::from_path(path)?;
             let headers = reader.headers()?;
             let mut table = Table::new(TableStyle::Simple);
             for header in headers {
                 table.add_column(header, 10, Alignment::Left);
             }
-            for result in reader.records() {
-                let record = result?;
-                table.add_row(record.iter().map(|s| Cell::new(s)).collect());
+            let mut record = csv::StringRecord::new();
+            while reader.read_record(&mut record)? {
+                table.add_row(record.iter().map(Cell::new).collect());
+                progress(reader.position().byte(), table.rows.len());
             }
             Ok(table)
         }
 
-        /// Writes the table to a CSV file.
+        /// Creates a table from a CSV file, converting the columns named in the schema.
+        /// Cells that cannot be parsed fail the import with an `InvalidData` error.
+        pub fn from_csv_with_schema(path: &str, schema: &Schema) -> io::Result<Self> {
+            let mut table = Table::from_csv(path)?;
+            table
+                .apply_schema(schema)
+                .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+            Ok(table)
+        }
+
+        /// Writes the table to a CSV file with a header row.
         pub fn to_csv(&self, path: &str) -> io::Result<()> {
-            let mut writer = csv::Writer::from_path(path)?;
+            self.to_csv_with_options(path, &CsvWriteOptions::default())
+        }
+
+        /// Writes the table to a CSV file using the given options.
+        pub fn to_csv_with_options(&self, path: &str, options: &CsvWriteOptions) -> io::Result<()> {
+            let mut writer = csv::WriterBuilder::new()
+                .delimiter(options.delimiter)
+                .quote_style(match options.quote_style {
+                    CsvQuoteStyle::Necessary => csv::QuoteStyle::Necessary,
+                    CsvQuoteStyle::Always => csv::QuoteStyle::Always,
+                    CsvQuoteStyle::NonNumeric => csv::QuoteStyle::NonNumeric,
+                    CsvQuoteStyle::Never => csv::QuoteStyle::Never,
+                })
+                .terminator(match options.line_terminator {
+                    CsvLineTerminator::Lf => csv::Terminator::Any(b'\n'),
+                    CsvLineTerminator::Crlf => csv::Terminator::CRLF,
+                })
+                .from_path(path)?;
+            if options.write_headers {
+                writer.write_record(self.columns.iter().map(|column| &column.header))?;
+            }
             for row in &self.rows {
-                writer.write_record(row.iter().map(|cell| &cell.content))?;
+                writer.write_record(row.iter().enumerate().map(|(i, cell)| {
+                    let content = cell.resolved_content();
+                    match self.columns[i].mask {
+                        Some(mask) => mask.apply(content),
+                        None => content.to_string(),
+                    }
+                }))?;
             }
-            Ok(writer.flush()?)
+            writer.flush()
         }
     }
 }
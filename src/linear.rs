@@ -0,0 +1,61 @@
+// SPDX-License-Identifier: MIT
+// Project: tabprinter
+// File: src/linear.rs
+// Author: Volker Schwaberow <volker@schwaberow.de>
+// Copyright (c) 2025 Volker Schwaberow
+
+use std::io;
+
+use crate::color::WriteColor;
+use crate::{Cell, Table};
+
+/// The text placed between the fields of a linear row by default.
+pub(crate) const DEFAULT_LINEAR_SEPARATOR: &str = "; ";
+
+impl Table {
+    /// Sets the text placed between the fields of each row by `to_linear_text`
+    /// and the accessible style. Defaults to `"; "`.
+    pub fn set_linear_separator(&mut self, separator: &str) {
+        self.linear_separator = separator.to_string();
+    }
+
+    /// Returns the row as a single line of `Header: value` fields.
+    /// Lines of multi-line cells are joined with spaces.
+    pub(crate) fn linear_line(&self, row: &[Cell]) -> String {
+        let fields: Vec<String> = self
+            .columns
+            .iter()
+            .zip(row)
+            .enumerate()
+            .map(|(i, (column, cell))| {
+                let content = self.cell_content(i, cell);
+                let value: Vec<&str> = content.lines().collect();
+                format!("{}: {}", column.header, value.join(" "))
+            })
+            .collect();
+        self.safe_text(fields.join(&self.linear_separator))
+    }
+
+    /// Exports the table as one line per row, such as
+    /// `Name: Alice; Age: 30; City: New York`, which screen readers can follow
+    /// where box drawing gets in the way. The footer, if any, is the last line.
+    pub fn to_linear_text(&self) -> String {
+        let mut text = String::new();
+        for row in self
+            .rows
+            .iter()
+            .map(|row| row.cells.as_slice())
+            .chain(self.footer.as_deref())
+        {
+            text.push_str(&self.linear_line(row));
+            text.push('\n');
+        }
+        text
+    }
+
+    /// Prints the table as linear text, followed by the footnotes.
+    pub(crate) fn print_linear(&self, writer: &mut dyn WriteColor) -> io::Result<()> {
+        write!(writer, "{}", self.to_linear_text())?;
+        self.print_footnotes(writer)
+    }
+}
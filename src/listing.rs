@@ -0,0 +1,95 @@
+// SPDX-License-Identifier: MIT
+// Project: tabprinter
+// File: src/listing.rs
+// Author: Volker Schwaberow <volker@schwaberow.de>
+// Copyright (c) 2025 Volker Schwaberow
+
+use std::fs::{self, Metadata};
+use std::io;
+use std::time::UNIX_EPOCH;
+
+use crate::title::format_utc;
+use crate::{Alignment, Cell, CellFormat, ColumnWidth, Table, TableStyle};
+
+/// Returns the permissions of the entry as `ls -l` shows them, such as `drwxr-xr-x`.
+#[cfg(unix)]
+fn permissions(metadata: &Metadata) -> String {
+    use std::os::unix::fs::PermissionsExt;
+
+    let kind = if metadata.is_dir() {
+        'd'
+    } else if metadata.file_type().is_symlink() {
+        'l'
+    } else {
+        '-'
+    };
+    let mode = metadata.permissions().mode();
+    let mut text = String::from(kind);
+    for shift in [6, 3, 0] {
+        let bits = mode >> shift;
+        text.push(if bits & 4 != 0 { 'r' } else { '-' });
+        text.push(if bits & 2 != 0 { 'w' } else { '-' });
+        text.push(if bits & 1 != 0 { 'x' } else { '-' });
+    }
+    text
+}
+
+/// Returns the permissions of the entry as `d` for directories followed by
+/// `r--` for read-only entries and `rw-` for writable ones.
+#[cfg(not(unix))]
+fn permissions(metadata: &Metadata) -> String {
+    let kind = if metadata.is_dir() { 'd' } else { '-' };
+    let access = if metadata.permissions().readonly() {
+        "r--"
+    } else {
+        "rw-"
+    };
+    format!("{}{}", kind, access)
+}
+
+impl Table {
+    /// Lists the entries of the directory with the columns `Name`, `Size`,
+    /// `Modified`, and `Permissions`, sorted by name. Directory names end in `/`
+    /// and have no size. Sizes keep the number of bytes as content and are shown
+    /// with binary units, so sorting and aggregating the column work on bytes.
+    /// Modification times are shown in UTC, which sorts chronologically as text.
+    /// Symbolic links are listed, not followed.
+    pub fn from_dir(path: &str) -> io::Result<Self> {
+        let mut entries = Vec::new();
+        for entry in fs::read_dir(path)? {
+            let entry = entry?;
+            let metadata = entry.metadata()?;
+            let mut name = entry.file_name().to_string_lossy().into_owned();
+            if metadata.is_dir() {
+                name.push('/');
+            }
+            entries.push((name, metadata));
+        }
+        entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+        let mut table = Table::new(TableStyle::Simple);
+        table.add_column("Name", ColumnWidth::Auto, Alignment::Left);
+        table.add_column("Size", ColumnWidth::Auto, Alignment::Right);
+        table.add_column("Modified", ColumnWidth::Auto, Alignment::Left);
+        table.add_column("Permissions", ColumnWidth::Auto, Alignment::Left);
+        for (name, metadata) in entries {
+            let mut size = Cell::new("");
+            if !metadata.is_dir() {
+                size = Cell::new(&metadata.len().to_string());
+                size.style.format = CellFormat::Bytes;
+            }
+            let modified = metadata
+                .modified()
+                .ok()
+                .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+                .map_or_else(String::new, |elapsed| format_utc(elapsed.as_secs()));
+            table.add_row(vec![
+                Cell::new(&name),
+                size,
+                Cell::new(&modified),
+                Cell::new(&permissions(&metadata)),
+            ]);
+        }
+        Ok(table)
+    }
+}
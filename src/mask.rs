@@ -0,0 +1,85 @@
+// SPDX-License-Identifier: MIT
+// Project: tabprinter
+// File: src/mask.rs
+// Author: Volker Schwaberow <volker@schwaberow.de>
+// Copyright (c) 2025 Volker Schwaberow
+
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::{superscript, Cell, ColumnRef, Table, TableError};
+
+/// The character replacing hidden content.
+const MASK_CHAR: char = '*';
+
+/// The number of mask characters shown for fully masked values.
+const FULL_MASK_LEN: usize = 8;
+
+/// Describes how the values of a sensitive column are hidden.
+/// Masking applies when the table is printed or exported; the stored cells keep
+/// their original content.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MaskStyle {
+    /// Every value is replaced by a fixed number of mask characters, hiding its length.
+    Full,
+    /// All but the last `keep_last` characters are replaced by mask characters.
+    Partial {
+        /// The number of trailing characters left visible.
+        keep_last: usize,
+    },
+    /// Every value is replaced by a short fingerprint, so equal values stay recognizable.
+    Hash,
+}
+
+impl MaskStyle {
+    /// Returns the masked form of the text.
+    pub(crate) fn apply(&self, text: &str) -> String {
+        match self {
+            MaskStyle::Full => MASK_CHAR.to_string().repeat(FULL_MASK_LEN),
+            MaskStyle::Partial { keep_last } => {
+                let graphemes: Vec<&str> = text.graphemes(true).collect();
+                let hidden = graphemes.len().saturating_sub(*keep_last);
+                let mut masked = MASK_CHAR.to_string().repeat(hidden);
+                masked.push_str(&graphemes[hidden..].concat());
+                masked
+            }
+            MaskStyle::Hash => format!("#{:08x}", fingerprint(text)),
+        }
+    }
+}
+
+/// Returns a 32-bit FNV-1a hash of the text, stable across platforms and releases.
+fn fingerprint(text: &str) -> u32 {
+    text.bytes().fold(0x811c_9dc5, |hash, byte| {
+        (hash ^ u32::from(byte)).wrapping_mul(0x0100_0193)
+    })
+}
+
+impl Table {
+    /// Masks the values of the specified column in every render path and export.
+    pub fn mask_column<'a>(
+        &mut self,
+        column: impl Into<ColumnRef<'a>>,
+        style: MaskStyle,
+    ) -> Result<(), TableError> {
+        let column_index = self.column_position(column)?;
+        self.columns[column_index].mask = Some(style);
+        self.invalidate_widths();
+        Ok(())
+    }
+
+    /// Returns the content of a cell as shown in the specified column, labeled by the
+    /// boolean format of the column and masked if needed. Headers are shown as they are.
+    pub(crate) fn cell_content(&self, column_index: usize, cell: &Cell) -> String {
+        let content = match self.bool_label(column_index, cell) {
+            Some(label) => match &cell.footnote {
+                Some(footnote) => format!("{}{}", label, superscript(footnote)),
+                None => label.to_string(),
+            },
+            None => cell.display_content(),
+        };
+        match self.columns[column_index].mask {
+            Some(mask) if !cell.is_header => mask.apply(&content),
+            _ => content,
+        }
+    }
+}
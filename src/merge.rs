@@ -0,0 +1,282 @@
+// SPDX-License-Identifier: MIT
+// Project: tabprinter
+// File: src/merge.rs
+// Author: Volker Schwaberow <volker@schwaberow.de>
+// Copyright (c) 2025 Volker Schwaberow
+
+use std::borrow::Cow;
+use std::io;
+use std::ops::Range;
+
+use crate::color::WriteColor;
+use crate::text::text_width;
+use crate::{Alignment, Cell, LineStyle, Table, TableError};
+
+/// A rectangular block of data cells, given as half-open row and column ranges.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CellRange {
+    /// The rows of the block.
+    pub rows: Range<usize>,
+    /// The columns of the block.
+    pub columns: Range<usize>,
+}
+
+impl CellRange {
+    /// Creates a block from its row and column ranges.
+    pub fn new(rows: Range<usize>, columns: Range<usize>) -> Self {
+        Self { rows, columns }
+    }
+
+    /// Returns whether the block contains the cell.
+    fn contains(&self, row: usize, column: usize) -> bool {
+        self.rows.contains(&row) && self.columns.contains(&column)
+    }
+
+    /// Returns whether the blocks share at least one cell.
+    fn overlaps(&self, other: &CellRange) -> bool {
+        self.rows.start < other.rows.end
+            && other.rows.start < self.rows.end
+            && self.columns.start < other.columns.end
+            && other.columns.start < self.columns.end
+    }
+}
+
+/// A part of a printed row: a single cell or the slice of a merged block.
+enum Segment<'a> {
+    Cell(usize),
+    Merged(&'a CellRange),
+}
+
+impl Table {
+    /// Merges each block of cells into one cell spanning the block, printed with the
+    /// content of its top-left cell centered horizontally and vertically.
+    /// The other cells of a block are cleared. Merged cells are drawn by the bordered
+    /// styles; the simple styles and exports show the top-left cell in its column.
+    /// Sorting, grouping, and removing duplicates undo all merges.
+    pub fn merge_cells(&mut self, ranges: &[CellRange]) -> Result<(), TableError> {
+        for (i, range) in ranges.iter().enumerate() {
+            if range.rows.is_empty() || range.columns.is_empty() {
+                return Err(TableError::InvalidMerge(format!(
+                    "the block {:?} × {:?} is empty",
+                    range.rows, range.columns
+                )));
+            }
+            self.check_row(range.rows.end - 1)?;
+            self.check_column(range.columns.end - 1)?;
+            if self
+                .merges
+                .iter()
+                .chain(&ranges[..i])
+                .any(|other| other.overlaps(range))
+            {
+                return Err(TableError::InvalidMerge(format!(
+                    "the block {:?} × {:?} overlaps another merged block",
+                    range.rows, range.columns
+                )));
+            }
+        }
+        for range in ranges {
+            for row in range.rows.clone() {
+                for column in range.columns.clone() {
+                    if (row, column) != (range.rows.start, range.columns.start) {
+                        self.rows[row][column].content.clear();
+                    }
+                }
+            }
+            self.merges.push(range.clone());
+        }
+        self.invalidate_widths();
+        Ok(())
+    }
+
+    /// Returns the merged block covering the cell, if any.
+    /// Blocks left outside the table by later edits are ignored.
+    pub(crate) fn merge_at(&self, row: usize, column: usize) -> Option<&CellRange> {
+        self.merges.iter().find(|range| {
+            range.rows.end <= self.rows.len()
+                && range.columns.end <= self.columns.len()
+                && range.contains(row, column)
+        })
+    }
+
+    /// Returns whether the vertical border left of the column is drawn through the
+    /// data row. Borders of header and footer rows, given as `None`, are always drawn.
+    pub(crate) fn splits(&self, row: Option<usize>, column: usize) -> bool {
+        match row.and_then(|row| self.merge_at(row, column)) {
+            Some(range) => range.columns.start == column,
+            None => true,
+        }
+    }
+
+    /// Splits the row into single cells and the slices of merged blocks.
+    fn segments(&self, row: usize) -> Vec<Segment<'_>> {
+        let mut segments = Vec::new();
+        let mut column = 0;
+        while column < self.columns.len() {
+            match self.merge_at(row, column) {
+                Some(range) => {
+                    segments.push(Segment::Merged(range));
+                    column = range.columns.end;
+                }
+                None => {
+                    segments.push(Segment::Cell(column));
+                    column += 1;
+                }
+            }
+        }
+        segments
+    }
+
+    /// Returns the width of the text of a merged block, including the borders
+    /// and padding between the columns it spans.
+    fn merged_width(
+        &self,
+        range: &CellRange,
+        widths: &[usize],
+        sep: &str,
+        padding: usize,
+    ) -> usize {
+        let spanned = range.columns.len();
+        widths[range.columns.clone()].iter().sum::<usize>()
+            + (spanned - 1) * (text_width(sep) + 2 * padding)
+    }
+
+    /// Returns the anchor cell of a merged block, centered.
+    fn merged_cell(&self, range: &CellRange) -> Cell {
+        let mut cell = self.rows[range.rows.start][range.columns.start].clone();
+        cell.style.alignment = Some(Alignment::Center);
+        cell
+    }
+
+    /// Returns the number of lines the cells of the row need, ignoring merged blocks.
+    fn unmerged_line_count(&self, row: usize, widths: &[usize]) -> usize {
+        (0..self.columns.len())
+            .filter(|&column| self.merge_at(row, column).is_none())
+            .map(|column| {
+                let cell = &self.rows[row][column];
+                let inner = self.inner_width(cell, widths[column]);
+                self.fitted_lines(column, cell, inner).len()
+            })
+            .max()
+            .unwrap_or(1)
+            .max(1)
+    }
+
+    /// Returns the number of lines printed for every data row, growing the last row
+    /// of a merged block when the merged content needs more lines than its rows.
+    pub(crate) fn merged_line_counts(
+        &self,
+        widths: &[usize],
+        sep: &str,
+        padding: usize,
+    ) -> Vec<usize> {
+        let mut counts: Vec<usize> = (0..self.rows.len())
+            .map(|row| self.unmerged_line_count(row, widths))
+            .collect();
+        for range in &self.merges {
+            if range.rows.end > self.rows.len() || range.columns.end > self.columns.len() {
+                continue;
+            }
+            let cell = self.merged_cell(range);
+            let width = self.merged_width(range, widths, sep, padding);
+            let needed = self
+                .fitted_lines(range.columns.start, &cell, self.inner_width(&cell, width))
+                .len();
+            let available: usize = counts[range.rows.clone()].iter().sum();
+            if needed > available {
+                counts[range.rows.end - 1] += needed - available;
+            }
+        }
+        counts
+    }
+
+    /// Prints the data row with the merged blocks it crosses.
+    /// `line_counts` holds the number of lines of every data row.
+    fn print_row_merged(
+        &self,
+        writer: &mut dyn WriteColor,
+        row: usize,
+        style: &LineStyle,
+        widths: &[usize],
+        line_counts: &[usize],
+    ) -> io::Result<()> {
+        let padding = self.style.config().map_or(1, |config| config.cell_padding);
+        let color = self.edge_color();
+        let outer = " ".repeat(padding);
+        let background = self.row_background(row);
+        for line_index in 0..line_counts[row] {
+            Self::write_border(writer, style.begin, color)?;
+            for (i, segment) in self.segments(row).into_iter().enumerate() {
+                if i > 0 {
+                    Self::write_border(writer, style.sep, color)?;
+                }
+                let (column, cell, line, width) = match segment {
+                    Segment::Cell(column) => (
+                        column,
+                        Cow::Borrowed(&self.rows[row][column]),
+                        line_index,
+                        widths[column],
+                    ),
+                    Segment::Merged(range) => {
+                        let cell = self.merged_cell(range);
+                        let width = self.merged_width(range, widths, style.sep, padding);
+                        let content_lines = self
+                            .fitted_lines(
+                                range.columns.start,
+                                &cell,
+                                self.inner_width(&cell, width),
+                            )
+                            .len();
+                        let total: usize = line_counts[range.rows.clone()].iter().sum();
+                        let offset: usize = line_counts[range.rows.start..row].iter().sum();
+                        let top = (total - content_lines.min(total)) / 2;
+                        let content_line =
+                            (offset + line_index).checked_sub(top).unwrap_or(usize::MAX);
+                        (range.columns.start, Cow::Owned(cell), content_line, width)
+                    }
+                };
+                writer.set_color(&Self::cell_spec(&cell, background))?;
+                write!(writer, "{}", outer)?;
+                self.write_cell_line(writer, column, &cell, line, width, background)?;
+                write!(writer, "{}", outer)?;
+                writer.reset()?;
+            }
+            Self::write_border(writer, style.end, color)?;
+            writeln!(writer)?;
+        }
+        Ok(())
+    }
+
+    /// Prints the data rows of a bordered table, drawing merged blocks when there are any.
+    /// Separators and repeated headers are drawn across merged blocks, with junctions
+    /// that leave out the borders the blocks hide.
+    pub(crate) fn print_data_rows_styled(
+        &self,
+        writer: &mut dyn WriteColor,
+        style: &LineStyle,
+        separator: &LineStyle,
+        widths: &[usize],
+    ) -> io::Result<()> {
+        let padding = self.style.config().map_or(1, |config| config.cell_padding);
+        let line_counts = if self.merges.is_empty() {
+            Vec::new()
+        } else {
+            self.merged_line_counts(widths, style.sep, padding)
+        };
+        for (i, row) in self.rows.iter().enumerate() {
+            if self.repeats_header_before(i) {
+                self.print_line_between(writer, separator, widths, i.checked_sub(1), None)?;
+                self.print_header_styled(writer, style, widths)?;
+                self.print_line_between(writer, separator, widths, None, Some(i))?;
+            } else if self.has_separator_before(i) {
+                self.print_line_between(writer, separator, widths, i.checked_sub(1), Some(i))?;
+            }
+            if line_counts.is_empty() {
+                self.print_row_styled(writer, row, style, widths, self.row_background(i))?;
+            } else {
+                self.print_row_merged(writer, i, style, widths, &line_counts)?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,443 @@
+// SPDX-License-Identifier: MIT
+// Project: tabprinter
+// File: src/meta.rs
+// Author: Volker Schwaberow <volker@schwaberow.de>
+// Copyright (c) 2025 Volker Schwaberow
+
+use serde_json::{json, Map, Value};
+#[cfg(feature = "csv")]
+use std::fs;
+use std::io;
+
+use crate::color::Color;
+use crate::theme::STYLE_NAMES;
+#[cfg(feature = "csv")]
+use crate::Table;
+use crate::{
+    Alignment, CellFormat, CellStyle, Column, ColumnWidth, MaskStyle, OverflowPolicy, TableError,
+    TableStyle, TableTemplate,
+};
+
+/// The version of the sidecar format written by `to_csv_with_meta`.
+const META_VERSION: u64 = 1;
+
+/// Returns the path of the sidecar file belonging to a CSV file.
+#[cfg(feature = "csv")]
+fn sidecar_path(path: &str) -> String {
+    format!("{}.meta.json", path)
+}
+
+/// Returns an error for a sidecar file that cannot be understood.
+fn invalid_meta(reason: &str) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("invalid table metadata: {}", reason),
+    )
+}
+
+/// Encodes an alignment by name.
+fn alignment_to_json(alignment: Alignment) -> Value {
+    json!(match alignment {
+        Alignment::Left => "left",
+        Alignment::Center => "center",
+        Alignment::Right => "right",
+    })
+}
+
+/// Decodes an alignment written by `alignment_to_json`.
+fn alignment_from_json(value: &Value) -> io::Result<Alignment> {
+    match value.as_str() {
+        Some("left") => Ok(Alignment::Left),
+        Some("center") => Ok(Alignment::Center),
+        Some("right") => Ok(Alignment::Right),
+        _ => Err(invalid_meta("unknown alignment")),
+    }
+}
+
+/// Encodes a text color in the notation accepted by `termcolor`, such as `"red"`,
+/// `"208"`, or `"255,128,0"`.
+fn color_to_json(color: Color) -> Option<Value> {
+    let name = match color {
+        Color::Black => "black".to_string(),
+        Color::Blue => "blue".to_string(),
+        Color::Green => "green".to_string(),
+        Color::Red => "red".to_string(),
+        Color::Cyan => "cyan".to_string(),
+        Color::Magenta => "magenta".to_string(),
+        Color::Yellow => "yellow".to_string(),
+        Color::White => "white".to_string(),
+        Color::Ansi256(index) => index.to_string(),
+        Color::Rgb(r, g, b) => format!("{},{},{}", r, g, b),
+        // `termcolor` may add colors; the stand-in without the `color` feature has no more.
+        #[allow(unreachable_patterns)]
+        _ => return None,
+    };
+    Some(json!(name))
+}
+
+/// Decodes a text color written by `color_to_json`.
+fn color_from_json(value: &Value) -> io::Result<Color> {
+    value
+        .as_str()
+        .and_then(|name| name.parse().ok())
+        .ok_or_else(|| invalid_meta("unknown color"))
+}
+
+/// Encodes a column width.
+fn width_to_json(width: ColumnWidth) -> Value {
+    match width {
+        ColumnWidth::Fixed(width) => json!({ "fixed": width }),
+        ColumnWidth::Auto => json!("auto"),
+        ColumnWidth::Percent(percent) => json!({ "percent": percent }),
+        ColumnWidth::Ratio(ratio) => json!({ "ratio": ratio }),
+    }
+}
+
+/// Decodes a column width written by `width_to_json`.
+fn width_from_json(value: &Value) -> io::Result<ColumnWidth> {
+    if value.as_str() == Some("auto") {
+        return Ok(ColumnWidth::Auto);
+    }
+    let number = |key: &str| value.get(key).and_then(Value::as_u64);
+    if let Some(width) = number("fixed") {
+        Ok(ColumnWidth::Fixed(width as usize))
+    } else if let Some(percent) = number("percent") {
+        Ok(ColumnWidth::Percent(percent.min(100) as u8))
+    } else if let Some(ratio) = number("ratio") {
+        Ok(ColumnWidth::Ratio(ratio as u32))
+    } else {
+        Err(invalid_meta("unknown column width"))
+    }
+}
+
+/// Encodes an overflow policy by name.
+fn overflow_to_json(overflow: OverflowPolicy) -> Value {
+    json!(match overflow {
+        OverflowPolicy::Overflow => "overflow",
+        OverflowPolicy::Truncate => "truncate",
+        OverflowPolicy::Wrap => "wrap",
+        OverflowPolicy::Error => "error",
+    })
+}
+
+/// Decodes an overflow policy written by `overflow_to_json`.
+fn overflow_from_json(value: &Value) -> io::Result<OverflowPolicy> {
+    match value.as_str() {
+        Some("overflow") => Ok(OverflowPolicy::Overflow),
+        Some("truncate") => Ok(OverflowPolicy::Truncate),
+        Some("wrap") => Ok(OverflowPolicy::Wrap),
+        Some("error") => Ok(OverflowPolicy::Error),
+        _ => Err(invalid_meta("unknown overflow policy")),
+    }
+}
+
+/// Encodes a mask style.
+fn mask_to_json(mask: MaskStyle) -> Value {
+    match mask {
+        MaskStyle::Full => json!("full"),
+        MaskStyle::Partial { keep_last } => json!({ "keep_last": keep_last }),
+        MaskStyle::Hash => json!("hash"),
+    }
+}
+
+/// Decodes a mask style written by `mask_to_json`.
+fn mask_from_json(value: &Value) -> io::Result<MaskStyle> {
+    match value.as_str() {
+        Some("full") => Ok(MaskStyle::Full),
+        Some("hash") => Ok(MaskStyle::Hash),
+        _ => value
+            .get("keep_last")
+            .and_then(Value::as_u64)
+            .map(|keep_last| MaskStyle::Partial {
+                keep_last: keep_last as usize,
+            })
+            .ok_or_else(|| invalid_meta("unknown mask style")),
+    }
+}
+
+/// Encodes the look of a column: its header, width, alignments, overflow policy,
+/// truncation indicator, and mask.
+fn column_to_json(column: &Column) -> Map<String, Value> {
+    let mut fields = Map::new();
+    fields.insert("header".into(), json!(column.header));
+    fields.insert("width".into(), width_to_json(column.width));
+    fields.insert("alignment".into(), alignment_to_json(column.alignment));
+    if let Some(alignment) = column.header_alignment {
+        fields.insert("header_alignment".into(), alignment_to_json(alignment));
+    }
+    if let Some(overflow) = column.overflow {
+        fields.insert("overflow".into(), overflow_to_json(overflow));
+    }
+    if let Some(mask) = column.mask {
+        fields.insert("mask".into(), mask_to_json(mask));
+    }
+    if let Some(indicator) = &column.truncation_indicator {
+        fields.insert("truncation_indicator".into(), json!(indicator));
+    }
+    fields
+}
+
+/// Applies the fields written by `column_to_json` to a column, except the header.
+fn apply_column_json(column: &mut Column, fields: &Value) -> io::Result<()> {
+    if let Some(width) = fields.get("width") {
+        column.width = width_from_json(width)?;
+    }
+    if let Some(alignment) = fields.get("alignment") {
+        column.alignment = alignment_from_json(alignment)?;
+    }
+    if let Some(alignment) = fields.get("header_alignment") {
+        column.header_alignment = Some(alignment_from_json(alignment)?);
+    }
+    if let Some(overflow) = fields.get("overflow") {
+        column.overflow = Some(overflow_from_json(overflow)?);
+    }
+    if let Some(mask) = fields.get("mask") {
+        column.mask = Some(mask_from_json(mask)?);
+    }
+    if let Some(indicator) = fields.get("truncation_indicator") {
+        let indicator = indicator
+            .as_str()
+            .ok_or_else(|| invalid_meta("the truncation indicator is not a string"))?;
+        column.truncation_indicator = Some(indicator.to_string());
+    }
+    Ok(())
+}
+
+/// Returns the name of the style used in JSON descriptions.
+fn style_name(style: TableStyle) -> &'static str {
+    STYLE_NAMES
+        .iter()
+        .find(|(_, named)| *named == style)
+        .map_or("simple", |(name, _)| name)
+}
+
+/// Returns the style with the name used in JSON descriptions.
+fn style_from_name(name: &str) -> io::Result<TableStyle> {
+    STYLE_NAMES
+        .iter()
+        .find(|(style_name, _)| *style_name == name)
+        .map(|(_, style)| *style)
+        .ok_or_else(|| invalid_meta("unknown style"))
+}
+
+/// Encodes the fields of a cell style that differ from the default.
+fn cell_style_to_json(style: &CellStyle) -> Map<String, Value> {
+    let default = CellStyle::default();
+    let mut fields = Map::new();
+    if style.bold {
+        fields.insert("bold".into(), json!(true));
+    }
+    if style.italic {
+        fields.insert("italic".into(), json!(true));
+    }
+    if style.underline {
+        fields.insert("underline".into(), json!(true));
+    }
+    if style.padding != default.padding {
+        fields.insert("padding".into(), json!(style.padding));
+    }
+    if let Some(decimal_places) = style.decimal_places {
+        fields.insert("decimal_places".into(), json!(decimal_places));
+    }
+    if style.thousand_separator {
+        fields.insert("thousand_separator".into(), json!(true));
+    }
+    if let Some(alignment) = style.alignment {
+        fields.insert("alignment".into(), alignment_to_json(alignment));
+    }
+    if style.fill_char != default.fill_char {
+        fields.insert("fill_char".into(), json!(style.fill_char.to_string()));
+    }
+    if let Some(color) = style.color.and_then(color_to_json) {
+        fields.insert("color".into(), color);
+    }
+    match style.format {
+        CellFormat::Number => {}
+        CellFormat::Bytes => {
+            fields.insert("format".into(), json!("bytes"));
+        }
+        CellFormat::Duration => {
+            fields.insert("format".into(), json!("duration"));
+        }
+    }
+    fields
+}
+
+/// Decodes a cell style written by `cell_style_to_json`, using defaults for missing fields.
+fn cell_style_from_json(value: &Value) -> io::Result<CellStyle> {
+    let flag = |key: &str| value.get(key).and_then(Value::as_bool).unwrap_or(false);
+    let number = |key: &str| value.get(key).and_then(Value::as_u64).map(|n| n as usize);
+    Ok(CellStyle {
+        bold: flag("bold"),
+        italic: flag("italic"),
+        underline: flag("underline"),
+        padding: number("padding").unwrap_or(CellStyle::default().padding),
+        decimal_places: number("decimal_places"),
+        thousand_separator: flag("thousand_separator"),
+        alignment: value
+            .get("alignment")
+            .map(alignment_from_json)
+            .transpose()?,
+        fill_char: value
+            .get("fill_char")
+            .and_then(Value::as_str)
+            .and_then(|fill| fill.chars().next())
+            .unwrap_or(CellStyle::default().fill_char),
+        color: value.get("color").map(color_from_json).transpose()?,
+        format: match value.get("format").and_then(Value::as_str) {
+            None => CellFormat::Number,
+            Some("bytes") => CellFormat::Bytes,
+            Some("duration") => CellFormat::Duration,
+            Some(_) => return Err(invalid_meta("unknown cell format")),
+        },
+    })
+}
+
+#[cfg(feature = "csv")]
+impl Table {
+    /// Describes the style, columns, and non-default cell styles of the table.
+    fn meta_json(&self) -> Value {
+        let style = style_name(self.style);
+        let columns: Vec<Value> = self
+            .columns
+            .iter()
+            .map(|column| Value::Object(column_to_json(column)))
+            .collect();
+        let mut cells = Vec::new();
+        for (row_index, row) in self.rows.iter().enumerate() {
+            for (column_index, cell) in row.iter().enumerate() {
+                let mut fields = cell_style_to_json(&cell.style);
+                if !fields.is_empty() {
+                    fields.insert("row".into(), json!(row_index));
+                    fields.insert("column".into(), json!(column_index));
+                    cells.push(Value::Object(fields));
+                }
+            }
+        }
+        json!({
+            "version": META_VERSION,
+            "style": style,
+            "columns": columns,
+            "cells": cells,
+        })
+    }
+
+    /// Applies a description written by `meta_json` to the table.
+    fn apply_meta_json(&mut self, meta: &Value) -> io::Result<()> {
+        if meta.get("version").and_then(Value::as_u64) != Some(META_VERSION) {
+            return Err(invalid_meta("unsupported version"));
+        }
+        if let Some(name) = meta.get("style").and_then(Value::as_str) {
+            self.style = style_from_name(name)?;
+        }
+        let columns = meta
+            .get("columns")
+            .and_then(Value::as_array)
+            .ok_or_else(|| invalid_meta("missing columns"))?;
+        if columns.len() != self.columns.len() {
+            return Err(invalid_meta("column count does not match the CSV file"));
+        }
+        for (column, fields) in self.columns.iter_mut().zip(columns) {
+            apply_column_json(column, fields)?;
+        }
+        let cells = meta.get("cells").and_then(Value::as_array);
+        for fields in cells.into_iter().flatten() {
+            let position = |key: &str| fields.get(key).and_then(Value::as_u64).map(|n| n as usize);
+            let (Some(row_index), Some(column_index)) = (position("row"), position("column"))
+            else {
+                return Err(invalid_meta("cell style without position"));
+            };
+            let cell = self
+                .rows
+                .get_mut(row_index)
+                .and_then(|row| row.get_mut(column_index))
+                .ok_or_else(|| invalid_meta("cell style outside the table"))?;
+            cell.style = cell_style_from_json(fields)?;
+        }
+        self.invalidate_widths();
+        Ok(())
+    }
+
+    /// Writes the table to a CSV file with a header row, and its style, column widths,
+    /// alignments, and cell styles to a JSON sidecar file next to it named
+    /// `<path>.meta.json`.
+    pub fn to_csv_with_meta(&self, path: &str) -> io::Result<()> {
+        self.to_csv(path)?;
+        let meta = serde_json::to_string_pretty(&self.meta_json()).map_err(io::Error::other)?;
+        fs::write(sidecar_path(path), meta)
+    }
+
+    /// Reads a table written by `to_csv_with_meta`, restoring its look from the
+    /// sidecar file. Without a sidecar file, this behaves like `from_csv`.
+    pub fn from_csv_with_meta(path: &str) -> io::Result<Self> {
+        let mut table = Table::from_csv(path)?;
+        let meta = match fs::read_to_string(sidecar_path(path)) {
+            Ok(meta) => meta,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(table),
+            Err(error) => return Err(error),
+        };
+        let meta: Value = serde_json::from_str(&meta)
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+        table.apply_meta_json(&meta)?;
+        Ok(table)
+    }
+}
+
+impl TableTemplate {
+    /// Serializes the template as JSON, in the format of the sidecar files of
+    /// `to_csv_with_meta` with the cell format of every column.
+    pub fn to_json(&self) -> String {
+        let columns: Vec<Value> = self
+            .columns
+            .iter()
+            .zip(&self.formats)
+            .map(|(column, format)| {
+                let mut fields = column_to_json(column);
+                let format = cell_style_to_json(format);
+                if !format.is_empty() {
+                    fields.insert("format".into(), Value::Object(format));
+                }
+                Value::Object(fields)
+            })
+            .collect();
+        let template = json!({
+            "version": META_VERSION,
+            "style": style_name(self.style),
+            "columns": columns,
+        });
+        serde_json::to_string_pretty(&template).expect("templates serialize to JSON")
+    }
+
+    /// Reads a template written by `to_json`.
+    pub fn from_json(json: &str) -> Result<Self, TableError> {
+        let invalid = |error: io::Error| TableError::InvalidJson(error.to_string());
+        let value: Value = serde_json::from_str(json)
+            .map_err(|error| TableError::InvalidJson(error.to_string()))?;
+        if value.get("version").and_then(Value::as_u64) != Some(META_VERSION) {
+            return Err(invalid(invalid_meta("unsupported version")));
+        }
+        let style = match value.get("style").and_then(Value::as_str) {
+            Some(name) => style_from_name(name).map_err(invalid)?,
+            None => TableStyle::Simple,
+        };
+        let columns = value
+            .get("columns")
+            .and_then(Value::as_array)
+            .ok_or_else(|| invalid(invalid_meta("missing columns")))?;
+        let mut template = TableTemplate::new(style);
+        for fields in columns {
+            let header = fields
+                .get("header")
+                .and_then(Value::as_str)
+                .ok_or_else(|| invalid(invalid_meta("column without header")))?;
+            template = template.column(header, ColumnWidth::Auto, Alignment::Left);
+            let column = template.columns.last_mut().expect("a column was added");
+            apply_column_json(column, fields).map_err(invalid)?;
+            if let Some(format) = fields.get("format") {
+                let format = cell_style_from_json(format).map_err(invalid)?;
+                *template.formats.last_mut().expect("a column was added") = format;
+            }
+        }
+        Ok(template)
+    }
+}
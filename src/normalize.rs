@@ -0,0 +1,68 @@
+// SPDX-License-Identifier: MIT
+// Project: tabprinter
+// File: src/normalize.rs
+// Author: Volker Schwaberow <volker@schwaberow.de>
+// Copyright (c) 2025 Volker Schwaberow
+
+use crate::{ColumnRef, Table, TableError};
+
+/// A cleanup step applied to the content of cells.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Normalize {
+    /// Removes whitespace at the start and end.
+    Trim,
+    /// Converts the text to lowercase.
+    Lower,
+    /// Converts the text to uppercase.
+    Upper,
+    /// Replaces every run of whitespace, including line breaks, with a single space.
+    CollapseWhitespace,
+}
+
+impl Normalize {
+    /// Returns the text after the step.
+    fn apply(&self, text: &str) -> String {
+        match self {
+            Normalize::Trim => text.trim().to_string(),
+            Normalize::Lower => text.to_lowercase(),
+            Normalize::Upper => text.to_uppercase(),
+            Normalize::CollapseWhitespace => {
+                let mut collapsed = String::with_capacity(text.len());
+                let mut in_whitespace = false;
+                for c in text.chars() {
+                    if c.is_whitespace() {
+                        if !in_whitespace {
+                            collapsed.push(' ');
+                        }
+                        in_whitespace = true;
+                    } else {
+                        collapsed.push(c);
+                        in_whitespace = false;
+                    }
+                }
+                collapsed
+            }
+        }
+    }
+}
+
+impl Table {
+    /// Applies the cleanup steps, in order, to the data cells of the specified
+    /// column, so values such as ` Berlin` and `berlin ` group and sort together.
+    /// The header and footer are not changed.
+    pub fn normalize_column<'a>(
+        &mut self,
+        column: impl Into<ColumnRef<'a>>,
+        steps: &[Normalize],
+    ) -> Result<(), TableError> {
+        let column_index = self.column_position(column)?;
+        for row in &mut self.rows {
+            let cell = &mut row[column_index];
+            cell.content = steps
+                .iter()
+                .fold(cell.content.clone(), |content, step| step.apply(&content));
+        }
+        self.invalidate_widths();
+        Ok(())
+    }
+}
@@ -0,0 +1,87 @@
+// SPDX-License-Identifier: MIT
+// Project: tabprinter
+// File: src/outlier.rs
+// Author: Volker Schwaberow <volker@schwaberow.de>
+// Copyright (c) 2025 Volker Schwaberow
+
+use crate::{CellStyle, ColumnRef, Table, TableError};
+
+/// Decides which values of a column are outliers.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum OutlierMethod {
+    /// Values more than the given number of standard deviations from the mean,
+    /// commonly 3.0.
+    ZScore(f64),
+    /// Values more than the given multiple of the interquartile range below the first
+    /// or above the third quartile, commonly 1.5.
+    Iqr(f64),
+}
+
+/// Returns the quantile `q` of the sorted values, interpolating between neighbors.
+fn quantile(sorted: &[f64], q: f64) -> f64 {
+    let position = q * (sorted.len() - 1) as f64;
+    let lower = position.floor() as usize;
+    let upper = position.ceil() as usize;
+    sorted[lower] + (sorted[upper] - sorted[lower]) * (position - lower as f64)
+}
+
+impl OutlierMethod {
+    /// Returns the range of values that are not outliers, or `None` if the values
+    /// do not spread enough to have outliers.
+    fn bounds(&self, values: &[f64]) -> Option<(f64, f64)> {
+        if values.len() < 2 {
+            return None;
+        }
+        match *self {
+            OutlierMethod::ZScore(threshold) => {
+                let n = values.len() as f64;
+                let mean = values.iter().sum::<f64>() / n;
+                let variance = values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / n;
+                let deviation = variance.sqrt();
+                (deviation > 0.0)
+                    .then_some((mean - threshold * deviation, mean + threshold * deviation))
+            }
+            OutlierMethod::Iqr(factor) => {
+                let mut sorted = values.to_vec();
+                sorted.sort_by(f64::total_cmp);
+                let first = quantile(&sorted, 0.25);
+                let third = quantile(&sorted, 0.75);
+                let range = third - first;
+                Some((first - factor * range, third + factor * range))
+            }
+        }
+    }
+}
+
+impl Table {
+    /// Gives the cells of the specified column whose numeric values are statistical
+    /// outliers the text attributes of the style, such as red bold text for unusual
+    /// latencies. The color, bold, italic, and underline of the style replace those of
+    /// the cell; padding, alignment, and number formats are kept. Cells that are not
+    /// numbers are left out of the statistics and never highlighted. The cells are
+    /// chosen from the values at the time of the call.
+    pub fn highlight_outliers<'a>(
+        &mut self,
+        column: impl Into<ColumnRef<'a>>,
+        method: OutlierMethod,
+        style: CellStyle,
+    ) -> Result<(), TableError> {
+        let column_index = self.column_position(column)?;
+        let parsed = self.parsed_column(column_index);
+        let finite = |value: &&f64| value.is_finite();
+        let values: Vec<f64> = parsed.iter().flatten().filter(finite).copied().collect();
+        let Some((low, high)) = method.bounds(&values) else {
+            return Ok(());
+        };
+        for (row, value) in self.rows.iter_mut().zip(parsed.iter()) {
+            let cell = &mut row.cells[column_index];
+            if value.is_some_and(|value| value < low || value > high) {
+                cell.style.color = style.color;
+                cell.style.bold = style.bold;
+                cell.style.italic = style.italic;
+                cell.style.underline = style.underline;
+            }
+        }
+        Ok(())
+    }
+}
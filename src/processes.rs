@@ -0,0 +1,67 @@
+// SPDX-License-Identifier: MIT
+// Project: tabprinter
+// File: src/processes.rs
+// Author: Volker Schwaberow <volker@schwaberow.de>
+// Copyright (c) 2025 Volker Schwaberow
+
+use std::thread;
+use sysinfo::{ProcessRefreshKind, ProcessesToUpdate, System, MINIMUM_CPU_UPDATE_INTERVAL};
+
+use crate::{Alignment, Cell, CellFormat, ColumnWidth, Table, TableStyle};
+
+/// Updates the CPU and memory usage of all processes, removing those that exited.
+fn refresh(system: &mut System) {
+    system.refresh_processes_specifics(
+        ProcessesToUpdate::All,
+        true,
+        ProcessRefreshKind::nothing().with_cpu().with_memory(),
+    );
+}
+
+impl Table {
+    /// Lists the running processes with the columns `PID`, `Name`, `CPU %`, and
+    /// `Memory`, sorted by CPU usage with the busiest first. CPU usage is measured
+    /// between two samples, so this waits `sysinfo::MINIMUM_CPU_UPDATE_INTERVAL`.
+    /// To refresh a listing repeatedly, as with `watch`, keep a `System` and use
+    /// `from_system` instead.
+    pub fn from_processes() -> Self {
+        let mut system = System::new();
+        refresh(&mut system);
+        thread::sleep(MINIMUM_CPU_UPDATE_INTERVAL);
+        refresh(&mut system);
+        Table::from_system(&system)
+    }
+
+    /// Lists the processes of the system as `from_processes` does, with the usage
+    /// of its last refresh. The CPU usage is zero until a process has been
+    /// refreshed twice. CPU usage keeps the percentage as content with one decimal
+    /// place, and memory keeps the number of bytes as content shown with binary
+    /// units, so sorting and aggregating the columns work on numbers.
+    pub fn from_system(system: &System) -> Self {
+        let mut processes: Vec<_> = system.processes().values().collect();
+        processes.sort_by(|a, b| {
+            b.cpu_usage()
+                .total_cmp(&a.cpu_usage())
+                .then_with(|| a.pid().cmp(&b.pid()))
+        });
+
+        let mut table = Table::new(TableStyle::Simple);
+        table.add_column("PID", ColumnWidth::Auto, Alignment::Right);
+        table.add_column("Name", ColumnWidth::Auto, Alignment::Left);
+        table.add_column("CPU %", ColumnWidth::Auto, Alignment::Right);
+        table.add_column("Memory", ColumnWidth::Auto, Alignment::Right);
+        for process in processes {
+            let mut cpu = Cell::new(&process.cpu_usage().to_string());
+            cpu.style.decimal_places = Some(1);
+            let mut memory = Cell::new(&process.memory().to_string());
+            memory.style.format = CellFormat::Bytes;
+            table.add_row(vec![
+                Cell::new(&process.pid().to_string()),
+                Cell::new(&process.name().to_string_lossy()),
+                cpu,
+                memory,
+            ]);
+        }
+        table
+    }
+}
@@ -0,0 +1,75 @@
+// SPDX-License-Identifier: MIT
+// Project: tabprinter
+// File: src/rank.rs
+// Author: Volker Schwaberow <volker@schwaberow.de>
+// Copyright (c) 2025 Volker Schwaberow
+
+use crate::{Alignment, Cell, ColumnRef, ColumnWidth, Table};
+
+/// Determines how tied values are ranked.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RankMethod {
+    /// Ties share a rank and the next value gets the following rank: 1, 2, 2, 3.
+    Dense,
+    /// Every row gets its own rank; ties are ranked in row order: 1, 2, 3, 4.
+    Ordinal,
+    /// Ties share the lowest rank of the group and ranks are skipped after them,
+    /// as in sports standings: 1, 2, 2, 4.
+    #[default]
+    Min,
+}
+
+impl Table {
+    /// Inserts a `Rank` column before the first column, ranking the rows by the
+    /// numeric value of the specified column from the highest value down, as on a
+    /// leaderboard. Cells that are not numbers get an empty rank. The ranks follow
+    /// the values at the time of the call and are not updated when rows change.
+    pub fn add_rank_column<'a>(&mut self, by_column: impl Into<ColumnRef<'a>>, method: RankMethod) {
+        let column_index = self.resolve_column(by_column);
+        let mut values: Vec<(usize, f64)> = self
+            .rows
+            .iter()
+            .enumerate()
+            .filter_map(|(i, row)| {
+                let value = row[column_index].content.trim().parse::<f64>().ok()?;
+                (!value.is_nan()).then_some((i, value))
+            })
+            .collect();
+        values.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+        let mut ranks = vec![None; self.rows.len()];
+        let mut dense = 0;
+        let mut previous = None;
+        for (position, &(row_index, value)) in values.iter().enumerate() {
+            let tied = previous.is_some_and(|(previous, _)| previous == value);
+            if !tied {
+                dense += 1;
+            }
+            let rank = match method {
+                RankMethod::Dense => dense,
+                RankMethod::Ordinal => position + 1,
+                RankMethod::Min if tied => previous.map_or(1, |(_, rank)| rank),
+                RankMethod::Min => position + 1,
+            };
+            previous = Some((value, rank));
+            ranks[row_index] = Some(rank);
+        }
+        for (row, rank) in self.rows.iter_mut().zip(ranks) {
+            let content = rank.map(|rank| rank.to_string()).unwrap_or_default();
+            row.cells.insert(0, Cell::new(&content));
+        }
+        if let Some(footer) = &mut self.footer {
+            footer.insert(0, Cell::new(""));
+        }
+        self.add_column("Rank", ColumnWidth::Auto, Alignment::Right);
+        if let Some(column) = self.columns.pop() {
+            self.columns.insert(0, column);
+        }
+        for sticky in &mut self.sticky_columns {
+            *sticky += 1;
+        }
+        for merge in &mut self.merges {
+            merge.columns = merge.columns.start + 1..merge.columns.end + 1;
+        }
+        self.invalidate_widths();
+    }
+}
@@ -0,0 +1,128 @@
+// SPDX-License-Identifier: MIT
+// Project: tabprinter
+// File: src/record.rs
+// Author: Volker Schwaberow <volker@schwaberow.de>
+// Copyright (c) 2025 Volker Schwaberow
+
+use std::io;
+
+use crate::color::{ColorChoice, StandardStream, WriteColor};
+use crate::text::text_width;
+use crate::{
+    Alignment, Cell, ColumnWidth, LineStyle, Table, TableError, TableStyle, TableStyleConfig,
+};
+
+/// Renders a single record as aligned `key: value` lines.
+/// The record is framed by the border characters of the chosen table style.
+pub struct RecordView<'a> {
+    /// The key and value of every field, in display order.
+    entries: Vec<(&'a str, String)>,
+    /// The style used for the surrounding border.
+    style: TableStyle,
+}
+
+impl RecordView<'_> {
+    /// Width of the longest key.
+    fn key_width(&self) -> usize {
+        self.entries
+            .iter()
+            .map(|(key, _)| text_width(key))
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Formats every field as a `key: value` line with the keys padded to the same width.
+    fn lines(&self) -> Vec<String> {
+        let key_width = self.key_width();
+        self.entries
+            .iter()
+            .map(|(key, value)| format!("{}: {}", Alignment::Left.pad(key, key_width), value))
+            .collect()
+    }
+
+    /// Prints a horizontal border line spanning the whole record.
+    fn print_line(
+        writer: &mut dyn WriteColor,
+        config: &TableStyleConfig,
+        style: &LineStyle,
+        width: usize,
+    ) -> io::Result<()> {
+        writeln!(
+            writer,
+            "{}{}{}",
+            style.begin,
+            style.hline.repeat(width + 2 * config.cell_padding),
+            style.end
+        )
+    }
+
+    /// Prints the record to the specified writer.
+    pub fn print_to_writer(&self, writer: &mut dyn WriteColor) -> io::Result<()> {
+        let lines = self.lines();
+        match self.style.config() {
+            Some(style_cfg) => {
+                let width = lines.iter().map(|l| text_width(l)).max().unwrap_or(0);
+                let padding = " ".repeat(style_cfg.cell_padding);
+                Self::print_line(writer, style_cfg, &style_cfg.top, width)?;
+                for line in &lines {
+                    writeln!(
+                        writer,
+                        "{}{}{}{}{}",
+                        style_cfg.row.begin,
+                        padding,
+                        Alignment::Left.pad(line, width),
+                        padding,
+                        style_cfg.row.end
+                    )?;
+                }
+                Self::print_line(writer, style_cfg, &style_cfg.bottom, width)
+            }
+            None => {
+                for line in &lines {
+                    writeln!(writer, "{}", line)?;
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// Prints the record to the standard output.
+    pub fn print(&self) -> io::Result<()> {
+        let mut stdout = StandardStream::stdout(ColorChoice::Always);
+        self.print_to_writer(&mut stdout)
+    }
+}
+
+impl Table {
+    /// Creates a two-column `Key`/`Value` table from the given pairs.
+    /// Column widths are adjusted to the content.
+    pub fn from_pairs(pairs: &[(&str, &str)]) -> Self {
+        let mut table = Table::new(TableStyle::Simple);
+        table.add_column("Key", ColumnWidth::Auto, Alignment::Left);
+        table.add_column("Value", ColumnWidth::Auto, Alignment::Left);
+        for (key, value) in pairs {
+            table.add_row(vec![Cell::new(key), Cell::new(value)]);
+        }
+        table
+    }
+
+    /// Returns a view that prints the specified row as `header: value` lines,
+    /// or an error if the row does not exist.
+    pub fn record_view(&self, row_index: usize) -> Result<RecordView<'_>, TableError> {
+        self.check_row(row_index)?;
+        Ok(RecordView {
+            entries: self
+                .columns
+                .iter()
+                .map(|column| column.header.as_str())
+                .zip(
+                    self.rows[row_index]
+                        .iter()
+                        .enumerate()
+                        .map(|(i, cell)| self.cell_content(i, cell)),
+                )
+                .collect(),
+            style: self.style,
+        })
+    }
+}
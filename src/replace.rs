@@ -0,0 +1,38 @@
+// SPDX-License-Identifier: MIT
+// Project: tabprinter
+// File: src/replace.rs
+// Author: Volker Schwaberow <volker@schwaberow.de>
+// Copyright (c) 2025 Volker Schwaberow
+
+use regex::Regex;
+use std::borrow::Cow;
+
+use crate::{ColumnRef, Table, TableError};
+
+impl Table {
+    /// Replaces every match of the regular expression in the data cells of the
+    /// specified column, such as `(\d+) ?ms` with `$1` to strip a unit. The replacement
+    /// refers to capture groups as `$1` or `${name}`. The header and footer are not
+    /// changed. Fails without changing the table if the pattern is not a valid
+    /// regular expression.
+    pub fn replace_in_column<'a>(
+        &mut self,
+        column: impl Into<ColumnRef<'a>>,
+        pattern: &str,
+        replacement: &str,
+    ) -> Result<(), TableError> {
+        let column_index = self.column_position(column)?;
+        let regex = Regex::new(pattern).map_err(|error| TableError::InvalidExpression {
+            expression: pattern.to_string(),
+            reason: error.to_string(),
+        })?;
+        for row in &mut self.rows {
+            let cell = &mut row[column_index];
+            if let Cow::Owned(replaced) = regex.replace_all(&cell.content, replacement) {
+                cell.content = replaced;
+            }
+        }
+        self.invalidate_widths();
+        Ok(())
+    }
+}
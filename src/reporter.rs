@@ -0,0 +1,181 @@
+// SPDX-License-Identifier: MIT
+// Project: tabprinter
+// File: src/reporter.rs
+// Author: Volker Schwaberow <volker@schwaberow.de>
+// Copyright (c) 2025 Volker Schwaberow
+
+use std::collections::HashMap;
+use std::io;
+
+use crate::color::{Color, WriteColor};
+use crate::{Alignment, Cell, CellStyle, ColumnWidth, Table, TableStyle};
+
+/// The outcome of a test or benchmark recorded by a `Reporter`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Outcome {
+    /// The test passed.
+    Pass,
+    /// The test failed.
+    Fail,
+    /// The test did not run.
+    Skip,
+}
+
+impl Outcome {
+    /// Returns the label shown in the status column.
+    pub fn label(&self) -> &'static str {
+        match self {
+            Outcome::Pass => "PASS",
+            Outcome::Fail => "FAIL",
+            Outcome::Skip => "SKIP",
+        }
+    }
+
+    /// Returns the style of the label: green, bold red, or yellow.
+    fn style(&self) -> CellStyle {
+        let mut style = CellStyle::new();
+        style.color = Some(match self {
+            Outcome::Pass => Color::Green,
+            Outcome::Fail => Color::Red,
+            Outcome::Skip => Color::Yellow,
+        });
+        style.bold = *self == Outcome::Fail;
+        style
+    }
+}
+
+/// Collects the results of a test runner or benchmark harness into a table with
+/// a color-coded `Status` column and a footer counting the outcomes.
+pub struct Reporter {
+    /// The results recorded so far, with the summary as footer.
+    table: Table,
+    /// The number of results per outcome.
+    counts: HashMap<Outcome, usize>,
+}
+
+impl Reporter {
+    /// Creates a reporter whose rows have a status followed by the given columns.
+    pub fn new(columns: &[&str]) -> Self {
+        let mut table = Table::new(TableStyle::Round);
+        table.add_column("Status", ColumnWidth::Auto, Alignment::Left);
+        for header in columns {
+            table.add_column(header, ColumnWidth::Auto, Alignment::Left);
+        }
+        let styles = [Outcome::Pass, Outcome::Fail, Outcome::Skip]
+            .iter()
+            .map(|outcome| (outcome.label().to_string(), outcome.style()))
+            .collect();
+        table
+            .set_category_styles(0, styles)
+            .expect("the status column exists");
+        let mut reporter = Self {
+            table,
+            counts: HashMap::new(),
+        };
+        reporter.update_summary();
+        reporter
+    }
+
+    /// Sets the style of the report.
+    pub fn set_style(&mut self, style: TableStyle) {
+        self.table.set_style(style);
+    }
+
+    /// Records a result with the outcome and one cell per column.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of cells does not match the columns given to `new`.
+    pub fn record<I, C>(&mut self, outcome: Outcome, row: I)
+    where
+        I: IntoIterator<Item = C>,
+        C: Into<Cell>,
+    {
+        let mut cells = vec![Cell::new(outcome.label())];
+        cells.extend(row.into_iter().map(Into::into));
+        self.table.add_row(cells);
+        *self.counts.entry(outcome).or_default() += 1;
+        self.update_summary();
+    }
+
+    /// Records a passed result.
+    pub fn pass<I, C>(&mut self, row: I)
+    where
+        I: IntoIterator<Item = C>,
+        C: Into<Cell>,
+    {
+        self.record(Outcome::Pass, row);
+    }
+
+    /// Records a failed result.
+    pub fn fail<I, C>(&mut self, row: I)
+    where
+        I: IntoIterator<Item = C>,
+        C: Into<Cell>,
+    {
+        self.record(Outcome::Fail, row);
+    }
+
+    /// Records a skipped result.
+    pub fn skip<I, C>(&mut self, row: I)
+    where
+        I: IntoIterator<Item = C>,
+        C: Into<Cell>,
+    {
+        self.record(Outcome::Skip, row);
+    }
+
+    /// Returns the number of results with the outcome.
+    pub fn count(&self, outcome: Outcome) -> usize {
+        self.counts.get(&outcome).copied().unwrap_or(0)
+    }
+
+    /// Returns whether no result failed, for the exit code of the runner.
+    pub fn success(&self) -> bool {
+        self.count(Outcome::Fail) == 0
+    }
+
+    /// Rewrites the footer counting the outcomes, such as `3 passed, 1 failed,
+    /// 0 skipped` under the first column after the status.
+    fn update_summary(&mut self) {
+        let total: usize = self.counts.values().sum();
+        let summary = format!(
+            "{} passed, {} failed, {} skipped",
+            self.count(Outcome::Pass),
+            self.count(Outcome::Fail),
+            self.count(Outcome::Skip)
+        );
+        let mut footer = vec![Cell::new(&format!("{} run", total))];
+        footer.extend((1..self.table.columns.len()).map(|_| Cell::new("")));
+        match footer.get_mut(1) {
+            Some(cell) => *cell = Cell::new(&summary),
+            None => footer[0] = Cell::new(&format!("{}: {}", total, summary)),
+        }
+        self.table.set_footer(footer);
+    }
+
+    /// Returns the table of the results, with the summary as footer.
+    pub fn table(&self) -> &Table {
+        &self.table
+    }
+
+    /// Returns the table of the results, for further processing or export.
+    pub fn into_table(self) -> Table {
+        self.table
+    }
+
+    /// Renders the report as text without colors.
+    pub fn render(&self) -> String {
+        self.table.render()
+    }
+
+    /// Prints the report to the writer.
+    pub fn print_to_writer(&self, writer: &mut dyn WriteColor) -> io::Result<()> {
+        self.table.print_to_writer(writer)
+    }
+
+    /// Prints the report to the standard output with colors.
+    pub fn print(&self) -> io::Result<()> {
+        self.table.print()
+    }
+}
@@ -0,0 +1,167 @@
+// SPDX-License-Identifier: MIT
+// Project: tabprinter
+// File: src/reshape.rs
+// Author: Volker Schwaberow <volker@schwaberow.de>
+// Copyright (c) 2025 Volker Schwaberow
+
+use std::collections::HashSet;
+
+use crate::{Cell, ColumnRef, Table, TableError};
+
+impl Table {
+    /// Moves the column settings that refer to columns by position after the columns
+    /// were rearranged. `map` gives the new position of every old column, or `None`
+    /// for removed columns. Sticky columns follow their column; merged blocks that
+    /// lose a column or no longer sit side by side are dropped.
+    fn remap_columns(&mut self, map: &[Option<usize>]) {
+        self.sticky_columns = self
+            .sticky_columns
+            .iter()
+            .filter_map(|&column| map.get(column).copied().flatten())
+            .collect();
+        self.merges.retain_mut(|merge| {
+            let mapped: Option<Vec<usize>> = merge.columns.clone().map(|c| map[c]).collect();
+            match mapped {
+                Some(mapped) if mapped.windows(2).all(|pair| pair[1] == pair[0] + 1) => {
+                    merge.columns = mapped[0]..mapped[0] + mapped.len();
+                    true
+                }
+                _ => false,
+            }
+        });
+        self.invalidate_widths();
+    }
+
+    /// Replaces the specified column with one column per header, holding the parts of
+    /// every cell separated by the delimiter, such as `City` and `Country` from
+    /// `Paris, France` with `", "`. The new columns keep the width and alignment of the
+    /// original. A cell with more parts keeps the rest in the last column. A footer cell
+    /// moves to the first new column.
+    ///
+    /// Fails without changing the table if a cell has fewer parts than there are
+    /// headers, or if no header is given.
+    pub fn split_column<'a>(
+        &mut self,
+        column: impl Into<ColumnRef<'a>>,
+        delimiter: &str,
+        new_headers: &[&str],
+    ) -> Result<(), TableError> {
+        let column_index = self.column_position(column)?;
+        let count = new_headers.len();
+        if count == 0 || delimiter.is_empty() {
+            return Err(TableError::InvalidColumns(
+                "a split needs a delimiter and at least one header".to_string(),
+            ));
+        }
+        let mut parts = Vec::with_capacity(self.rows.len());
+        for (row_index, row) in self.rows.iter().enumerate() {
+            let content = row[column_index].resolved_content();
+            let split: Vec<&str> = content.splitn(count, delimiter).collect();
+            if split.len() < count {
+                return Err(TableError::Parse {
+                    row: row_index,
+                    column: self.columns[column_index].header.clone(),
+                    value: content.to_string(),
+                    reason: format!("expected {} parts separated by `{}`", count, delimiter),
+                });
+            }
+            parts.push(split.into_iter().map(str::to_string).collect::<Vec<_>>());
+        }
+        for (row, parts) in self.rows.iter_mut().zip(parts) {
+            let style = row[column_index].style;
+            let cells = parts.iter().map(|part| Cell {
+                style,
+                ..Cell::new(part)
+            });
+            row.cells.splice(column_index..=column_index, cells);
+        }
+        if let Some(footer) = &mut self.footer {
+            let blanks = (1..count).map(|_| Cell::new(""));
+            footer.splice(column_index + 1..column_index + 1, blanks);
+        }
+        let original = self.columns[column_index].clone();
+        let columns = new_headers.iter().map(|header| {
+            let mut column = original.clone();
+            column.header = header.to_string();
+            column
+        });
+        self.columns.splice(column_index..=column_index, columns);
+        let map: Vec<Option<usize>> = (0..self.columns.len() + 1 - count)
+            .map(|c| Some(if c > column_index { c + count - 1 } else { c }))
+            .collect();
+        self.remap_columns(&map);
+        Ok(())
+    }
+
+    /// Replaces the specified columns, given by index or header, with a single column
+    /// at the position of the first of them, holding the contents of every row joined
+    /// by the separator in the order given, such as `First` and `Last` into `Name`. The
+    /// new column keeps the width and alignment of the first column. Footer cells are
+    /// joined the same way.
+    ///
+    /// Fails without changing the table if a column does not exist, is given twice,
+    /// or no column is given.
+    pub fn combine_columns<'a, C: Into<ColumnRef<'a>>>(
+        &mut self,
+        columns: impl IntoIterator<Item = C>,
+        separator: &str,
+        new_header: &str,
+    ) -> Result<(), TableError> {
+        let columns = self.column_positions(columns)?;
+        let len = self.columns.len();
+        let mut seen = HashSet::new();
+        if columns.is_empty() || !columns.iter().all(|index| seen.insert(index)) {
+            return Err(TableError::InvalidColumns(
+                "combine needs at least one column, each given once".to_string(),
+            ));
+        }
+        let target = columns[0];
+        let join = |cells: &[Cell]| {
+            let parts: Vec<&str> = columns
+                .iter()
+                .map(|&c| cells[c].resolved_content())
+                .collect();
+            Cell {
+                style: cells[target].style,
+                ..Cell::new(&parts.join(separator))
+            }
+        };
+        let keep = |index: &usize| *index == target || !columns.contains(index);
+        let combine = |cells: &mut Vec<Cell>| {
+            let mut combined = Some(join(cells));
+            *cells = std::mem::take(cells)
+                .into_iter()
+                .enumerate()
+                .filter(|(index, _)| keep(index))
+                .map(|(index, cell)| match index == target {
+                    true => combined.take().unwrap_or(cell),
+                    false => cell,
+                })
+                .collect();
+        };
+        for row in &mut self.rows {
+            combine(&mut row.cells);
+        }
+        if let Some(footer) = &mut self.footer {
+            combine(footer);
+        }
+        self.columns[target].header = new_header.to_string();
+        self.columns = std::mem::take(&mut self.columns)
+            .into_iter()
+            .enumerate()
+            .filter(|(index, _)| keep(index))
+            .map(|(_, column)| column)
+            .collect();
+        let mut next = 0;
+        let map: Vec<Option<usize>> = (0..len)
+            .map(|index| {
+                keep(&index).then(|| {
+                    next += 1;
+                    next - 1
+                })
+            })
+            .collect();
+        self.remap_columns(&map);
+        Ok(())
+    }
+}
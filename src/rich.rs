@@ -0,0 +1,195 @@
+// SPDX-License-Identifier: MIT
+// Project: tabprinter
+// File: src/rich.rs
+// Author: Volker Schwaberow <volker@schwaberow.de>
+// Copyright (c) 2025 Volker Schwaberow
+
+use std::io;
+
+use crate::color::{Color, ColorSpec, WriteColor};
+use crate::text::text_width;
+use crate::{Cell, CellStyle, OverflowPolicy, Table};
+
+/// A run of text inside a rich cell with its own colors and attributes.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Span {
+    /// The text of the span.
+    pub text: String,
+    /// The colors and attributes of the span, combined with those of the cell.
+    pub style: ColorSpec,
+}
+
+impl Span {
+    /// Creates a span printed with the attributes of its cell.
+    pub fn raw(text: &str) -> Self {
+        Self::styled(text, ColorSpec::new())
+    }
+
+    /// Creates a span with its own colors and attributes.
+    pub fn styled(text: &str, style: ColorSpec) -> Self {
+        Self {
+            text: text.to_string(),
+            style,
+        }
+    }
+}
+
+impl Cell {
+    /// Creates a cell whose parts carry their own colors and attributes,
+    /// such as a colored log level followed by a plain message.
+    /// The content of the cell is the text of all spans, so widths, sorting,
+    /// and exports see the plain text.
+    pub fn rich(spans: Vec<Span>) -> Self {
+        Self {
+            content: spans.iter().map(|span| span.text.as_str()).collect(),
+            style: CellStyle::new(),
+            footnote: None,
+            spans,
+            is_header: false,
+            lazy: None,
+        }
+    }
+
+    /// Returns the spans of the cell if they still match its content.
+    /// Spans are ignored once the content is changed, for example by `set_cell`.
+    fn active_spans(&self) -> Option<&[Span]> {
+        let covered = self.spans.iter().map(|span| span.text.len()).sum::<usize>();
+        let matches = !self.spans.is_empty()
+            && covered == self.content.len()
+            && self
+                .spans
+                .iter()
+                .scan(0, |start, span| {
+                    let end = *start + span.text.len();
+                    let same = self.content.get(*start..end) == Some(span.text.as_str());
+                    *start = end;
+                    Some(same)
+                })
+                .all(|same| same);
+        matches.then_some(self.spans.as_slice())
+    }
+}
+
+/// Combines the attributes of a span with those of its cell.
+fn span_spec(base: &ColorSpec, span: &ColorSpec) -> ColorSpec {
+    let mut spec = base.clone();
+    if let Some(fg) = span.fg() {
+        spec.set_fg(Some(*fg));
+    }
+    if let Some(bg) = span.bg() {
+        spec.set_bg(Some(*bg));
+    }
+    spec.set_bold(base.bold() || span.bold())
+        .set_italic(base.italic() || span.italic())
+        .set_underline(base.underline() || span.underline())
+        .set_dimmed(base.dimmed() || span.dimmed())
+        .set_intense(base.intense() || span.intense());
+    spec
+}
+
+/// Assigns the characters of a fitted line to the characters of the content,
+/// starting at `cursor`. Whitespace dropped by wrapping is skipped; characters
+/// not found in the content, such as an ellipsis, get no span.
+fn match_line(owners: &[(char, usize)], cursor: &mut usize, line: &str) -> Vec<Option<usize>> {
+    line.chars()
+        .map(|c| {
+            while *cursor < owners.len()
+                && owners[*cursor].0 != c
+                && owners[*cursor].0.is_whitespace()
+            {
+                *cursor += 1;
+            }
+            match owners.get(*cursor) {
+                Some(&(owned, span)) if owned == c => {
+                    *cursor += 1;
+                    Some(span)
+                }
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+impl Table {
+    /// Returns the span owning every character of the line of the cell, or `None`
+    /// if the cell has no spans or its text is replaced by a mask.
+    fn span_owners(
+        &self,
+        column_index: usize,
+        cell: &Cell,
+        lines: &[String],
+        line_index: usize,
+    ) -> Option<Vec<Option<usize>>> {
+        let spans = cell.active_spans()?;
+        if self.columns[column_index].mask.is_some() {
+            return None;
+        }
+        let owners: Vec<(char, usize)> = spans
+            .iter()
+            .enumerate()
+            .flat_map(|(i, span)| span.text.chars().map(move |c| (c, i)))
+            .collect();
+        let policy = self.columns[column_index].overflow.unwrap_or(self.overflow);
+        let mut cursor = 0;
+        if policy == OverflowPolicy::Wrap {
+            for line in &lines[..line_index] {
+                match_line(&owners, &mut cursor, line);
+            }
+        } else {
+            // Every line starts a line of the content; the rest may have been cut.
+            let mut breaks = owners.iter().enumerate().filter(|(_, (c, _))| *c == '\n');
+            if line_index > 0 {
+                cursor = breaks
+                    .nth(line_index - 1)
+                    .map_or(owners.len(), |(i, _)| i + 1);
+            }
+        }
+        Some(match_line(&owners, &mut cursor, &lines[line_index]))
+    }
+
+    /// Writes the line of the cell with its padding, filling exactly the width,
+    /// using the attributes of the cell on the background and those of its spans.
+    /// The colors are left set for the caller to reset.
+    pub(crate) fn write_cell_line(
+        &self,
+        writer: &mut dyn WriteColor,
+        column_index: usize,
+        cell: &Cell,
+        line_index: usize,
+        width: usize,
+        background: Option<Color>,
+    ) -> io::Result<()> {
+        let base = self.display_spec(column_index, cell, background);
+        let text = self.render_padded_cell_line(column_index, cell, line_index, width);
+        let inner = self.inner_width(cell, width);
+        let lines = self.fitted_lines(column_index, cell, inner);
+        let owners = match lines.get(line_index) {
+            Some(_) => self.span_owners(column_index, cell, &lines, line_index),
+            None => None,
+        };
+        writer.set_color(&base)?;
+        let (Some(owners), Some(spans)) = (owners, cell.active_spans()) else {
+            return write!(writer, "{}", text);
+        };
+        let line = &lines[line_index];
+        let (left, _) = self
+            .cell_alignment(column_index, cell)
+            .fill(inner.saturating_sub(text_width(line)));
+        let start = self.cell_padding(cell).min(width / 2) + left;
+        let mut chars = text.chars();
+        write!(writer, "{}", chars.by_ref().take(start).collect::<String>())?;
+        let mut current = None;
+        for (c, owner) in chars.by_ref().zip(owners) {
+            if owner != current {
+                current = owner;
+                match owner {
+                    Some(span) => writer.set_color(&span_spec(&base, &spans[span].style))?,
+                    None => writer.set_color(&base)?,
+                }
+            }
+            write!(writer, "{}", c)?;
+        }
+        writer.set_color(&base)?;
+        write!(writer, "{}", chars.collect::<String>())
+    }
+}
@@ -0,0 +1,108 @@
+// SPDX-License-Identifier: MIT
+// Project: tabprinter
+// File: src/running.rs
+// Author: Volker Schwaberow <volker@schwaberow.de>
+// Copyright (c) 2025 Volker Schwaberow
+
+use crate::text::format_number;
+use crate::{Alignment, Cell, ColumnRef, ColumnWidth, Table};
+
+/// A statistic computed over a run of numeric values.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Aggregation {
+    /// The sum of the values.
+    Sum,
+    /// The average of the values.
+    Mean,
+    /// The smallest value.
+    Min,
+    /// The largest value.
+    Max,
+    /// The number of values.
+    Count,
+}
+
+impl Aggregation {
+    /// Returns the lowercase name used in generated headers.
+    fn name(&self) -> &'static str {
+        match self {
+            Aggregation::Sum => "sum",
+            Aggregation::Mean => "mean",
+            Aggregation::Min => "min",
+            Aggregation::Max => "max",
+            Aggregation::Count => "count",
+        }
+    }
+
+    /// Computes the statistic of the values, or `None` if there are none.
+    fn apply(&self, values: &[f64]) -> Option<f64> {
+        if values.is_empty() {
+            return None;
+        }
+        Some(match self {
+            Aggregation::Sum => values.iter().sum(),
+            Aggregation::Mean => values.iter().sum::<f64>() / values.len() as f64,
+            Aggregation::Min => values.iter().copied().fold(f64::INFINITY, f64::min),
+            Aggregation::Max => values.iter().copied().fold(f64::NEG_INFINITY, f64::max),
+            Aggregation::Count => values.len() as f64,
+        })
+    }
+}
+
+impl Table {
+    /// Appends a column holding the sum of the specified column from the first row
+    /// down to each row, under the header `label`. Cells that are not numbers add
+    /// nothing. The totals follow the current row order and are not updated when
+    /// rows are added, removed, or sorted later.
+    pub fn add_running_total<'a>(&mut self, column: impl Into<ColumnRef<'a>>, label: &str) {
+        let column_index = self.resolve_column(column);
+        self.add_running_column(column_index, Aggregation::Sum, None, label);
+    }
+
+    /// Appends a column holding the aggregation of the specified column over a
+    /// window ending at each row, such as a rolling mean. With `Some(n)` the window
+    /// holds the row and up to `n - 1` rows before it; with `None` it reaches back to
+    /// the first row. Cells that are not numbers are left out, and rows whose window
+    /// holds no numbers get an empty cell. The header names the aggregation, as in
+    /// `Price (mean of 3)` or `Price (running max)`.
+    pub fn add_running_aggregate<'a>(
+        &mut self,
+        column: impl Into<ColumnRef<'a>>,
+        aggregation: Aggregation,
+        window: Option<usize>,
+    ) {
+        let column_index = self.resolve_column(column);
+        let header = &self.columns[column_index].header;
+        let label = match window {
+            Some(window) => format!("{} ({} of {})", header, aggregation.name(), window),
+            None => format!("{} (running {})", header, aggregation.name()),
+        };
+        self.add_running_column(column_index, aggregation, window, &label);
+    }
+
+    /// Appends the computed column of a running aggregation.
+    fn add_running_column(
+        &mut self,
+        column_index: usize,
+        aggregation: Aggregation,
+        window: Option<usize>,
+        label: &str,
+    ) {
+        let values: Vec<Option<f64>> = self
+            .rows
+            .iter()
+            .map(|row| row[column_index].content.trim().parse::<f64>().ok())
+            .collect();
+        for (i, row) in self.rows.iter_mut().enumerate() {
+            let start = window.map_or(0, |window| (i + 1).saturating_sub(window.max(1)));
+            let run: Vec<f64> = values[start..=i].iter().flatten().copied().collect();
+            let result = aggregation.apply(&run).map(format_number);
+            row.cells.push(Cell::new(&result.unwrap_or_default()));
+        }
+        if let Some(footer) = &mut self.footer {
+            footer.push(Cell::new(""));
+        }
+        self.add_column(label, ColumnWidth::Auto, Alignment::Right);
+        self.invalidate_widths();
+    }
+}
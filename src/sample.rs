@@ -0,0 +1,89 @@
+// SPDX-License-Identifier: MIT
+// Project: tabprinter
+// File: src/sample.rs
+// Author: Volker Schwaberow <volker@schwaberow.de>
+// Copyright (c) 2025 Volker Schwaberow
+
+use std::collections::HashMap;
+
+use crate::{ColumnRef, Row, Table};
+
+/// A small deterministic random number generator (SplitMix64), so samples with the
+/// same seed are the same on every platform and release.
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    /// Returns the next random number.
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a random number below `bound`, which must not be zero.
+    fn below(&mut self, bound: usize) -> usize {
+        (self.next() % bound as u64) as usize
+    }
+
+    /// Picks `n` of the items at random, or all of them if there are fewer, and
+    /// returns them in their original order.
+    fn choose(&mut self, mut items: Vec<usize>, n: usize) -> Vec<usize> {
+        let n = n.min(items.len());
+        for i in 0..n {
+            let j = i + self.below(items.len() - i);
+            items.swap(i, j);
+        }
+        items.truncate(n);
+        items.sort_unstable();
+        items
+    }
+}
+
+impl Table {
+    /// Returns a new table with the given rows of this table, in order.
+    fn derive_with_row_indices(&self, indices: &[usize]) -> Self {
+        let rows: Vec<Row> = indices.iter().map(|&i| self.rows[i].clone()).collect();
+        self.derive_with_rows(rows)
+    }
+
+    /// Returns a new table with `n` rows picked at random, or all rows if the table
+    /// has fewer, for previewing large data sets. The rows keep their order.
+    /// The same seed always picks the same rows of the same table.
+    pub fn sample(&self, n: usize, seed: u64) -> Self {
+        let mut rng = SplitMix64(seed);
+        let indices = rng.choose((0..self.rows.len()).collect(), n);
+        self.derive_with_row_indices(&indices)
+    }
+
+    /// Returns a new table with up to `n_per_group` rows picked at random for every
+    /// distinct value of the specified column, so small groups are not crowded out
+    /// by large ones. The rows keep their order. The same seed always picks the same
+    /// rows of the same table.
+    pub fn sample_stratified<'a>(
+        &self,
+        group_column: impl Into<ColumnRef<'a>>,
+        n_per_group: usize,
+        seed: u64,
+    ) -> Self {
+        let column_index = self.resolve_column(group_column);
+        let mut groups: Vec<Vec<usize>> = Vec::new();
+        let mut positions: HashMap<&str, usize> = HashMap::new();
+        for (i, row) in self.rows.iter().enumerate() {
+            let value = row[column_index].content.as_str();
+            let position = *positions.entry(value).or_insert_with(|| {
+                groups.push(Vec::new());
+                groups.len() - 1
+            });
+            groups[position].push(i);
+        }
+        let mut rng = SplitMix64(seed);
+        let mut indices: Vec<usize> = groups
+            .into_iter()
+            .flat_map(|group| rng.choose(group, n_per_group))
+            .collect();
+        indices.sort_unstable();
+        self.derive_with_row_indices(&indices)
+    }
+}
@@ -0,0 +1,260 @@
+// SPDX-License-Identifier: MIT
+// Project: tabprinter
+// File: src/schema.rs
+// Author: Volker Schwaberow <volker@schwaberow.de>
+// Copyright (c) 2025 Volker Schwaberow
+
+use std::sync::Arc;
+
+use crate::boolean::parse_bool;
+use crate::{Alignment, CellStyle, ColumnWidth, Table, TableError, TableStyle, TableTemplate};
+
+/// A function converting the raw text of a cell into its normalized content.
+type Parser = Arc<dyn Fn(&str) -> Result<String, String> + Send + Sync>;
+
+/// The type of the values of a column, used to normalize imported text.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ColumnType {
+    /// The text is kept as it is.
+    Text,
+    /// Whole numbers, such as `-42`.
+    Integer,
+    /// Numbers with the given decimal and thousands separators, such as `1.234,5`
+    /// with `decimal_separator: ','` and `thousands_separator: Some('.')`.
+    Number {
+        /// The character between the whole and fractional part.
+        decimal_separator: char,
+        /// The character grouping thousands, if any.
+        thousands_separator: Option<char>,
+    },
+    /// Truth values written as `yes`/`no`, `true`/`false`, `y`/`n`, `on`/`off`, or `1`/`0`.
+    Boolean,
+}
+
+impl ColumnType {
+    /// Normalizes the text to the canonical form of the type: numbers with a `.`
+    /// decimal point and no grouping, and booleans as `true` or `false`.
+    /// Empty cells stay empty.
+    fn parse(&self, text: &str) -> Result<String, String> {
+        let text = text.trim();
+        if text.is_empty() || *self == ColumnType::Text {
+            return Ok(text.to_string());
+        }
+        match self {
+            ColumnType::Text => unreachable!(),
+            ColumnType::Integer => text
+                .parse::<i64>()
+                .map(|value| value.to_string())
+                .map_err(|_| "not a whole number".to_string()),
+            ColumnType::Number {
+                decimal_separator,
+                thousands_separator,
+            } => {
+                let normalized: String = text
+                    .chars()
+                    .filter(|c| Some(*c) != *thousands_separator)
+                    .map(|c| if c == *decimal_separator { '.' } else { c })
+                    .collect();
+                normalized
+                    .parse::<f64>()
+                    .map(|_| normalized)
+                    .map_err(|_| "not a number".to_string())
+            }
+            ColumnType::Boolean => parse_bool(text)
+                .map(|value| value.to_string())
+                .ok_or_else(|| "not a yes/no value".to_string()),
+        }
+    }
+}
+
+/// The declaration of one column: its type, the format of its cells, and its alignment.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ColumnDef<'a> {
+    /// The header of the column.
+    pub name: &'a str,
+    /// The type of the values.
+    pub dtype: ColumnType,
+    /// The style given to every cell of the column, such as its decimal places, if any.
+    pub format: Option<CellStyle>,
+    /// The alignment of the column. Numeric columns default to right-aligned.
+    pub alignment: Option<Alignment>,
+}
+
+impl<'a> ColumnDef<'a> {
+    /// Declares a column of the type, with the default format and alignment.
+    pub fn new(name: &'a str, dtype: ColumnType) -> Self {
+        Self {
+            name,
+            dtype,
+            format: None,
+            alignment: None,
+        }
+    }
+
+    /// Sets the style given to every cell of the column.
+    pub fn format(mut self, format: CellStyle) -> Self {
+        self.format = Some(format);
+        self
+    }
+
+    /// Sets the alignment of the column.
+    pub fn alignment(mut self, alignment: Alignment) -> Self {
+        self.alignment = Some(alignment);
+        self
+    }
+}
+
+/// A column declared in a schema.
+#[derive(Clone)]
+struct SchemaColumn {
+    /// The header of the column.
+    header: String,
+    /// The conversion applied to every cell.
+    parser: Parser,
+    /// The style given to every cell, if any.
+    format: Option<CellStyle>,
+    /// The alignment of the column, if set.
+    alignment: Option<Alignment>,
+}
+
+/// Declares the columns of a table in one place: the type or parser of every
+/// column, the format of its cells, and its alignment. Schemas convert imported
+/// data so it sorts and aggregates correctly without a cleanup pass, validate
+/// added rows, and describe tables through templates.
+#[derive(Clone, Default)]
+pub struct Schema {
+    /// The declared columns, in order.
+    columns: Vec<SchemaColumn>,
+}
+
+impl Schema {
+    /// Creates a schema that leaves every column as text.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Declares the type of the column with the header.
+    /// Numeric columns are right-aligned.
+    pub fn column(self, header: &str, column_type: ColumnType) -> Self {
+        self.define(ColumnDef::new(header, column_type))
+    }
+
+    /// Declares a column with its type, format, and alignment.
+    pub fn define(mut self, def: ColumnDef<'_>) -> Self {
+        let numeric = matches!(def.dtype, ColumnType::Integer | ColumnType::Number { .. });
+        let column_type = def.dtype;
+        self.columns.push(SchemaColumn {
+            header: def.name.to_string(),
+            parser: Arc::new(move |text: &str| column_type.parse(text)),
+            format: def.format,
+            alignment: def.alignment.or(numeric.then_some(Alignment::Right)),
+        });
+        self
+    }
+
+    /// Declares a custom parser for the column with the header.
+    /// The parser returns the new content of a cell or a description of the problem.
+    pub fn parser<F>(mut self, header: &str, parser: F) -> Self
+    where
+        F: Fn(&str) -> Result<String, String> + Send + Sync + 'static,
+    {
+        self.columns.push(SchemaColumn {
+            header: header.to_string(),
+            parser: Arc::new(parser),
+            format: None,
+            alignment: None,
+        });
+        self
+    }
+
+    /// Returns a template with the declared columns in order, sized to their content,
+    /// so tables created from it have the declared alignments and cell formats.
+    pub fn to_template(&self, style: TableStyle) -> TableTemplate {
+        let mut template = TableTemplate::new(style);
+        for column in &self.columns {
+            template = template.column(
+                &column.header,
+                ColumnWidth::Auto,
+                column.alignment.unwrap_or(Alignment::Left),
+            );
+            if let Some(format) = column.format {
+                template = template.format(&column.header, format);
+            }
+        }
+        template
+    }
+}
+
+impl Table {
+    /// Returns the position of every column declared in the schema.
+    fn schema_positions(&self, schema: &Schema) -> Result<Vec<usize>, TableError> {
+        schema
+            .columns
+            .iter()
+            .map(|column| self.column_position(column.header.as_str()))
+            .collect()
+    }
+
+    /// Converts every cell of the columns named in the schema and applies their
+    /// declared formats and alignments.
+    /// Fails on the first cell that cannot be parsed, leaving the table unchanged,
+    /// or if the schema names a column the table does not have.
+    pub fn apply_schema(&mut self, schema: &Schema) -> Result<(), TableError> {
+        let positions = self.schema_positions(schema)?;
+        let mut converted = Vec::with_capacity(schema.columns.len());
+        for (column, &column_index) in schema.columns.iter().zip(&positions) {
+            let values = self
+                .rows
+                .iter()
+                .enumerate()
+                .map(|(row_index, row)| {
+                    let value = &row[column_index].content;
+                    (column.parser)(value).map_err(|reason| TableError::Parse {
+                        row: row_index,
+                        column: column.header.clone(),
+                        value: value.clone(),
+                        reason,
+                    })
+                })
+                .collect::<Result<Vec<String>, TableError>>()?;
+            converted.push(values);
+        }
+        for ((column, column_index), values) in schema.columns.iter().zip(positions).zip(converted)
+        {
+            for (row, value) in self.rows.iter_mut().zip(values) {
+                row[column_index].content = value;
+                if let Some(format) = column.format {
+                    row[column_index].style = format;
+                }
+            }
+            if let Some(alignment) = column.alignment {
+                self.columns[column_index].alignment = alignment;
+            }
+        }
+        self.invalidate_widths();
+        Ok(())
+    }
+
+    /// Sets a row validator rejecting added rows whose cells do not parse as the
+    /// types declared in the schema. Fails if the schema names a column the table
+    /// does not have.
+    pub fn set_schema_validator(&mut self, schema: &Schema) -> Result<(), TableError> {
+        let checks: Vec<(usize, SchemaColumn)> = self
+            .schema_positions(schema)?
+            .into_iter()
+            .zip(schema.columns.iter().cloned())
+            .collect();
+        self.set_row_validator(move |row| {
+            for (column_index, column) in &checks {
+                (column.parser)(&row[*column_index].content).map_err(|reason| {
+                    format!(
+                        "column `{}`: cannot parse `{}`: {}",
+                        column.header, row[*column_index].content, reason
+                    )
+                })?;
+            }
+            Ok(())
+        });
+        Ok(())
+    }
+}
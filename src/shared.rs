@@ -0,0 +1,111 @@
+// SPDX-License-Identifier: MIT
+// Project: tabprinter
+// File: src/shared.rs
+// Author: Volker Schwaberow <volker@schwaberow.de>
+// Copyright (c) 2025 Volker Schwaberow
+
+use std::io;
+use std::sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+use crate::color::WriteColor;
+use crate::{Cell, Table, TableError};
+
+/// A table that worker threads append rows to while it is printed, such as the
+/// results of scanning hosts in parallel. Clones share the same table.
+///
+/// Appends take a short write lock; renders and snapshots take a read lock, so
+/// they always see a consistent set of complete rows.
+#[derive(Clone)]
+pub struct SharedTable {
+    /// The table shared by all clones.
+    inner: Arc<RwLock<Table>>,
+}
+
+impl SharedTable {
+    /// Shares the table, which provides the columns, style, and any rows so far.
+    pub fn new(table: Table) -> Self {
+        Self {
+            inner: Arc::new(RwLock::new(table)),
+        }
+    }
+
+    /// Locks the table for reading. A panic in another thread holding the lock
+    /// does not make the table unusable, as every mutation leaves it consistent.
+    fn read(&self) -> RwLockReadGuard<'_, Table> {
+        self.inner
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Locks the table for writing.
+    fn write(&self) -> RwLockWriteGuard<'_, Table> {
+        self.inner
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Appends a row like `Table::try_add_row`, returning why it was rejected.
+    pub fn append_row(&self, row: Vec<Cell>) -> Result<(), TableError> {
+        self.write().try_add_row(row)
+    }
+
+    /// Returns the number of rows appended so far.
+    pub fn len(&self) -> usize {
+        self.read().rows.len()
+    }
+
+    /// Returns whether the table has no rows.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Calls the function with the table locked for reading, such as to compute
+    /// a statistic over the rows so far.
+    pub fn with<R>(&self, f: impl FnOnce(&Table) -> R) -> R {
+        f(&self.read())
+    }
+
+    /// Calls the function with the table locked for writing, such as to sort the
+    /// rows or change settings. Appends from other threads wait until it returns.
+    pub fn with_mut<R>(&self, f: impl FnOnce(&mut Table) -> R) -> R {
+        f(&mut self.write())
+    }
+
+    /// Returns a copy of the table as it is now, for printing or further
+    /// processing while the workers keep appending, as with `watch`.
+    pub fn snapshot(&self) -> Table {
+        self.read().duplicate()
+    }
+
+    /// Renders the rows appended so far as text without colors.
+    pub fn render(&self) -> String {
+        self.read().render()
+    }
+
+    /// Prints the rows appended so far to the writer. The table stays locked for
+    /// reading while printing, so use `snapshot` for slow writers.
+    pub fn print_to_writer(&self, writer: &mut dyn WriteColor) -> io::Result<()> {
+        self.read().print_to_writer(writer)
+    }
+
+    /// Prints the rows appended so far to the standard output.
+    pub fn print(&self) -> io::Result<()> {
+        self.read().print()
+    }
+
+    /// Returns the table if no other clone shares it, or the shared table back.
+    pub fn try_into_inner(self) -> Result<Table, SharedTable> {
+        match Arc::try_unwrap(self.inner) {
+            Ok(lock) => Ok(lock
+                .into_inner()
+                .unwrap_or_else(|poisoned| poisoned.into_inner())),
+            Err(inner) => Err(Self { inner }),
+        }
+    }
+}
+
+impl From<Table> for SharedTable {
+    fn from(table: Table) -> Self {
+        Self::new(table)
+    }
+}
@@ -0,0 +1,63 @@
+// SPDX-License-Identifier: MIT
+// Project: tabprinter
+// File: src/side_by_side.rs
+// Author: Volker Schwaberow <volker@schwaberow.de>
+// Copyright (c) 2025 Volker Schwaberow
+
+use std::io;
+
+use crate::color::{Buffer, ColorChoice, StandardStream, WriteColor};
+use crate::text::text_width;
+use crate::Table;
+
+/// Prints the tables next to each other to the writer, separated by `gap` spaces.
+/// Their top edges are aligned and every table is padded to its widest line,
+/// so the tables after it start in the same column on every line.
+pub fn print_side_by_side_to_writer(
+    writer: &mut dyn WriteColor,
+    tables: &[&Table],
+    gap: usize,
+) -> io::Result<()> {
+    let mut blocks = Vec::with_capacity(tables.len());
+    for table in tables {
+        let plain = table.render();
+        let widths: Vec<usize> = plain.lines().map(text_width).collect();
+        let lines = if writer.supports_color() {
+            let mut buffer = Buffer::ansi();
+            table.print_color(&mut buffer)?;
+            String::from_utf8_lossy(buffer.as_slice()).into_owned()
+        } else {
+            plain
+        };
+        let lines: Vec<String> = lines.lines().map(String::from).collect();
+        let width = widths.iter().copied().max().unwrap_or(0);
+        blocks.push((lines, widths, width));
+    }
+    let height = blocks
+        .iter()
+        .map(|(lines, _, _)| lines.len())
+        .max()
+        .unwrap_or(0);
+    let gap = " ".repeat(gap);
+    for line_index in 0..height {
+        let mut line = String::new();
+        for (i, (lines, widths, width)) in blocks.iter().enumerate() {
+            if i > 0 {
+                line.push_str(&gap);
+            }
+            let text = lines.get(line_index).map_or("", String::as_str);
+            let used = widths.get(line_index).copied().unwrap_or(0);
+            line.push_str(text);
+            line.push_str(&" ".repeat(width - used));
+        }
+        writeln!(writer, "{}", line.trim_end_matches(' '))?;
+    }
+    Ok(())
+}
+
+/// Prints the tables next to each other to the standard output, separated by
+/// `gap` spaces, such as a before and after comparison.
+pub fn print_side_by_side(tables: &[&Table], gap: usize) -> io::Result<()> {
+    let mut stdout = StandardStream::stdout(ColorChoice::Always);
+    print_side_by_side_to_writer(&mut stdout, tables, gap)
+}
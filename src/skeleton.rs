@@ -0,0 +1,67 @@
+// SPDX-License-Identifier: MIT
+// Project: tabprinter
+// File: src/skeleton.rs
+// Author: Volker Schwaberow <volker@schwaberow.de>
+// Copyright (c) 2025 Volker Schwaberow
+
+use std::io;
+
+use crate::color::{ColorChoice, StandardStream, WriteColor};
+use crate::{Cell, ColumnWidth, RenderSafety, Row, RowId, Table};
+
+/// The character filling the placeholder cells of a skeleton.
+const SHADE: &str = "▒";
+
+/// The character filling the placeholder cells under strict render safety.
+const ASCII_SHADE: &str = "#";
+
+impl Table {
+    /// Returns a copy of the table whose data rows are shaded placeholders
+    /// with the widths the current columns are printed with.
+    fn skeleton(&self, rows: usize) -> Table {
+        let widths = self.column_widths();
+        let padding = self.cell_padding(&Cell::new(""));
+        let spacing = if self.style.config().is_some() { 0 } else { 1 };
+        let shade = match self.render_safety {
+            RenderSafety::Strict => ASCII_SHADE,
+            RenderSafety::Raw => SHADE,
+        };
+        let cells: Vec<Cell> = widths
+            .iter()
+            .map(|width| Cell::new(&shade.repeat(width.saturating_sub(spacing + 2 * padding))))
+            .collect();
+        let skeleton_rows = (0..rows)
+            .map(|id| Row {
+                id: RowId(id as u64),
+                cells: cells.clone(),
+            })
+            .collect();
+        let mut skeleton = self.derive_with_rows(skeleton_rows);
+        for (column, width) in skeleton.columns.iter_mut().zip(widths) {
+            column.width = ColumnWidth::Fixed(width);
+            column.mask = None;
+        }
+        skeleton.footer = None;
+        skeleton.footnotes.clear();
+        skeleton.row_gradient = None;
+        skeleton
+    }
+
+    /// Prints the frame of the table with `rows` shaded placeholder rows instead
+    /// of its data, such as a loading state shown before the data arrives.
+    /// The columns keep the widths they currently print with.
+    pub fn print_skeleton_to_writer(
+        &self,
+        writer: &mut dyn WriteColor,
+        rows: usize,
+    ) -> io::Result<()> {
+        self.skeleton(rows).print_to_writer(writer)
+    }
+
+    /// Prints the frame of the table with `rows` shaded placeholder rows
+    /// to the standard output.
+    pub fn print_skeleton(&self, rows: usize) -> io::Result<()> {
+        let mut stdout = StandardStream::stdout(ColorChoice::Always);
+        self.skeleton(rows).print_color(&mut stdout)
+    }
+}
@@ -0,0 +1,284 @@
+// SPDX-License-Identifier: MIT
+// Project: tabprinter
+// File: src/sort.rs
+// Author: Volker Schwaberow <volker@schwaberow.de>
+// Copyright (c) 2025 Volker Schwaberow
+
+use std::cmp::Ordering;
+use unicode_normalization::char::is_combining_mark;
+use unicode_normalization::UnicodeNormalization;
+
+use crate::{Cell, ColumnRef, Row, Table};
+
+/// A language whose alphabet order is used to collate text.
+/// Letters are compared without regard to accents, except for the letters the
+/// language places elsewhere in its alphabet, such as `å`, `ä`, and `ö` after `z`
+/// in Swedish.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Locale {
+    /// The primary language subtag, such as `sv` for `sv-SE`.
+    language: String,
+}
+
+impl Locale {
+    /// Creates a locale from a language tag such as `"de"` or `"sv-SE"`.
+    pub fn new(tag: &str) -> Self {
+        let language = tag.split(['-', '_']).next().unwrap_or("");
+        Self {
+            language: language.to_ascii_lowercase(),
+        }
+    }
+
+    /// Returns the letters sorted after another letter in this language.
+    /// Each entry is the letter, the letter it follows, and its rank among
+    /// the letters following the same letter.
+    fn tailoring(&self) -> &'static [(char, char, u32)] {
+        match self.language.as_str() {
+            "sv" | "fi" => &[
+                ('å', 'z', 1),
+                ('ä', 'z', 2),
+                ('æ', 'z', 2),
+                ('ö', 'z', 3),
+                ('ø', 'z', 3),
+            ],
+            "da" | "nb" | "nn" | "no" => &[('æ', 'z', 1), ('ø', 'z', 2), ('å', 'z', 3)],
+            "es" => &[('ñ', 'n', 1)],
+            _ => &[],
+        }
+    }
+
+    /// Returns the primary collation key of the text: lowercase letters with their
+    /// accents removed, except for tailored letters.
+    fn primary_key(&self, text: &str) -> Vec<u32> {
+        let tailoring = self.tailoring();
+        let mut key = Vec::with_capacity(text.len());
+        for c in text.chars().flat_map(char::to_lowercase) {
+            match tailoring.iter().find(|(letter, _, _)| *letter == c) {
+                Some((_, after, rank)) => key.push(u32::from(*after) * 4 + rank),
+                None => key.extend(
+                    c.to_string()
+                        .nfd()
+                        .filter(|c| !is_combining_mark(*c))
+                        .map(|c| u32::from(c) * 4),
+                ),
+            }
+        }
+        key
+    }
+
+    /// Compares two strings in the alphabet order of this language.
+    /// Strings equal except for accents and case are ordered by their lowercase
+    /// form first and their exact content last.
+    pub(crate) fn compare(&self, a: &str, b: &str) -> Ordering {
+        self.primary_key(a)
+            .cmp(&self.primary_key(b))
+            .then_with(|| a.to_lowercase().cmp(&b.to_lowercase()))
+            .then_with(|| a.cmp(b))
+    }
+}
+
+/// Determines how cell contents are ordered.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SortKind {
+    /// Contents are compared character by character.
+    #[default]
+    Lexical,
+    /// Runs of digits are compared by their numeric value, so `file2` sorts
+    /// before `file10` and `v1.9.0` before `v1.10.0`.
+    Natural,
+}
+
+/// Splits the text into alternating runs of digits and other characters.
+fn natural_chunks(text: &str) -> Vec<&str> {
+    let mut chunks = Vec::new();
+    let mut start = 0;
+    let mut digits = None;
+    for (i, c) in text.char_indices() {
+        let is_digit = c.is_ascii_digit();
+        if digits.is_some_and(|d| d != is_digit) {
+            chunks.push(&text[start..i]);
+            start = i;
+        }
+        digits = Some(is_digit);
+    }
+    if start < text.len() {
+        chunks.push(&text[start..]);
+    }
+    chunks
+}
+
+/// Compares two strings in natural order, comparing runs of digits numerically
+/// and all other runs with `compare_text`.
+fn natural_compare<F>(a: &str, b: &str, compare_text: F) -> Ordering
+where
+    F: Fn(&str, &str) -> Ordering,
+{
+    let (a_chunks, b_chunks) = (natural_chunks(a), natural_chunks(b));
+    for (a_chunk, b_chunk) in a_chunks.iter().zip(&b_chunks) {
+        let both_digits = a_chunk.starts_with(|c: char| c.is_ascii_digit())
+            && b_chunk.starts_with(|c: char| c.is_ascii_digit());
+        let ord = if both_digits {
+            let (a_value, b_value) = (
+                a_chunk.trim_start_matches('0'),
+                b_chunk.trim_start_matches('0'),
+            );
+            a_value
+                .len()
+                .cmp(&b_value.len())
+                .then_with(|| a_value.cmp(b_value))
+                .then_with(|| a_chunk.len().cmp(&b_chunk.len()))
+        } else {
+            compare_text(a_chunk, b_chunk)
+        };
+        if ord != Ordering::Equal {
+            return ord;
+        }
+    }
+    a_chunks.len().cmp(&b_chunks.len())
+}
+
+/// Options controlling how cell contents are compared when sorting.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SortOptions {
+    /// Whether letters are compared without regard to case.
+    pub case_insensitive: bool,
+    /// Whether empty cells are placed after all others, regardless of the direction.
+    pub nulls_last: bool,
+    /// The language whose alphabet order is used, if any.
+    pub collation: Option<Locale>,
+    /// Whether contents are compared lexically or naturally.
+    pub kind: SortKind,
+}
+
+impl SortOptions {
+    /// Compares two cells in ascending order.
+    /// Empty cells are not treated specially here; see `compare_directed`.
+    fn compare(&self, a: &Cell, b: &Cell) -> Ordering {
+        let (a, b) = (a.content.as_str(), b.content.as_str());
+        match self.kind {
+            SortKind::Lexical => self.compare_text(a, b),
+            SortKind::Natural => natural_compare(a, b, |a, b| self.compare_text(a, b)),
+        }
+    }
+
+    /// Compares two strings with the case and collation options.
+    fn compare_text(&self, a: &str, b: &str) -> Ordering {
+        match &self.collation {
+            Some(locale) => locale.compare(a, b),
+            None if self.case_insensitive => a
+                .to_lowercase()
+                .cmp(&b.to_lowercase())
+                .then_with(|| a.cmp(b)),
+            None => a.cmp(b),
+        }
+    }
+
+    /// Compares two cells in the requested direction, keeping empty cells last
+    /// when `nulls_last` is set.
+    pub(crate) fn compare_directed(&self, a: &Cell, b: &Cell, ascending: bool) -> Ordering {
+        if self.nulls_last {
+            let (a_null, b_null) = (a.content.trim().is_empty(), b.content.trim().is_empty());
+            if a_null || b_null {
+                return a_null.cmp(&b_null);
+            }
+        }
+        let ord = self.compare(a, b);
+        if ascending {
+            ord
+        } else {
+            ord.reverse()
+        }
+    }
+}
+
+impl Table {
+    /// Sorts the rows by the specified column using the given comparison options.
+    /// If `ascending` is true, sorts in ascending order; otherwise, sorts in descending order.
+    pub fn sort_by_column_with<'a>(
+        &mut self,
+        column: impl Into<ColumnRef<'a>>,
+        ascending: bool,
+        options: &SortOptions,
+    ) {
+        let column_index = self.resolve_column(column);
+        // Sorting moves unmeasured rows out of the tail, so measure them first.
+        self.column_widths();
+        self.separators.clear();
+        self.merges.clear();
+        self.invalidate_numbers();
+        self.rows.sort_by(|a, b| {
+            options.compare_directed(&a[column_index], &b[column_index], ascending)
+        });
+    }
+
+    /// Sorts the rows in ascending order of a key computed from the cells of the
+    /// specified column, such as a parsed version or a severity rank.
+    /// The sort is stable, so rows with equal keys keep their order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no column has the header.
+    pub fn sort_by_key_fn<'a, K, F>(&mut self, column: impl Into<ColumnRef<'a>>, mut key_fn: F)
+    where
+        K: Ord,
+        F: FnMut(&Cell) -> K,
+    {
+        let column_index = self.resolve_column(column);
+        self.column_widths();
+        self.separators.clear();
+        self.merges.clear();
+        self.invalidate_numbers();
+        self.rows
+            .sort_by_cached_key(|row| key_fn(&row[column_index]));
+    }
+
+    /// Returns a new table with the `n` rows holding the largest numeric values
+    /// of the specified column, largest first.
+    /// Rows whose cell is not a number are left out.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no column has the header.
+    pub fn top_n<'a>(&self, column: impl Into<ColumnRef<'a>>, n: usize) -> Self {
+        let column_index = self.resolve_column(column);
+        self.ranked_rows(column_index, n, false)
+    }
+
+    /// Returns a new table with the `n` rows holding the smallest numeric values
+    /// of the specified column, smallest first.
+    /// Rows whose cell is not a number are left out.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no column has the header.
+    pub fn bottom_n<'a>(&self, column: impl Into<ColumnRef<'a>>, n: usize) -> Self {
+        let column_index = self.resolve_column(column);
+        self.ranked_rows(column_index, n, true)
+    }
+
+    /// Returns a new table with the first `n` numeric rows ordered by the column.
+    fn ranked_rows(&self, column_index: usize, n: usize, ascending: bool) -> Self {
+        let mut ranked: Vec<(f64, &Row)> = self
+            .rows
+            .iter()
+            .filter_map(|row| {
+                let value = row[column_index].content.trim().parse::<f64>().ok()?;
+                (!value.is_nan()).then_some((value, row))
+            })
+            .collect();
+        ranked.sort_by(|a, b| {
+            let ord = a.0.total_cmp(&b.0);
+            if ascending {
+                ord
+            } else {
+                ord.reverse()
+            }
+        });
+        let rows = ranked
+            .into_iter()
+            .take(n)
+            .map(|(_, row)| row.clone())
+            .collect();
+        self.derive_with_rows(rows)
+    }
+}
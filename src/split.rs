@@ -0,0 +1,124 @@
+// SPDX-License-Identifier: MIT
+// Project: tabprinter
+// File: src/split.rs
+// Author: Volker Schwaberow <volker@schwaberow.de>
+// Copyright (c) 2025 Volker Schwaberow
+
+use std::io;
+
+use crate::color::{ColorChoice, StandardStream, WriteColor};
+use crate::text::text_width;
+use crate::{ColumnRef, ColumnWidth, Table, TableError, TableStyle};
+
+impl Table {
+    /// Sets the columns repeated at the left of every part when the table is printed
+    /// split, such as a name or identifier that keeps rows recognizable. Columns are
+    /// given by index or header. Fails if a column does not exist.
+    pub fn set_sticky_columns<'a, C: Into<ColumnRef<'a>>>(
+        &mut self,
+        columns: impl IntoIterator<Item = C>,
+    ) -> Result<(), TableError> {
+        self.sticky_columns = self.column_positions(columns)?;
+        Ok(())
+    }
+
+    /// Returns the columns repeated in every part: the key column, if given,
+    /// followed by the sticky columns.
+    fn repeated_columns(&self, key_column: Option<usize>) -> Vec<usize> {
+        let mut repeated: Vec<usize> = key_column.into_iter().collect();
+        for &column in &self.sticky_columns {
+            if column < self.columns.len() && !repeated.contains(&column) {
+                repeated.push(column);
+            }
+        }
+        repeated
+    }
+
+    /// Returns the printed width of a table made of the given columns.
+    fn chunk_width(&self, columns: &[usize], widths: &[usize]) -> usize {
+        let total: usize = columns.iter().map(|&c| widths[c]).sum();
+        match self.style.config() {
+            Some(style) => {
+                total
+                    + text_width(style.row.begin)
+                    + text_width(style.row.end)
+                    + text_width(style.row.sep) * columns.len().saturating_sub(1)
+                    + 2 * style.cell_padding * columns.len()
+            }
+            None => total.saturating_sub(1),
+        }
+    }
+
+    /// Splits the columns into groups that each fit in `max_width`, starting every
+    /// group with the repeated columns. A column too wide on its own gets a group
+    /// of its own.
+    pub(crate) fn split_columns(
+        &self,
+        max_width: usize,
+        key_column: Option<usize>,
+    ) -> Vec<Vec<usize>> {
+        let widths = self.column_widths();
+        let start = self.repeated_columns(key_column);
+        let mut chunks = Vec::new();
+        let mut chunk = start.clone();
+        for column in (0..self.columns.len()).filter(|c| !start.contains(c)) {
+            chunk.push(column);
+            if chunk.len() > start.len() + 1 && self.chunk_width(&chunk, &widths) > max_width {
+                chunk.pop();
+                chunks.push(std::mem::replace(&mut chunk, start.clone()));
+                chunk.push(column);
+            }
+        }
+        if chunk.len() > start.len() || chunks.is_empty() {
+            chunks.push(chunk);
+        }
+        chunks
+    }
+
+    /// Prints a table wider than `max_width` as several tables stacked on top of
+    /// each other, each showing as many columns as fit. The key column, given by index
+    /// or header, such as a name or identifier, and the sticky columns are repeated at the left of every
+    /// part so rows can be matched up. Parts are separated by an empty line.
+    /// Every column keeps the width it has in the whole table, and the footnotes
+    /// are printed once, after the last part.
+    pub fn print_split_to_writer(
+        &self,
+        writer: &mut dyn WriteColor,
+        max_width: usize,
+        key_column: Option<ColumnRef<'_>>,
+    ) -> io::Result<()> {
+        let key_column = key_column
+            .map(|column| self.column_position(column))
+            .transpose()?;
+        if self.style == TableStyle::Accessible {
+            return self.print_to_writer(writer);
+        }
+        let widths = self.column_widths();
+        let parts = self.split_columns(max_width, key_column);
+        for (i, columns) in parts.iter().enumerate() {
+            if i > 0 {
+                writeln!(writer)?;
+            }
+            let mut part = self.view().select(columns)?.to_table();
+            for (column, &c) in part.columns.iter_mut().zip(columns) {
+                column.width = ColumnWidth::Fixed(widths[c]);
+            }
+            if i + 1 < parts.len() {
+                part.footnotes.clear();
+            }
+            part.print_to_writer(writer)?;
+        }
+        Ok(())
+    }
+
+    /// Prints the table to the standard output, split into stacked parts that
+    /// each fit in `max_width`.
+    pub fn print_split(
+        &self,
+        max_width: usize,
+        key_column: Option<ColumnRef<'_>>,
+    ) -> io::Result<()> {
+        let mut stdout = StandardStream::stdout(ColorChoice::Always);
+        self.print_split_to_writer(&mut stdout, max_width, key_column)
+    }
+}
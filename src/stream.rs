@@ -0,0 +1,200 @@
+// SPDX-License-Identifier: MIT
+// Project: tabprinter
+// File: src/stream.rs
+// Author: Volker Schwaberow <volker@schwaberow.de>
+// Copyright (c) 2025 Volker Schwaberow
+
+use std::io;
+
+use crate::color::WriteColor;
+use crate::{Cell, LineStyle, OverflowPolicy, Table, TableStyle};
+
+/// Determines how a `StreamPrinter` fixes the widths of the columns.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum WidthPolicy {
+    /// The widths are measured from the table when the printer is created.
+    /// Later rows wider than their column follow the overflow policy of the
+    /// column, so with the default policy they push the following borders out.
+    #[default]
+    Fixed,
+    /// Like `Fixed`, but content wider than its column is truncated instead of
+    /// overflowing, so the borders never move. Wrapping columns still wrap.
+    GrowNever,
+    /// The first rows pushed are buffered and measured together with the rows of
+    /// the table before anything is printed. Later rows wider than the sample are
+    /// truncated as with `GrowNever`.
+    SampleFirst(usize),
+}
+
+/// Prints rows one at a time as they become available.
+/// Column widths are fixed before the header is printed, so rows can be written
+/// immediately without buffering the whole table.
+pub struct StreamPrinter<W: WriteColor> {
+    /// The table providing columns, style, and the rows printed first.
+    table: Table,
+    /// How the widths of the columns are fixed.
+    policy: WidthPolicy,
+    /// The fixed widths of the columns.
+    widths: Vec<usize>,
+    /// The writer receiving the output.
+    writer: W,
+    /// Whether the header has been printed.
+    started: bool,
+    /// The number of rows buffered for `WidthPolicy::SampleFirst`.
+    sampled: usize,
+    /// The number of rows of the table and rows pushed so far.
+    row_count: usize,
+}
+
+impl<W: WriteColor> StreamPrinter<W> {
+    /// Creates a printer for the columns and style of the table.
+    /// Rows already in the table are printed right after the header.
+    pub fn new(table: Table, writer: W) -> Self {
+        Self::with_width_policy(table, writer, WidthPolicy::Fixed)
+    }
+
+    /// Creates a printer that fixes the column widths according to the policy.
+    pub fn with_width_policy(table: Table, writer: W, policy: WidthPolicy) -> Self {
+        let mut printer = Self {
+            row_count: table.rows.len(),
+            table,
+            policy,
+            widths: Vec::new(),
+            writer,
+            started: false,
+            sampled: 0,
+        };
+        if !matches!(policy, WidthPolicy::SampleFirst(_)) {
+            printer.fix_widths();
+        }
+        printer
+    }
+
+    /// Measures the widths of the columns from the rows collected so far.
+    fn fix_widths(&mut self) {
+        self.widths = self.table.column_widths();
+        if self.policy != WidthPolicy::Fixed {
+            let table = &mut self.table;
+            if table.overflow == OverflowPolicy::Overflow {
+                table.overflow = OverflowPolicy::Truncate;
+            }
+            for column in &mut table.columns {
+                if column.overflow == Some(OverflowPolicy::Overflow) {
+                    column.overflow = Some(OverflowPolicy::Truncate);
+                }
+            }
+        }
+    }
+
+    /// Prints the header and the rows of the table, if not done yet.
+    /// With `WidthPolicy::SampleFirst`, this ends the sample early.
+    pub fn print_header(&mut self) -> io::Result<()> {
+        if self.started {
+            return Ok(());
+        }
+        self.started = true;
+        if matches!(self.policy, WidthPolicy::SampleFirst(_)) {
+            self.fix_widths();
+        }
+        let table = &self.table;
+        table.check_column_widths(&self.widths, 0..table.columns.len())?;
+        match table.style.config() {
+            _ if table.style == TableStyle::Accessible => {}
+            Some(style) => {
+                table.print_top(&mut self.writer, &style.top, &self.widths)?;
+                table.print_header_styled(&mut self.writer, &style.row, &self.widths)?;
+                table.print_line(&mut self.writer, &style.below_header, &self.widths)?;
+            }
+            None => {
+                table.print_top(&mut self.writer, &LineStyle::EMPTY, &self.widths)?;
+                table.print_headers(&mut self.writer, &self.widths)?;
+            }
+        }
+        for row in &table.rows {
+            Self::write_row(table, &mut self.writer, &self.widths, row)?;
+        }
+        self.writer.flush()
+    }
+
+    /// Prints a single row and flushes the writer.
+    /// The length of the row must match the number of columns.
+    /// Rows rejected by the row validator of the table fail with an `InvalidData` error.
+    /// Rows with content wider than a column with `OverflowPolicy::Error` fail with
+    /// `TableError::ContentTooWide` as an `InvalidInput` error and are not printed.
+    pub fn push_row(&mut self, row: Vec<Cell>) -> io::Result<()> {
+        assert_eq!(
+            self.table.columns.len(),
+            row.len(),
+            "Row length must match number of columns"
+        );
+        self.table
+            .check_new_row(&row, self.row_count)
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+        if let WidthPolicy::SampleFirst(sample) = self.policy {
+            if !self.started && self.sampled < sample {
+                self.row_count += 1;
+                self.table.add_row(row);
+                self.sampled += 1;
+                return if self.sampled == sample {
+                    self.print_header()
+                } else {
+                    Ok(())
+                };
+            }
+        }
+        self.print_header()?;
+        self.table.check_row_widths(
+            Some(self.row_count),
+            &row,
+            &self.widths,
+            0..self.table.columns.len(),
+        )?;
+        self.row_count += 1;
+        Self::write_row(&self.table, &mut self.writer, &self.widths, &row)?;
+        self.writer.flush()
+    }
+
+    /// Writes a row using the table style.
+    fn write_row(table: &Table, writer: &mut W, widths: &[usize], row: &[Cell]) -> io::Result<()> {
+        if table.style == TableStyle::Accessible {
+            return writeln!(writer, "{}", table.linear_line(row));
+        }
+        match table.style.config() {
+            Some(style) => table.print_row_styled(writer, row, &style.row, widths, None),
+            None => table.print_row(writer, row, widths, None),
+        }
+    }
+
+    /// Prints the bottom border and returns the writer.
+    pub fn finish(mut self) -> io::Result<W> {
+        self.print_header()?;
+        match self.table.style.config() {
+            _ if self.table.style == TableStyle::Accessible => {
+                if let Some(footer) = &self.table.footer {
+                    writeln!(self.writer, "{}", self.table.linear_line(footer))?;
+                }
+            }
+            Some(style) => {
+                if let Some(footer) = &self.table.footer {
+                    self.table
+                        .print_line(&mut self.writer, &style.above_footer, &self.widths)?;
+                    self.table.print_row_styled(
+                        &mut self.writer,
+                        footer,
+                        &style.row,
+                        &self.widths,
+                        None,
+                    )?;
+                }
+                self.table
+                    .print_line(&mut self.writer, &style.bottom, &self.widths)?;
+            }
+            None => self
+                .table
+                .print_simple_footer(&mut self.writer, &self.widths)?,
+        }
+        self.table.print_footnotes(&mut self.writer)?;
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+}
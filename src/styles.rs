@@ -4,14 +4,16 @@
 // Author: Volker Schwaberow <volker@schwaberow.de>
 // Copyright (c) 2025 Volker Schwaberow
 
-use crate::TableStyleConfig;
+use crate::color::Color;
 use crate::LineStyle;
+use crate::TableStyleConfig;
 
 macro_rules! define_styles {
     ($($name:ident: {
         $($field:ident: {
             $($inner_field:ident: $value:expr),+ $(,)?
         }),+ $(,)?
+        $(; $($option:ident: $option_value:expr),+ $(,)?)?
     }),+ $(,)?
     ) => {
         pub const STYLES: [TableStyleConfig; count_expr!($($name),+)] = [
@@ -20,12 +22,28 @@ macro_rules! define_styles {
                     $($field: LineStyle {
                         $($inner_field: $value,)+
                     },)+
+                    $($($option: $option_value,)+)?
+                    ..BASE
                 },
             )+
         ];
     };
 }
 
+/// Rendering options shared by every style unless overridden.
+const BASE: TableStyleConfig = TableStyleConfig {
+    top: LineStyle::EMPTY,
+    below_header: LineStyle::EMPTY,
+    above_footer: LineStyle::EMPTY,
+    bottom: LineStyle::EMPTY,
+    row: LineStyle::EMPTY,
+    cell_padding: 1,
+    compact: false,
+    line_color: None,
+    edge_color: None,
+    header_underline: "",
+};
+
 macro_rules! count_expr {
     ($($e:expr),*) => { <[()]>::len(&[$(count_expr!(@sub $e)),*]) };
     (@sub $e:expr) => { () };
@@ -35,85 +53,136 @@ define_styles! {
     Simple: {
         top: { begin: "", hline: "", sep: "", end: "" },
         below_header: { begin: "", hline: "", sep: "", end: "" },
+        above_footer: { begin: "", hline: "", sep: "", end: "" },
         bottom: { begin: "", hline: "", sep: "", end: "" },
         row: { begin: "", hline: "", sep: "", end: "" }
     },
     Grid: {
         top: { begin: "+", hline: "-", sep: "+", end: "+" },
         below_header: { begin: "+", hline: "-", sep: "+", end: "+" },
+        above_footer: { begin: "+", hline: "=", sep: "+", end: "+" },
         bottom: { begin: "+", hline: "-", sep: "+", end: "+" },
-        row: { begin: "|", hline: "", sep: "|", end: "|" }
+        row: { begin: "|", hline: "", sep: "|", end: "|" };
+        header_underline: "="
     },
     FancyGrid: {
         top: { begin: "╒", hline: "═", sep: "╤", end: "╕" },
         below_header: { begin: "╞", hline: "═", sep: "╪", end: "╡" },
+        above_footer: { begin: "╞", hline: "═", sep: "╪", end: "╡" },
         bottom: { begin: "╘", hline: "═", sep: "╧", end: "╛" },
         row: { begin: "│", hline: "", sep: "│", end: "│" }
     },
     Clean: {
         top: { begin: "", hline: "─", sep: " ", end: "" },
         below_header: { begin: "", hline: "─", sep: " ", end: "" },
+        above_footer: { begin: "", hline: "═", sep: " ", end: "" },
         bottom: { begin: "", hline: "─", sep: " ", end: "" },
         row: { begin: "", hline: "", sep: " ", end: "" }
     },
     Round: {
         top: { begin: "╭", hline: "─", sep: "┬", end: "╮" },
         below_header: { begin: "├", hline: "─", sep: "┼", end: "┤" },
+        above_footer: { begin: "╞", hline: "═", sep: "╪", end: "╡" },
         bottom: { begin: "╰", hline: "─", sep: "┴", end: "╯" },
-        row: { begin: "│", hline: "", sep: "│", end: "│" }
+        row: { begin: "│", hline: "", sep: "│", end: "│" };
+        header_underline: "═"
     },
     Banner: {
         top: { begin: "╒", hline: "═", sep: "╤", end: "╕" },
         below_header: { begin: "╘", hline: "═", sep: "╧", end: "╛" },
+        above_footer: { begin: "╞", hline: "═", sep: "╪", end: "╡" },
         bottom: { begin: "╘", hline: "═", sep: "╧", end: "╛" },
         row: { begin: "│", hline: "", sep: "│", end: "│" }
     },
     Block: {
         top: { begin: "◢", hline: "■", sep: "■", end: "◣" },
         below_header: { begin: " ", hline: "━", sep: "━", end: " " },
+        above_footer: { begin: " ", hline: "━", sep: "━", end: " " },
         bottom: { begin: "◥", hline: "■", sep: "■", end: "◤" },
         row: { begin: "", hline: "", sep: " ", end: "" }
     },
     Amiga: {
         top: { begin: "", hline: "", sep: "", end: "" },
         below_header: { begin: "", hline: "", sep: "", end: "" },
+        above_footer: { begin: "", hline: "", sep: "", end: "" },
         bottom: { begin: "", hline: "", sep: "", end: "" },
         row: { begin: "", hline: "", sep: "", end: "" }
     },
     Minimal: {
         top: { begin: "┌", hline: "─", sep: "┬", end: "┐" },
         below_header: { begin: "├", hline: "─", sep: "┼", end: "┤" },
+        above_footer: { begin: "╞", hline: "═", sep: "╪", end: "╡" },
         bottom: { begin: "└", hline: "─", sep: "┴", end: "┘" },
-        row: { begin: "│", hline: "", sep: "│", end: "│" }
+        row: { begin: "│", hline: "", sep: "│", end: "│" };
+        header_underline: "═"
     },
     Compact: {
         top: { begin: "┌", hline: "─", sep: "┬", end: "┐" },
         below_header: { begin: "├", hline: "─", sep: "┼", end: "┤" },
+        above_footer: { begin: "╞", hline: "═", sep: "╪", end: "╡" },
         bottom: { begin: "└", hline: "─", sep: "┴", end: "┘" },
-        row: { begin: "│", hline: "", sep: "│", end: "│" }
+        row: { begin: "│", hline: "", sep: "│", end: "│" };
+        cell_padding: 0,
+        compact: true,
+        header_underline: "═"
     },
     Markdown: {
         top: { begin: "", hline: "", sep: "", end: "" },
         below_header: { begin: "|", hline: "-", sep: "|", end: "|" },
+        above_footer: { begin: "", hline: "", sep: "", end: "" },
         bottom: { begin: "", hline: "", sep: "", end: "" },
         row: { begin: "|", hline: "", sep: "|", end: "|" }
     },
     Dotted: {
         top: { begin: ".", hline: ".", sep: ".", end: "." },
         below_header: { begin: ":", hline: ".", sep: ":", end: ":" },
+        above_footer: { begin: ":", hline: "=", sep: ":", end: ":" },
         bottom: { begin: "'", hline: ".", sep: "'", end: "'" },
         row: { begin: ":", hline: "", sep: ":", end: ":" }
     },
     Heavy: {
         top: { begin: "┏", hline: "━", sep: "┳", end: "┓" },
         below_header: { begin: "┣", hline: "━", sep: "╋", end: "┫" },
+        above_footer: { begin: "┣", hline: "━", sep: "╋", end: "┫" },
         bottom: { begin: "┗", hline: "━", sep: "┻", end: "┛" },
-        row: { begin: "┃", hline: "", sep: "┃", end: "┃" }
+        row: { begin: "┃", hline: "", sep: "┃", end: "┃" };
+        header_underline: "═"
     },
     Neon: {
         top: { begin: "┏", hline: "━", sep: "┳", end: "┓" },
         below_header: { begin: "┣", hline: "━", sep: "╋", end: "┫" },
+        above_footer: { begin: "┣", hline: "━", sep: "╋", end: "┫" },
         bottom: { begin: "┗", hline: "━", sep: "┻", end: "┛" },
-        row: { begin: "┃", hline: "", sep: "┃", end: "┃" }
+        row: { begin: "┃", hline: "", sep: "┃", end: "┃" };
+        line_color: Some(Color::Magenta),
+        edge_color: Some(Color::Cyan),
+        header_underline: "═"
+    }
+}
+
+/// Families of junction characters, each listed as
+/// `[crossing, opening downwards, opening upwards]`.
+const JUNCTIONS: [[&str; 3]; 3] = [["┼", "┬", "┴"], ["╪", "╤", "╧"], ["╋", "┳", "┻"]];
+
+/// Returns the junction drawn instead of `sep` when the vertical border above and
+/// below it may be missing. The junction keeps only the directions `sep` already
+/// opens to, and becomes a plain `hline` when none are left.
+/// Characters outside the known families are kept as they are.
+pub(crate) fn junction(
+    sep: &'static str,
+    hline: &'static str,
+    up: bool,
+    down: bool,
+) -> &'static str {
+    let Some(family) = JUNCTIONS.iter().find(|family| family.contains(&sep)) else {
+        return sep;
+    };
+    let up = up && sep != family[1];
+    let down = down && sep != family[2];
+    match (up, down) {
+        (true, true) => family[0],
+        (false, true) => family[1],
+        (true, false) => family[2],
+        (false, false) => hline,
     }
 }
@@ -0,0 +1,147 @@
+// SPDX-License-Identifier: MIT
+// Project: tabprinter
+// File: src/summary.rs
+// Author: Volker Schwaberow <volker@schwaberow.de>
+// Copyright (c) 2025 Volker Schwaberow
+
+use std::io::{self, Write};
+
+use crate::color::{ColorChoice, StandardStream, WriteColor};
+use crate::text::{format_number, text_width};
+use crate::{Cell, Table, TableError};
+
+/// A statistic printed in the summary block under a table.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Summary {
+    /// The sum of the numeric values of the column.
+    Sum(usize),
+    /// The average of the numeric values of the column.
+    Avg(usize),
+    /// The number of rows.
+    Count,
+    /// The statistic of the column computed by the aggregator registered under the name.
+    Custom(usize, &'static str),
+}
+
+impl Summary {
+    /// Returns the label starting the summary line.
+    fn label(&self) -> String {
+        match self {
+            Summary::Sum(_) => "Sum:".to_string(),
+            Summary::Avg(_) => "Avg:".to_string(),
+            Summary::Count => "Count:".to_string(),
+            Summary::Custom(_, name) => format!("{}:", name),
+        }
+    }
+
+    /// Returns the column the value is aligned under, if any.
+    fn column(&self) -> Option<usize> {
+        match self {
+            Summary::Sum(column_index)
+            | Summary::Avg(column_index)
+            | Summary::Custom(column_index, _) => Some(*column_index),
+            Summary::Count => None,
+        }
+    }
+
+    /// Computes and formats the value for the table.
+    /// Columns without numeric values are shown as `-`.
+    fn value(&self, table: &Table) -> Result<String, TableError> {
+        let value = match self {
+            Summary::Sum(column_index) => table.sum_column(*column_index),
+            Summary::Avg(column_index) => table.average_column(*column_index),
+            Summary::Count => return Ok(table.rows.len().to_string()),
+            Summary::Custom(column_index, name) => table.aggregate_named(*column_index, name)?,
+        };
+        Ok(value.map_or_else(|| "-".to_string(), format_number))
+    }
+}
+
+impl Table {
+    /// Returns the start position and width of the content area of every column
+    /// in the rendered table, inside the default cell padding.
+    fn column_spans(&self, widths: &[usize]) -> Vec<(usize, usize)> {
+        let padding = self.cell_padding(&Cell::new(""));
+        let mut spans = Vec::with_capacity(widths.len());
+        match self.style.config() {
+            Some(style) => {
+                let mut start = text_width(style.row.begin);
+                for width in widths {
+                    spans.push((
+                        start + style.cell_padding + padding,
+                        width.saturating_sub(2 * padding),
+                    ));
+                    start += width + 2 * style.cell_padding + text_width(style.row.sep);
+                }
+            }
+            None => {
+                let mut start = 0;
+                for width in widths {
+                    spans.push((start + padding, width.saturating_sub(1 + 2 * padding)));
+                    start += width;
+                }
+            }
+        }
+        spans
+    }
+
+    /// Formats the summary block, one line per kind of statistic.
+    /// Values are aligned under their columns using the column alignment.
+    /// Fails if a custom summary names an aggregator that is not registered.
+    fn summary_lines(&self, summaries: &[Summary]) -> Result<Vec<String>, TableError> {
+        let spans = self.column_spans(&self.column_widths());
+        let mut labels: Vec<String> = Vec::new();
+        for summary in summaries {
+            if !labels.contains(&summary.label()) {
+                labels.push(summary.label());
+            }
+        }
+        labels
+            .into_iter()
+            .map(|label| {
+                let mut line = label.clone();
+                for summary in summaries.iter().filter(|s| s.label() == label) {
+                    let value = summary.value(self)?;
+                    let (start, value) = match summary.column() {
+                        Some(column_index) => {
+                            let (start, width) = spans[column_index];
+                            let alignment = self.columns[column_index].alignment;
+                            (start, alignment.pad(&value, width))
+                        }
+                        None => (0, value),
+                    };
+                    let used = text_width(&line);
+                    line.push_str(&" ".repeat(start.saturating_sub(used).max(1)));
+                    line.push_str(&value);
+                }
+                Ok(line.trim_end().to_string())
+            })
+            .collect()
+    }
+
+    /// Prints the table to the specified writer followed by a summary block.
+    /// The block is plain text under the bottom border, not part of the table.
+    pub fn print_with_summary_to_writer(
+        &self,
+        writer: &mut dyn WriteColor,
+        summaries: &[Summary],
+    ) -> io::Result<()> {
+        let lines = self.summary_lines(summaries)?;
+        self.print_to_writer(writer)?;
+        for line in lines {
+            writeln!(writer, "{}", line)?;
+        }
+        Ok(())
+    }
+
+    /// Prints the table to the standard output followed by a summary block.
+    pub fn print_with_summary(&self, summaries: &[Summary]) -> io::Result<()> {
+        let lines = self.summary_lines(summaries)?;
+        let mut stdout = StandardStream::stdout(ColorChoice::Always);
+        self.print_color(&mut stdout)?;
+        for line in lines {
+            writeln!(stdout, "{}", line)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,35 @@
+// SPDX-License-Identifier: MIT
+// Project: tabprinter
+// File: src/tabled.rs
+// Author: Volker Schwaberow <volker@schwaberow.de>
+// Copyright (c) 2025 Volker Schwaberow
+
+use tabled::Tabled;
+
+use crate::{Alignment, Cell, ColumnWidth, Table, TableStyle};
+
+impl Table {
+    /// Creates a table from values implementing `tabled::Tabled`, such as structs
+    /// deriving it for the `tabled` crate. The headers become columns sized to
+    /// their content and every value becomes a row.
+    pub fn from_tabled<I, T>(values: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Tabled,
+    {
+        let mut table = Table::new(TableStyle::Simple);
+        for header in T::headers() {
+            table.add_column(&header, ColumnWidth::Auto, Alignment::Left);
+        }
+        for value in values {
+            table.add_row(
+                value
+                    .fields()
+                    .iter()
+                    .map(|field| Cell::new(field))
+                    .collect(),
+            );
+        }
+        table
+    }
+}
@@ -0,0 +1,199 @@
+// SPDX-License-Identifier: MIT
+// Project: tabprinter
+// File: src/tail.rs
+// Author: Volker Schwaberow <volker@schwaberow.de>
+// Copyright (c) 2025 Volker Schwaberow
+
+use std::collections::VecDeque;
+use std::fs::File;
+use std::io::{self, Read, Seek, SeekFrom};
+use std::path::PathBuf;
+use std::time::Duration;
+
+use crate::color::WriteColor;
+use crate::{Alignment, Cell, StreamPrinter, Table, TableStyle};
+
+/// How long `follow_csv` waits between checks for appended records.
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Reads the header and the last `n` records of a CSV file.
+/// Returns the table together with the byte offsets of the end of the header and of the data.
+fn read_tail(path: &str, n: usize) -> io::Result<(Table, u64, u64)> {
+    let mut reader = csv::Reader::from_path(path)?;
+    let mut table = Table::new(TableStyle::Simple);
+    for header in reader.headers()? {
+        table.add_column(header, 10, Alignment::Left);
+    }
+    let header_end = reader.position().byte();
+    let mut last: VecDeque<Vec<Cell>> = VecDeque::with_capacity(n);
+    for result in reader.records() {
+        let record = result?;
+        if n == 0 {
+            continue;
+        }
+        if last.len() == n {
+            last.pop_front();
+        }
+        last.push_back(record.iter().map(Cell::new).collect());
+    }
+    let data_end = reader.position().byte();
+    for row in last {
+        table.add_row(row);
+    }
+    Ok((table, header_end, data_end))
+}
+
+/// Returns the length of the complete records at the start of the chunk: up to the
+/// last line terminator outside a quoted field, so a field holding line breaks is
+/// only read once its closing quote has been written.
+fn complete_len(chunk: &[u8]) -> usize {
+    let mut quoted = false;
+    let mut complete = 0;
+    for (i, &byte) in chunk.iter().enumerate() {
+        match byte {
+            b'"' => quoted = !quoted,
+            b'\n' if !quoted => complete = i + 1,
+            _ => {}
+        }
+    }
+    complete
+}
+
+/// Reads the records appended to a CSV file since the last poll.
+pub struct CsvFollower {
+    /// The path of the followed file.
+    path: PathBuf,
+    /// The byte offset where the data starts, used when the file is truncated.
+    header_end: u64,
+    /// The byte offset up to which records have been read.
+    offset: u64,
+}
+
+impl CsvFollower {
+    /// Starts following the file from its current end.
+    pub fn open(path: &str) -> io::Result<Self> {
+        let (_, header_end, offset) = read_tail(path, 0)?;
+        Ok(Self {
+            path: PathBuf::from(path),
+            header_end,
+            offset,
+        })
+    }
+
+    /// Returns the complete records appended since the last poll.
+    /// A trailing record without a line terminator, or with a quoted field still
+    /// open, is left for the next poll.
+    /// If the file shrank, reading restarts after the header.
+    pub fn poll(&mut self) -> io::Result<Vec<Vec<Cell>>> {
+        let mut file = File::open(&self.path)?;
+        let len = file.metadata()?.len();
+        if len < self.offset {
+            self.offset = self.header_end;
+        }
+        if len == self.offset {
+            return Ok(Vec::new());
+        }
+        file.seek(SeekFrom::Start(self.offset))?;
+        let mut chunk = Vec::new();
+        file.take(len - self.offset).read_to_end(&mut chunk)?;
+        let complete = complete_len(&chunk);
+        if complete == 0 {
+            return Ok(Vec::new());
+        }
+        self.offset += complete as u64;
+        let mut reader = csv::ReaderBuilder::new()
+            .has_headers(false)
+            .from_reader(&chunk[..complete]);
+        let mut rows = Vec::new();
+        for result in reader.records() {
+            let record = result?;
+            rows.push(record.iter().map(Cell::new).collect());
+        }
+        Ok(rows)
+    }
+}
+
+/// Blocks until the followed file may have changed.
+#[cfg(feature = "notify")]
+struct ChangeWaiter {
+    _watcher: notify::RecommendedWatcher,
+    events: std::sync::mpsc::Receiver<notify::Result<notify::Event>>,
+}
+
+#[cfg(feature = "notify")]
+impl ChangeWaiter {
+    fn new(path: &str) -> io::Result<Self> {
+        use notify::Watcher;
+        let (sender, events) = std::sync::mpsc::channel();
+        let mut watcher = notify::recommended_watcher(sender).map_err(io::Error::other)?;
+        watcher
+            .watch(
+                std::path::Path::new(path),
+                notify::RecursiveMode::NonRecursive,
+            )
+            .map_err(io::Error::other)?;
+        Ok(Self {
+            _watcher: watcher,
+            events,
+        })
+    }
+
+    fn wait(&self) {
+        // The timeout also covers changes the watcher cannot report, such as rotated files.
+        if self.events.recv_timeout(POLL_INTERVAL).is_ok() {
+            while self.events.try_recv().is_ok() {}
+        }
+    }
+}
+
+/// Blocks until the followed file may have changed.
+#[cfg(not(feature = "notify"))]
+struct ChangeWaiter;
+
+#[cfg(not(feature = "notify"))]
+impl ChangeWaiter {
+    fn new(_path: &str) -> io::Result<Self> {
+        Ok(Self)
+    }
+
+    fn wait(&self) {
+        std::thread::sleep(POLL_INTERVAL);
+    }
+}
+
+impl Table {
+    /// Creates a table from the last `n` records of a CSV file.
+    /// The first row of the CSV file is used as the header.
+    pub fn tail_csv(path: &str, n: usize) -> io::Result<Self> {
+        read_tail(path, n).map(|(table, _, _)| table)
+    }
+
+    /// Prints the last `n` records of a CSV file and then keeps printing
+    /// records as they are appended, like `tail -f`.
+    /// With the `notify` feature, file system events are used instead of polling.
+    /// Only returns on error.
+    pub fn follow_csv<W: WriteColor>(path: &str, n: usize, writer: W) -> io::Result<()> {
+        let (table, header_end, offset) = read_tail(path, n)?;
+        let columns = table.columns.len();
+        let mut follower = CsvFollower {
+            path: PathBuf::from(path),
+            header_end,
+            offset,
+        };
+        let waiter = ChangeWaiter::new(path)?;
+        let mut printer = StreamPrinter::new(table, writer);
+        printer.print_header()?;
+        loop {
+            waiter.wait();
+            for row in follower.poll()? {
+                if row.len() != columns {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("expected {} fields, found {}", columns, row.len()),
+                    ));
+                }
+                printer.push_row(row)?;
+            }
+        }
+    }
+}
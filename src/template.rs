@@ -0,0 +1,121 @@
+// SPDX-License-Identifier: MIT
+// Project: tabprinter
+// File: src/template.rs
+// Author: Volker Schwaberow <volker@schwaberow.de>
+// Copyright (c) 2025 Volker Schwaberow
+
+use std::collections::HashMap;
+
+use crate::{Alignment, Cell, CellStyle, Column, ColumnWidth, Table, TableStyle};
+
+/// The shape of a table without its rows: the style, the columns with their headers,
+/// widths, and alignments, and the format of the cells of every column.
+/// Tools printing the same report in many places define it once and create
+/// tables from it with `instantiate`.
+#[derive(Clone)]
+pub struct TableTemplate {
+    /// The style of the created tables.
+    pub(crate) style: TableStyle,
+    /// The columns of the created tables.
+    pub(crate) columns: Vec<Column>,
+    /// The style of the cells added by `instantiate_with_rows`, per column.
+    pub(crate) formats: Vec<CellStyle>,
+}
+
+impl TableTemplate {
+    /// Creates a template with the style and no columns.
+    pub fn new(style: TableStyle) -> Self {
+        Self {
+            style,
+            columns: Vec::new(),
+            formats: Vec::new(),
+        }
+    }
+
+    /// Adds a column, as `Table::add_column` does.
+    pub fn column(
+        mut self,
+        header: &str,
+        width: impl Into<ColumnWidth>,
+        alignment: Alignment,
+    ) -> Self {
+        self.columns.push(Column {
+            header: header.to_string(),
+            width: width.into(),
+            alignment,
+            header_alignment: None,
+            overflow: None,
+            header_overflow: None,
+            mask: None,
+            bool_format: None,
+            category_styles: HashMap::new(),
+            truncation_indicator: None,
+        });
+        self.formats.push(CellStyle::default());
+        self
+    }
+
+    /// Sets the style of the cells of the column with the header, such as the
+    /// number of decimal places. Has no effect if no column has the header.
+    pub fn format(mut self, header: &str, style: CellStyle) -> Self {
+        if let Some(index) = self
+            .columns
+            .iter()
+            .position(|column| column.header == header)
+        {
+            self.formats[index] = style;
+        }
+        self
+    }
+
+    /// Returns the headers of the columns.
+    pub fn headers(&self) -> Vec<&str> {
+        self.columns
+            .iter()
+            .map(|column| column.header.as_str())
+            .collect()
+    }
+
+    /// Creates an empty table with the style and columns of the template.
+    pub fn instantiate(&self) -> Table {
+        let mut table = Table::new(self.style);
+        table.columns = self.columns.clone();
+        table
+    }
+
+    /// Creates a table with the style and columns of the template and the rows,
+    /// formatting every cell by its column.
+    /// The length of every row must match the number of columns.
+    pub fn instantiate_with_rows<I, R, S>(&self, rows: I) -> Table
+    where
+        I: IntoIterator<Item = R>,
+        R: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut table = self.instantiate();
+        for row in rows {
+            table.add_row(
+                row.into_iter()
+                    .zip(&self.formats)
+                    .map(|(content, format)| Cell {
+                        style: *format,
+                        ..Cell::new(content.as_ref())
+                    })
+                    .collect(),
+            );
+        }
+        table
+    }
+}
+
+impl Table {
+    /// Captures the style and columns of the table as a template.
+    /// Cells of the created tables get the default format.
+    pub fn to_template(&self) -> TableTemplate {
+        TableTemplate {
+            style: self.style,
+            columns: self.columns.clone(),
+            formats: vec![CellStyle::default(); self.columns.len()],
+        }
+    }
+}
@@ -27,7 +27,7 @@ fn create_test_table(style: TableStyle) -> Table {
 #[test]
 fn test_amiga_table_no_crash() {
     let table = create_test_table(TableStyle::Amiga);
-    let mut buffer = termcolor::Buffer::ansi();
+    let mut buffer = Buffer::ansi();
     table.print_to_writer(&mut buffer).unwrap();
     assert!(!buffer.is_empty());
 }
@@ -38,7 +38,7 @@ fn test_add_column() {
     table.add_column("Test", 10, Alignment::Left);
     assert_eq!(table.columns.len(), 1);
     assert_eq!(table.columns[0].header, "Test");
-    assert_eq!(table.columns[0].width, 10);
+    assert_eq!(table.columns[0].width, ColumnWidth::Fixed(10));
     assert!(matches!(table.columns[0].alignment, Alignment::Left));
 }
 
@@ -69,7 +69,7 @@ fn test_add_row_mismatch() {
 #[test]
 fn test_print_color() {
     let table = create_test_table(TableStyle::Grid);
-    let mut buffer = termcolor::Buffer::ansi();
+    let mut buffer = Buffer::ansi();
     table.print_color(&mut buffer).unwrap();
     let result = String::from_utf8(buffer.into_inner()).unwrap();
     assert!(!result.is_empty());
@@ -78,7 +78,7 @@ fn test_print_color() {
 #[test]
 fn test_print_to_writer() {
     let table = create_test_table(TableStyle::Grid);
-    let mut buffer = termcolor::Buffer::ansi();
+    let mut buffer = Buffer::ansi();
     table.print_to_writer(&mut buffer).unwrap();
     let result = String::from_utf8(buffer.into_inner()).unwrap();
     assert!(!result.is_empty());
@@ -88,7 +88,7 @@ fn test_print_to_writer() {
 fn test_auto_adjust_widths() {
     let mut table = create_test_table(TableStyle::Simple);
     table.auto_adjust_widths();
-    assert!(table.columns.iter().all(|col| col.width > 0));
+    assert!(table.column_widths().iter().all(|width| *width > 0));
 }
 
 #[test]
@@ -123,7 +123,7 @@ fn test_cell_padding() {
     let mut cell = Cell::new("Value");
     cell.style.padding = 2;
     table.add_row(vec![cell]);
-    let mut buffer = termcolor::Buffer::ansi();
+    let mut buffer = Buffer::ansi();
     table.print_to_writer(&mut buffer).unwrap();
     let result = String::from_utf8(buffer.into_inner()).unwrap();
     assert!(result.contains("  Value  "));
@@ -137,7 +137,7 @@ fn test_number_formatting() {
     cell.style.decimal_places = Some(2);
     cell.style.thousand_separator = true;
     table.add_row(vec![cell]);
-    let mut buffer = termcolor::Buffer::ansi();
+    let mut buffer = Buffer::ansi();
     table.print_to_writer(&mut buffer).unwrap();
     let result = String::from_utf8(buffer.into_inner()).unwrap();
     assert!(result.contains("1,234,567.89"));
@@ -153,7 +153,7 @@ fn test_group_by_column_with_subtotals() {
     table.add_row(vec![Cell::new("B"), Cell::new("300")]);
     table.add_row(vec![Cell::new("B"), Cell::new("400")]);
     table.group_by_column_with_subtotals(0);
-    let mut buffer = termcolor::Buffer::ansi();
+    let mut buffer = Buffer::ansi();
     table.print_to_writer(&mut buffer).unwrap();
     let result = String::from_utf8(buffer.into_inner()).unwrap();
     assert!(result.contains("Subtotal"));
@@ -200,3 +200,3213 @@ fn test_max_column() {
     table.add_row(vec![Cell::new("300")]);
     assert_eq!(table.max_column(0), Some(300.0));
 }
+
+#[test]
+fn test_from_pairs() {
+    let table = Table::from_pairs(&[("Name", "Alice"), ("Age", "30")]);
+    assert_eq!(table.columns.len(), 2);
+    assert_eq!(table.rows.len(), 2);
+    assert_eq!(table.rows[1][0].content, "Age");
+    assert_eq!(table.rows[1][1].content, "30");
+}
+
+#[test]
+fn test_record_view() {
+    let table = create_test_table(TableStyle::Grid);
+    let mut buffer = Buffer::no_color();
+    table
+        .record_view(1)
+        .unwrap()
+        .print_to_writer(&mut buffer)
+        .unwrap();
+    let result = String::from_utf8(buffer.into_inner()).unwrap();
+    assert!(result.contains("| Name: Bob         |"));
+    assert!(result.contains("| City: Los Angeles |"));
+    assert!(result.starts_with("+-------------------+"));
+    assert_eq!(
+        table.record_view(2).err(),
+        Some(TableError::RowOutOfBounds { index: 2, len: 2 })
+    );
+}
+
+#[test]
+fn test_cell_alignment_override() {
+    let mut table = Table::new(TableStyle::Grid);
+    table.add_column("Amount", 7, Alignment::Right);
+    let mut cell = Cell::new("-");
+    cell.style.alignment = Some(Alignment::Center);
+    table.add_row(vec![Cell::new("42")]);
+    table.add_row(vec![cell]);
+    let mut buffer = Buffer::no_color();
+    table.print_to_writer(&mut buffer).unwrap();
+    let result = String::from_utf8(buffer.into_inner()).unwrap();
+    assert!(result.contains("     42"));
+    assert!(result.contains("   -   "));
+}
+
+#[test]
+fn test_auto_widths_follow_new_rows() {
+    let mut table = create_test_table(TableStyle::Grid);
+    table.auto_adjust_widths();
+    assert_eq!(table.column_widths(), vec![7, 5, 13]);
+    table.add_row(vec![
+        Cell::new("Christopher"),
+        Cell::new("7"),
+        Cell::new("Rome"),
+    ]);
+    assert_eq!(table.column_widths(), vec![13, 5, 13]);
+    table.sort_by_column(0, true);
+    table.add_row(vec![Cell::new("Eve"), Cell::new("101"), Cell::new("Oslo")]);
+    assert_eq!(table.column_widths(), vec![13, 5, 13]);
+}
+
+#[test]
+fn test_freeze_widths() {
+    let mut table = create_test_table(TableStyle::Grid);
+    table.auto_adjust_widths();
+    table.freeze_widths();
+    table.add_row(vec![
+        Cell::new("Christopher"),
+        Cell::new("7"),
+        Cell::new("Rome"),
+    ]);
+    assert_eq!(table.column_widths(), vec![7, 5, 13]);
+}
+
+#[test]
+fn test_relative_column_widths() {
+    let mut table = Table::new(TableStyle::Grid);
+    table.add_column("Name", ColumnWidth::Percent(30), Alignment::Left);
+    table.add_column("Description", ColumnWidth::Ratio(1), Alignment::Left);
+    table.add_column("Size", 6, Alignment::Right);
+    table.set_target_width(60);
+    assert_eq!(table.column_widths(), vec![15, 29, 6]);
+    let mut buffer = Buffer::no_color();
+    table.print_to_writer(&mut buffer).unwrap();
+    let result = String::from_utf8(buffer.into_inner()).unwrap();
+    assert_eq!(result.lines().next().unwrap().chars().count(), 60);
+}
+
+#[test]
+fn test_add_separator() {
+    let mut table = Table::new(TableStyle::Grid);
+    table.add_column("Name", 5, Alignment::Left);
+    table.add_row(vec![Cell::new("A")]);
+    table.add_separator();
+    table.add_row(vec![Cell::new("B")]);
+    let mut buffer = Buffer::no_color();
+    table.print_to_writer(&mut buffer).unwrap();
+    let result = String::from_utf8(buffer.into_inner()).unwrap();
+    let lines: Vec<&str> = result.lines().collect();
+    assert_eq!(lines.len(), 7);
+    assert_eq!(lines[4], "+-------+");
+}
+
+#[test]
+fn test_separators_dropped_on_sort() {
+    let mut table = create_test_table(TableStyle::Grid);
+    table.add_separator();
+    table.sort_by_column(0, true);
+    assert!(table.separators.is_empty());
+}
+
+#[test]
+fn test_footnotes() {
+    let mut table = Table::new(TableStyle::Grid);
+    table.add_column("Value", ColumnWidth::Auto, Alignment::Left);
+    table.add_row(vec![Cell::new("12.5").with_footnote("1")]);
+    table.set_footnotes(&["Estimated"]);
+    let mut buffer = Buffer::no_color();
+    table.print_to_writer(&mut buffer).unwrap();
+    let result = String::from_utf8(buffer.into_inner()).unwrap();
+    assert_eq!(table.column_widths(), vec![7]);
+    assert!(result.contains("12.5¹"));
+    assert!(result.ends_with("+---------+\n¹ Estimated\n"));
+}
+
+#[test]
+fn test_row_tags_survive_sort_and_filter() {
+    let mut table = create_test_table(TableStyle::Simple);
+    let alice = table.row_id(0).unwrap();
+    table.set_row_tag(alice, 42_u32);
+    table.sort_by_column(0, false);
+    assert_eq!(table.row_index(alice), Some(1));
+    let filtered = table.filter_rows(|row| row[0].content == "Alice");
+    let id = filtered.row_id(0).unwrap();
+    assert_eq!(id, alice);
+    assert_eq!(filtered.row_tag::<u32>(id), Some(&42));
+    assert_eq!(filtered.row_tag::<String>(id), None);
+}
+
+#[test]
+fn test_table_view_leaves_source_untouched() {
+    let table = create_test_table(TableStyle::Simple);
+    let view = table
+        .view()
+        .filter(|row| row[1].content != "99")
+        .sort_by_column(1, true)
+        .select(["City", "Name"])
+        .unwrap();
+    let projected = view.to_table();
+    assert!(table.view().select(["Town"]).is_err());
+    assert_eq!(projected.columns.len(), 2);
+    assert_eq!(projected.columns[0].header, "City");
+    assert_eq!(projected.rows[0][0].content, "Los Angeles");
+    assert_eq!(projected.rows[0][1].content, "Bob");
+    assert_eq!(table.rows[0][0].content, "Alice");
+    assert_eq!(table.columns.len(), 3);
+}
+
+#[test]
+fn test_table_view_grouping() {
+    let mut table = Table::new(TableStyle::Simple);
+    table.add_column("Category", 10, Alignment::Left);
+    table.add_column("Amount", 10, Alignment::Right);
+    table.add_row(vec![Cell::new("B"), Cell::new("300")]);
+    table.add_row(vec![Cell::new("A"), Cell::new("100")]);
+    table.add_row(vec![Cell::new("B"), Cell::new("400")]);
+    let grouped = table
+        .view()
+        .sort_by_column(0, true)
+        .group_by_column_with_subtotals(0)
+        .to_table();
+    assert_eq!(grouped.rows.len(), 5);
+    assert_eq!(grouped.rows[4][1].content, "700");
+    assert_eq!(table.rows.len(), 3);
+}
+
+#[cfg(feature = "csv")]
+#[test]
+fn test_tail_csv() {
+    let table = Table::tail_csv("examples/data.csv", 2).unwrap();
+    assert_eq!(table.columns.len(), 4);
+    assert_eq!(table.rows.len(), 2);
+    assert_eq!(table.rows[0][0].content, "Diana");
+    assert_eq!(table.rows[1][0].content, "Ethan");
+}
+
+#[cfg(feature = "csv")]
+#[test]
+fn test_csv_follower_reads_appended_records() {
+    use std::io::Write as _;
+    let path = std::env::temp_dir().join("tabprinter_follow_test.csv");
+    std::fs::write(&path, "Name,Age\nAlice,30\n").unwrap();
+    let mut follower = CsvFollower::open(path.to_str().unwrap()).unwrap();
+    assert!(follower.poll().unwrap().is_empty());
+    let mut file = std::fs::OpenOptions::new()
+        .append(true)
+        .open(&path)
+        .unwrap();
+    write!(file, "Bob,25\nCarol,4").unwrap();
+    let rows = follower.poll().unwrap();
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0][0].content, "Bob");
+    writeln!(file, "1").unwrap();
+    let rows = follower.poll().unwrap();
+    assert_eq!(rows[0][1].content, "41");
+
+    write!(file, "Dave,\"first line\nsecond").unwrap();
+    assert!(follower.poll().unwrap().is_empty());
+    writeln!(file, " line\"").unwrap();
+    let rows = follower.poll().unwrap();
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0][1].content, "first line\nsecond line");
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_stream_printer() {
+    let mut table = Table::new(TableStyle::Grid);
+    table.add_column("Name", 5, Alignment::Left);
+    let mut printer = StreamPrinter::new(table, Buffer::no_color());
+    printer.push_row(vec![Cell::new("A")]).unwrap();
+    printer.push_row(vec![Cell::new("B")]).unwrap();
+    let buffer = printer.finish().unwrap();
+    let result = String::from_utf8(buffer.into_inner()).unwrap();
+    assert_eq!(result.lines().count(), 6);
+    assert!(result.ends_with("+-------+\n"));
+}
+
+#[test]
+fn test_to_markdown() {
+    let table = create_test_table(TableStyle::Grid);
+    assert_eq!(
+        table.to_markdown(),
+        "| Name | Age | City |\n\
+         | :--- | ---: | :---: |\n\
+         | Alice | 30 | New York |\n\
+         | Bob | 25 | Los Angeles |\n"
+    );
+}
+
+#[test]
+fn test_to_github_summary_collapses_long_tables() {
+    let table = create_test_table(TableStyle::Grid);
+    assert_eq!(table.to_github_summary(), table.to_markdown());
+    let summary = table.to_github_summary_collapsed_after(1);
+    assert!(summary.starts_with("<details>\n<summary>2 rows</summary>\n\n| Name |"));
+    assert!(summary.ends_with("</details>\n"));
+}
+
+#[test]
+fn test_truncate_keeps_grapheme_clusters() {
+    let family = "👨\u{200d}👩\u{200d}👧";
+    let text = format!("ab{}cd", family);
+    assert_eq!(text::truncate(&text, 4, "…"), "ab…");
+    assert_eq!(text::truncate(&text, 5, "…"), format!("ab{}…", family));
+    assert_eq!(text::truncate("e\u{301}tude", 2, "…"), "e\u{301}…");
+    assert_eq!(text::truncate("short", 5, "…"), "short");
+}
+
+#[test]
+fn test_truncate_overflow_policy() {
+    let mut table = Table::new(TableStyle::Grid);
+    table.add_column("City", 6, Alignment::Left);
+    table.add_column("Country", 7, Alignment::Left);
+    table.set_overflow_policy(OverflowPolicy::Truncate);
+    table
+        .set_column_overflow(1, OverflowPolicy::Overflow)
+        .unwrap();
+    table.add_row(vec![Cell::new("Los Angeles"), Cell::new("United States")]);
+    let mut buffer = Buffer::no_color();
+    table.print_to_writer(&mut buffer).unwrap();
+    let result = String::from_utf8(buffer.into_inner()).unwrap();
+    assert!(result.contains("Los…"));
+    assert!(result.contains("United States"));
+}
+
+#[test]
+fn test_error_overflow_policy() {
+    let mut table = Table::new(TableStyle::Grid);
+    table.add_column("City", 6, Alignment::Left);
+    table.add_column("Country", 7, Alignment::Left);
+    table.set_overflow_policy(OverflowPolicy::Error);
+    table
+        .set_column_overflow(1, OverflowPolicy::Truncate)
+        .unwrap();
+    table.add_row(vec![Cell::new("Rome"), Cell::new("Italy")]);
+    table.add_row(vec![Cell::new("Los Angeles"), Cell::new("United States")]);
+    let expected = TableError::ContentTooWide {
+        row: Some(1),
+        column: "City".to_string(),
+        width: 11,
+        available: 4,
+    };
+    assert_eq!(table.check_widths(), Err(expected.clone()));
+
+    let mut buffer = Buffer::no_color();
+    let error = table.print_to_writer(&mut buffer).unwrap_err();
+    assert!(buffer.is_empty());
+    assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+    assert_eq!(
+        error.to_string(),
+        "row 1, column `City`: content is 11 characters wide but only 4 fit"
+    );
+    assert_eq!(error.to_string(), expected.to_string());
+
+    table.set_column_overflow(0, OverflowPolicy::Wrap).unwrap();
+    assert_eq!(table.check_widths(), Ok(()));
+    assert!(table.render().contains("Unit…"));
+}
+
+#[test]
+fn test_resize_column() {
+    let mut table = create_test_table(TableStyle::Grid);
+    table.auto_adjust_widths();
+    table
+        .set_column_overflow("City", OverflowPolicy::Wrap)
+        .unwrap();
+    assert_eq!(
+        table.set_column_overflow("Town", OverflowPolicy::Wrap),
+        Err(TableError::UnknownColumn("Town".to_string()))
+    );
+    let widths = table.column_widths();
+    table.resize_column("City", -4).unwrap();
+    assert_eq!(table.columns[2].width, ColumnWidth::Fixed(widths[2] - 4));
+    assert_eq!(table.column_widths()[..2], widths[..2]);
+    let output = table.render();
+    assert!(output.contains("|    New    |"));
+    assert!(output.contains("|  Angeles  |"));
+
+    table.resize_column(2, 4).unwrap();
+    assert_eq!(table.column_widths(), widths);
+    assert_eq!(
+        table.set_column_width("City", 2),
+        Err(TableError::ColumnTooNarrow {
+            column: "City".to_string(),
+            width: 2,
+            minimum: 3,
+        })
+    );
+    assert_eq!(
+        table.set_column_width(5, 10),
+        Err(TableError::ColumnOutOfBounds { index: 5, len: 3 })
+    );
+
+    table
+        .set_column_overflow("Name", OverflowPolicy::Error)
+        .unwrap();
+    assert!(matches!(
+        table.resize_column("Name", -1),
+        Err(TableError::ContentTooWide { row: Some(0), .. })
+    ));
+    assert_eq!(table.column_widths(), widths);
+
+    let mut table = create_test_table(TableStyle::Grid);
+    table.set_target_width(60);
+    table.columns[0].width = ColumnWidth::Percent(30);
+    table.columns[2].width = ColumnWidth::Ratio(1);
+    let widths = table.column_widths();
+    table.resize_column("Age", 3).unwrap();
+    let resized = table.column_widths();
+    assert_eq!(resized[1], widths[1] + 3);
+    assert_eq!((resized[0], resized[2]), (widths[0], widths[2]));
+}
+
+#[test]
+fn test_multiline_cells_print_each_line() {
+    let mut table = Table::new(TableStyle::Grid);
+    table.add_column("Note", 6, Alignment::Left);
+    table.add_row(vec![Cell::new("first\nsecond")]);
+    let mut buffer = Buffer::no_color();
+    table.print_to_writer(&mut buffer).unwrap();
+    let result = String::from_utf8(buffer.into_inner()).unwrap();
+    assert_eq!(result.matches("first").count(), 1);
+    assert_eq!(result.matches("second").count(), 1);
+}
+
+#[test]
+fn test_expand_tabs() {
+    assert_eq!(text::expand_tabs("a\tb", 4), "a   b");
+    assert_eq!(text::expand_tabs("abcd\tb\nx\ty", 4), "abcd    b\nx   y");
+    assert_eq!(text::expand_tabs("no tabs", 4), "no tabs");
+}
+
+#[test]
+fn test_tab_width_applies_to_measurement() {
+    let mut table = Table::new(TableStyle::Grid);
+    table.add_column("Code", ColumnWidth::Auto, Alignment::Left);
+    table.add_row(vec![Cell::new("if x:\n\treturn")]);
+    table.set_tab_width(4);
+    assert_eq!(table.column_widths(), vec![12]);
+    let mut buffer = Buffer::no_color();
+    table.print_to_writer(&mut buffer).unwrap();
+    let result = String::from_utf8(buffer.into_inner()).unwrap();
+    assert!(result.contains("    return"));
+    assert!(!result.contains('\t'));
+}
+
+#[cfg(feature = "bidi")]
+#[test]
+fn test_bidi_reorder_and_mirror() {
+    let mut table = Table::new(TableStyle::Grid);
+    table.add_column("Name", 10, Alignment::Left);
+    table.add_row(vec![Cell::new("abc אבג")]);
+    table.set_bidi_mode(BidiMode::ReorderMirrored);
+    let mut buffer = Buffer::no_color();
+    table.print_to_writer(&mut buffer).unwrap();
+    let result = String::from_utf8(buffer.into_inner()).unwrap();
+    assert!(result.contains("|   abc גבא  |"));
+    assert!(result.contains("      Name  |"));
+}
+
+#[test]
+fn test_footer_separator() {
+    let mut table = create_test_table(TableStyle::Grid);
+    table.set_footer(vec![Cell::new("Total"), Cell::new("55"), Cell::new("")]);
+    let mut buffer = Buffer::no_color();
+    table.print_to_writer(&mut buffer).unwrap();
+    let result = String::from_utf8(buffer.into_inner()).unwrap();
+    let lines: Vec<&str> = result.lines().collect();
+    assert!(lines[lines.len() - 3].starts_with("+==="));
+    assert!(lines[lines.len() - 2].contains("Total"));
+
+    let mut table = create_test_table(TableStyle::Simple);
+    table.set_footer(vec![Cell::new("Total"), Cell::new("55"), Cell::new("")]);
+    let mut buffer = Buffer::no_color();
+    table.print_to_writer(&mut buffer).unwrap();
+    let result = String::from_utf8(buffer.into_inner()).unwrap();
+    let lines: Vec<&str> = result.lines().collect();
+    assert!(lines[lines.len() - 2].starts_with("----"));
+    assert!(lines[lines.len() - 1].contains("Total"));
+}
+
+#[test]
+fn test_compact_style_has_no_padding() {
+    let mut table = Table::new(TableStyle::Compact);
+    table.add_column("Name", ColumnWidth::Auto, Alignment::Left);
+    table.add_row(vec![Cell::new("Alice")]);
+    assert_eq!(table.column_widths(), vec![5]);
+    let mut buffer = Buffer::no_color();
+    table.print_to_writer(&mut buffer).unwrap();
+    let result = String::from_utf8(buffer.into_inner()).unwrap();
+    let lines: Vec<&str> = result.lines().collect();
+    assert_eq!(lines[0], "┌─────┐");
+    assert_eq!(lines[3], "│Alice│");
+}
+
+#[test]
+fn test_styled_rows_match_border_width() {
+    let table = create_test_table(TableStyle::Grid);
+    let mut buffer = Buffer::no_color();
+    table.print_to_writer(&mut buffer).unwrap();
+    let result = String::from_utf8(buffer.into_inner()).unwrap();
+    let widths: Vec<usize> = result.lines().map(|line| line.chars().count()).collect();
+    assert!(widths.iter().all(|width| *width == widths[0]));
+}
+
+#[cfg(feature = "color")]
+#[test]
+fn test_neon_style_colors_borders() {
+    let mut table = Table::new(TableStyle::Neon);
+    table.add_column("Name", 5, Alignment::Left);
+    let mut buffer = Buffer::ansi();
+    table.print_to_writer(&mut buffer).unwrap();
+    let result = String::from_utf8(buffer.into_inner()).unwrap();
+    assert!(result.contains("\x1b[38;5;13m"));
+    assert!(result.contains("\x1b[38;5;14m"));
+}
+
+#[test]
+fn test_wrap_text() {
+    assert_eq!(
+        text::wrap("the quick brown fox", 9),
+        vec!["the quick", "brown fox"]
+    );
+    assert_eq!(text::wrap("abcdefgh", 3), vec!["abc", "def", "gh"]);
+    assert_eq!(text::wrap("a\nb c", 5), vec!["a", "b c"]);
+}
+
+#[test]
+fn test_wrap_overflow_policy() {
+    let mut table = Table::new(TableStyle::Grid);
+    table.add_column("Text", 11, Alignment::Left);
+    table.set_overflow_policy(OverflowPolicy::Wrap);
+    table.add_row(vec![Cell::new("the quick brown fox")]);
+    let mut buffer = Buffer::no_color();
+    table.print_to_writer(&mut buffer).unwrap();
+    let result = String::from_utf8(buffer.into_inner()).unwrap();
+    assert!(result.contains("|  the quick  |"));
+    assert!(result.contains("|  brown fox  |"));
+}
+
+#[test]
+fn test_fitted_exports() {
+    let mut table = Table::new(TableStyle::Grid);
+    table.add_column("Text", 11, Alignment::Left);
+    table.set_overflow_policy(OverflowPolicy::Wrap);
+    table.add_row(vec![Cell::new("the quick brown fox")]);
+    assert!(table.to_markdown().contains("| the quick brown fox |"));
+    assert!(table.to_html().contains(">the quick brown fox</td>"));
+    table.set_fit_exports(true);
+    assert!(table.to_markdown().contains("| the quick<br>brown fox |"));
+    assert!(table.to_html().contains(">the quick<br>brown fox</td>"));
+}
+
+#[test]
+fn test_html_export() {
+    let mut table = create_test_table(TableStyle::Grid);
+    table.add_row(vec![
+        Cell::new("<Eve>"),
+        Cell::new("41"),
+        Cell::new("A & B"),
+    ]);
+    table.set_footer(vec![Cell::new("Total"), Cell::new("96"), Cell::new("")]);
+    let html = table.to_html();
+    assert!(html.starts_with("<table>\n  <thead>\n"));
+    assert!(html.contains("<th style=\"text-align: right\">Age</th>"));
+    assert!(html.contains("&lt;Eve&gt;"));
+    assert!(html.contains("A &amp; B"));
+    assert!(html.contains("<tfoot>\n    <tr><td style=\"text-align: left\">Total</td>"));
+}
+
+#[test]
+fn test_bbcode_export() {
+    let mut table = create_test_table(TableStyle::Grid);
+    table.rows[1][0].style.bold = true;
+    table.rows[1][0].style.color = Some(Color::Rgb(255, 128, 0));
+    table.set_footer(vec![Cell::new("Total"), Cell::new("55"), Cell::new("")]);
+    assert_eq!(
+        table.to_bbcode(),
+        "[table]\n\
+         [tr][th]Name[/th][th]Age[/th][th]City[/th][/tr]\n\
+         [tr][td]Alice[/td][td]30[/td][td]New York[/td][/tr]\n\
+         [tr][td][b][color=#ff8000]Bob[/color][/b][/td][td]25[/td][td]Los Angeles[/td][/tr]\n\
+         [tr][td]Total[/td][td]55[/td][td][/td][/tr]\n\
+         [/table]\n"
+    );
+}
+
+#[test]
+fn test_code_block_export() {
+    let mut table = Table::new(TableStyle::Grid);
+    table.add_column("Name", ColumnWidth::Auto, Alignment::Left);
+    table.add_column("Note", ColumnWidth::Auto, Alignment::Left);
+    table.add_row(vec![
+        Cell::new("Alice"),
+        Cell::new("likes long walks on the beach"),
+    ]);
+    let block = table.to_code_block("```", 80);
+    assert_eq!(block, format!("```\n{}```\n", table.render()));
+
+    let block = table.to_code_block("```text", 28);
+    let lines: Vec<&str> = block.lines().collect();
+    assert_eq!(lines[0], "```text");
+    assert_eq!(lines[lines.len() - 1], "```");
+    assert!(lines.iter().all(|line| text_width(line) <= 28));
+    assert!(block.contains("|  Alice  |  likes long    |"));
+    assert_eq!(table.columns[1].width, ColumnWidth::Auto);
+
+    let block = table.to_code_block("~~~", 10);
+    assert!(block.starts_with("~~~\n+"));
+    assert!(block.ends_with("…\n~~~\n"));
+    assert!(block.lines().all(|line| text_width(line) <= 10));
+}
+
+#[test]
+fn test_html_export_with_classes() {
+    let mut table = create_test_table(TableStyle::Grid);
+    table.rows[0][0].style.bold = true;
+    table.set_footer(vec![Cell::new("Total"), Cell::new("55"), Cell::new("")]);
+    let options = HtmlOptions {
+        inline_styles: false,
+        class_prefix: "tp-".to_string(),
+    };
+    let html = table.to_html_with_options(&options);
+    assert!(!html.contains("style="));
+    assert!(html.starts_with("<table class=\"tp-table\">\n  <thead>\n"));
+    assert!(html.contains("<th class=\"tp-col-1 tp-right\">Age</th>"));
+    assert!(html.contains("<td class=\"tp-col-0 tp-left tp-bold\">Alice</td>"));
+    assert!(html.contains("<td class=\"tp-col-1 tp-right tp-num\">25</td>"));
+    assert!(html.contains("<tfoot>\n    <tr><td class=\"tp-col-0 tp-left\">Total</td>"));
+    assert_eq!(
+        table.to_html(),
+        table.to_html_with_options(&HtmlOptions::default())
+    );
+
+    let stylesheet = HtmlOptions {
+        class_prefix: "report-".to_string(),
+        ..options
+    }
+    .stylesheet();
+    assert!(stylesheet.contains(".report-num { font-variant-numeric: tabular-nums; }\n"));
+    assert!(stylesheet.contains(".report-bold { font-weight: bold; }\n"));
+}
+
+#[test]
+fn test_set_cell_reflows_widths() {
+    let mut table = Table::new(TableStyle::Grid);
+    table.add_column("Name", ColumnWidth::Auto, Alignment::Left);
+    table.add_row(vec![Cell::new("Bob")]);
+    assert_eq!(table.column_widths(), vec![6]);
+    table.set_cell(0, 0, "Bartholomew").unwrap();
+    assert_eq!(table.rows[0][0].content, "Bartholomew");
+    assert_eq!(table.column_widths(), vec![13]);
+    assert_eq!(
+        table.set_cell(1, 0, "Eve"),
+        Err(TableError::RowOutOfBounds { index: 1, len: 1 })
+    );
+    assert_eq!(
+        table.set_cell(0, 3, String::from("Eve")),
+        Err(TableError::ColumnOutOfBounds { index: 3, len: 1 })
+    );
+}
+
+#[test]
+fn test_map_column() {
+    let mut table = create_test_table(TableStyle::Simple);
+    table
+        .map_column(1, |cell| {
+            let years: u32 = cell.content.parse().unwrap();
+            cell.content = (years * 12).to_string();
+        })
+        .unwrap();
+    assert_eq!(table.rows[0][1].content, "360");
+    assert_eq!(table.rows[1][1].content, "300");
+    assert!(table.map_column(5, |_| {}).is_err());
+}
+
+#[test]
+fn test_mask_styles() {
+    assert_eq!(MaskStyle::Full.apply("secret"), "********");
+    assert_eq!(
+        MaskStyle::Partial { keep_last: 4 }.apply("4111111111111111"),
+        "************1111"
+    );
+    assert_eq!(MaskStyle::Partial { keep_last: 4 }.apply("abc"), "abc");
+    let hash = MaskStyle::Hash.apply("alice@example.com");
+    assert_eq!(hash, MaskStyle::Hash.apply("alice@example.com"));
+    assert_ne!(hash, MaskStyle::Hash.apply("bob@example.com"));
+}
+
+#[test]
+fn test_mask_column_applies_to_all_outputs() {
+    let mut table = Table::new(TableStyle::Grid);
+    table.add_column("User", ColumnWidth::Auto, Alignment::Left);
+    table.add_column("Token", ColumnWidth::Auto, Alignment::Left);
+    table.add_row(vec![Cell::new("alice"), Cell::new("sk-12345678")]);
+    table
+        .mask_column(1, MaskStyle::Partial { keep_last: 2 })
+        .unwrap();
+    let mut buffer = Buffer::no_color();
+    table.print_to_writer(&mut buffer).unwrap();
+    let result = String::from_utf8(buffer.into_inner()).unwrap();
+    assert!(result.contains("*********78"));
+    assert!(result.contains("Token"));
+    assert!(!result.contains("sk-"));
+    assert!(!table.to_markdown().contains("sk-"));
+    assert!(!table.to_html().contains("sk-"));
+    let mut buffer = Buffer::no_color();
+    table
+        .record_view(0)
+        .unwrap()
+        .print_to_writer(&mut buffer)
+        .unwrap();
+    assert!(!String::from_utf8(buffer.into_inner())
+        .unwrap()
+        .contains("sk-"));
+    assert_eq!(table.rows[0][1].content, "sk-12345678");
+
+    table.add_row(vec![Cell::new("bob"), Cell::new("sk-12345678")]);
+    table.mask_column("Token", MaskStyle::Full).unwrap();
+    let duplicates = table.count_duplicates("Token");
+    assert_eq!(duplicates.rows.len(), 1);
+    assert!(!duplicates.render().contains("sk-"));
+    assert!(duplicates.render().contains("********"));
+}
+
+#[test]
+fn test_shape_and_statistics() {
+    let table = create_test_table(TableStyle::Grid);
+    assert_eq!(table.shape(), (2, 3));
+    assert!(!table.is_empty());
+    assert!(Table::new(TableStyle::Grid).is_empty());
+    assert_eq!(table.column_headers(), vec!["Name", "Age", "City"]);
+    assert!(table.memory_footprint() > std::mem::size_of::<Table>());
+    let mut buffer = Buffer::no_color();
+    table.print_to_writer(&mut buffer).unwrap();
+    let result = String::from_utf8(buffer.into_inner()).unwrap();
+    let first_line = result.lines().next().unwrap();
+    assert_eq!(table.content_width(), first_line.chars().count());
+}
+
+#[test]
+fn test_empty_policy() {
+    let mut table = Table::new(TableStyle::Grid);
+    table.add_column("Name", 8, Alignment::Left);
+    table.add_column("Age", 5, Alignment::Right);
+
+    let mut buffer = Buffer::no_color();
+    table.print_to_writer(&mut buffer).unwrap();
+    assert_eq!(
+        buffer.into_inner().iter().filter(|&&b| b == b'\n').count(),
+        4
+    );
+
+    table.set_empty_policy(EmptyPolicy::Placeholder("(no data)".to_string()));
+    let mut buffer = Buffer::no_color();
+    table.print_to_writer(&mut buffer).unwrap();
+    let result = String::from_utf8(buffer.into_inner()).unwrap();
+    let lines: Vec<&str> = result.lines().collect();
+    assert_eq!(lines[3], "|    (no data)     |");
+    assert_eq!(lines[3].len(), lines[0].len());
+
+    table.set_empty_policy(EmptyPolicy::Skip);
+    let mut buffer = Buffer::no_color();
+    table.print_to_writer(&mut buffer).unwrap();
+    assert!(buffer.is_empty());
+
+    let table = Table::new(TableStyle::Simple);
+    let mut buffer = Buffer::no_color();
+    table.print_to_writer(&mut buffer).unwrap();
+    assert!(buffer.is_empty());
+}
+
+#[test]
+fn test_print_with_summary() {
+    let mut table = Table::new(TableStyle::Grid);
+    table.add_column("Item", 8, Alignment::Left);
+    table.add_column("Price", 8, Alignment::Right);
+    table.add_row(vec![Cell::new("Apple"), Cell::new("1.5")]);
+    table.add_row(vec![Cell::new("Pear"), Cell::new("2")]);
+    let mut buffer = Buffer::no_color();
+    table
+        .print_with_summary_to_writer(
+            &mut buffer,
+            &[Summary::Sum(1), Summary::Avg(1), Summary::Count],
+        )
+        .unwrap();
+    let result = String::from_utf8(buffer.into_inner()).unwrap();
+    let lines: Vec<&str> = result.lines().collect();
+    let row = lines[3];
+    let sum = lines[6];
+    assert_eq!(sum.trim_start_matches("Sum:").trim(), "3.50");
+    assert_eq!(sum.len(), row.find("1.5").unwrap() + 3);
+    assert!(lines[7].starts_with("Avg:"));
+    assert_eq!(lines[8], "Count: 2");
+}
+
+#[test]
+fn test_sort_options() {
+    let mut table = Table::new(TableStyle::Simple);
+    table.add_column("Name", 10, Alignment::Left);
+    for name in ["bob", "", "Alice", "Émile", "eve"] {
+        table.add_row(vec![Cell::new(name)]);
+    }
+    let names = |table: &Table| -> Vec<String> {
+        table
+            .rows
+            .iter()
+            .map(|row| row[0].content.clone())
+            .collect()
+    };
+    let options = SortOptions {
+        case_insensitive: true,
+        nulls_last: true,
+        ..SortOptions::default()
+    };
+    table.sort_by_column_with(0, true, &options);
+    assert_eq!(names(&table), vec!["Alice", "bob", "eve", "Émile", ""]);
+    table.sort_by_column_with(0, false, &options);
+    assert_eq!(names(&table), vec!["Émile", "eve", "bob", "Alice", ""]);
+
+    let options = SortOptions {
+        collation: Some(Locale::new("fr-FR")),
+        ..SortOptions::default()
+    };
+    table.sort_by_column_with(0, true, &options);
+    assert_eq!(names(&table), vec!["", "Alice", "bob", "Émile", "eve"]);
+}
+
+#[test]
+fn test_locale_tailoring() {
+    let swedish = Locale::new("sv-SE");
+    assert_eq!(swedish.compare("öl", "zebra"), std::cmp::Ordering::Greater);
+    assert_eq!(swedish.compare("åsa", "ära"), std::cmp::Ordering::Less);
+    let german = Locale::new("de");
+    assert_eq!(german.compare("öl", "zebra"), std::cmp::Ordering::Less);
+}
+
+#[test]
+fn test_sort_by_key_fn() {
+    let mut table = Table::new(TableStyle::Simple);
+    table.add_column("Severity", 10, Alignment::Left);
+    for severity in ["info", "error", "debug", "warn"] {
+        table.add_row(vec![Cell::new(severity)]);
+    }
+    let rank = |cell: &Cell| match cell.content.as_str() {
+        "error" => 0,
+        "warn" => 1,
+        "info" => 2,
+        _ => 3,
+    };
+    table.sort_by_key_fn(0, rank);
+    let order: Vec<&str> = table
+        .rows
+        .iter()
+        .map(|row| row[0].content.as_str())
+        .collect();
+    assert_eq!(order, vec!["error", "warn", "info", "debug"]);
+}
+
+#[test]
+fn test_natural_sort() {
+    let mut table = Table::new(TableStyle::Simple);
+    table.add_column("File", 10, Alignment::Left);
+    for name in ["file10", "v1.10.0", "file2", "v1.9.0", "file02", "file1"] {
+        table.add_row(vec![Cell::new(name)]);
+    }
+    let options = SortOptions {
+        kind: SortKind::Natural,
+        ..SortOptions::default()
+    };
+    table.sort_by_column_with(0, true, &options);
+    let order: Vec<&str> = table
+        .rows
+        .iter()
+        .map(|row| row[0].content.as_str())
+        .collect();
+    assert_eq!(
+        order,
+        vec!["file1", "file2", "file02", "file10", "v1.9.0", "v1.10.0"]
+    );
+}
+
+#[test]
+fn test_dedup_and_distinct() {
+    let mut table = Table::new(TableStyle::Simple);
+    table.add_column("Level", 8, Alignment::Left);
+    table.add_column("Message", 12, Alignment::Left);
+    for (level, message) in [
+        ("warn", "disk full"),
+        ("error", "timeout"),
+        ("warn", "disk full"),
+        ("warn", "retrying"),
+    ] {
+        table.add_row(vec![Cell::new(level), Cell::new(message)]);
+    }
+
+    let distinct = table.distinct_by_column(0);
+    assert_eq!(distinct.rows.len(), 2);
+    assert_eq!(distinct.rows[1][1].content, "timeout");
+
+    let counts = table.count_duplicates(0);
+    assert_eq!(counts.column_headers(), vec!["Level", "Count"]);
+    assert_eq!(counts.rows[0][0].content, "warn");
+    assert_eq!(counts.rows[0][1].content, "3");
+    assert_eq!(counts.rows[1][1].content, "1");
+
+    table.dedup_rows();
+    assert_eq!(table.rows.len(), 3);
+    assert_eq!(table.rows[2][1].content, "retrying");
+}
+
+#[test]
+fn test_top_and_bottom_n() {
+    let mut table = Table::new(TableStyle::Simple);
+    table.add_column("File", 10, Alignment::Left);
+    table.add_column("Size", 8, Alignment::Right);
+    for (file, size) in [
+        ("a", "120"),
+        ("b", "9"),
+        ("c", "n/a"),
+        ("d", "1000"),
+        ("e", "45"),
+    ] {
+        table.add_row(vec![Cell::new(file), Cell::new(size)]);
+    }
+    let files = |table: &Table| -> Vec<String> {
+        table
+            .rows
+            .iter()
+            .map(|row| row[0].content.clone())
+            .collect()
+    };
+    assert_eq!(files(&table.top_n(1, 3)), vec!["d", "a", "e"]);
+    assert_eq!(files(&table.bottom_n(1, 2)), vec!["b", "e"]);
+    assert_eq!(table.top_n(1, 10).rows.len(), 4);
+}
+
+#[test]
+fn test_computed_columns() {
+    let mut table = Table::new(TableStyle::Grid);
+    table.add_column("Item", 8, Alignment::Left);
+    table.add_column("Unit Price", 8, Alignment::Right);
+    table.add_column("Qty", 5, Alignment::Right);
+    table.add_row(vec![Cell::new("Apple"), Cell::new("1.25"), Cell::new("4")]);
+    table.add_row(vec![Cell::new("Pear"), Cell::new("0.8"), Cell::new("n/a")]);
+    table
+        .add_computed_column_expr("Total", "[Unit Price] * Qty")
+        .unwrap();
+    assert_eq!(table.rows[0][3].content, "5");
+    assert_eq!(table.rows[1][3].content, "");
+    table.add_computed_column("Code", |row| row[0].content.to_uppercase());
+    assert_eq!(table.rows[1][4].content, "PEAR");
+    table
+        .add_computed_column_expr("Net", "-(Qty - 1) / 2 + 10")
+        .unwrap();
+    assert_eq!(table.rows[0][5].content, "8.50");
+    assert_eq!(
+        table.add_computed_column_expr("Bad", "Price * Qty"),
+        Err(TableError::UnknownColumn("Price".to_string()))
+    );
+    assert!(matches!(
+        table.add_computed_column_expr("Bad", "Qty *"),
+        Err(TableError::InvalidExpression { .. })
+    ));
+    assert_eq!(table.columns.len(), 6);
+}
+
+#[test]
+fn test_slice() {
+    let mut table = Table::new(TableStyle::Simple);
+    for header in ["A", "B", "C", "D"] {
+        table.add_column(header, 4, Alignment::Left);
+    }
+    for i in 0..10 {
+        let row = (0..4).map(|c| Cell::new(&format!("{}{}", i, c))).collect();
+        table.add_row(row);
+    }
+    let window = table.slice(3..5, 1..3).to_table();
+    assert_eq!(window.shape(), (2, 2));
+    assert_eq!(window.column_headers(), vec!["B", "C"]);
+    assert_eq!(window.rows[0][0].content, "31");
+    assert_eq!(table.slice(8.., ..=0).to_table().shape(), (2, 1));
+    assert_eq!(table.slice(20..30, 2..9).to_table().shape(), (0, 2));
+}
+
+#[test]
+fn test_header_alignment() {
+    let mut table = Table::new(TableStyle::Grid);
+    table.add_column("Amount", 12, Alignment::Right);
+    table.add_row(vec![Cell::new("42")]);
+    table.set_header_alignment(0, Alignment::Center).unwrap();
+    let mut buffer = Buffer::no_color();
+    table.print_to_writer(&mut buffer).unwrap();
+    let result = String::from_utf8(buffer.into_inner()).unwrap();
+    assert!(result.contains("|    Amount    |"));
+    assert!(result.contains("|          42  |"));
+    assert!(table
+        .to_html()
+        .contains("<th style=\"text-align: center\">Amount</th>"));
+}
+
+/// Computes the geometric mean of the values.
+#[derive(Default)]
+struct GeometricMean {
+    log_sum: f64,
+    count: usize,
+}
+
+impl Aggregator for GeometricMean {
+    fn feed(&mut self, value: f64) {
+        self.log_sum += value.ln();
+        self.count += 1;
+    }
+
+    fn finish(&self) -> f64 {
+        (self.log_sum / self.count as f64).exp()
+    }
+
+    fn name(&self) -> &str {
+        "geomean"
+    }
+}
+
+#[test]
+fn test_custom_aggregators() {
+    let mut table = Table::new(TableStyle::Simple);
+    table.add_column("Group", 8, Alignment::Left);
+    table.add_column("Value", 8, Alignment::Right);
+    for (group, value) in [("a", "2"), ("a", "8"), ("b", "3")] {
+        table.add_row(vec![Cell::new(group), Cell::new(value)]);
+    }
+    let mut geomean = GeometricMean::default();
+    let mean = table.aggregate_with(1, &mut geomean).unwrap();
+    assert!((mean - f64::cbrt(48.0)).abs() < 1e-9);
+    table.register_aggregator("geomean", || Box::new(GeometricMean::default()));
+    assert_eq!(table.aggregate_named(1, "max"), Ok(Some(8.0)));
+    assert_eq!(
+        table.aggregate_named(1, "p99"),
+        Err(TableError::UnknownAggregator("p99".to_string()))
+    );
+
+    let mut buffer = Buffer::no_color();
+    table
+        .print_with_summary_to_writer(&mut buffer, &[Summary::Custom(1, "geomean")])
+        .unwrap();
+    let result = String::from_utf8(buffer.into_inner()).unwrap();
+    assert!(result.lines().last().unwrap().starts_with("geomean:"));
+    assert!(result.ends_with("3.63\n"));
+
+    table.group_by_column_with_aggregator(0, "geomean").unwrap();
+    assert_eq!(table.rows[2][0].content, "Subtotal (geomean)");
+    let subtotal: f64 = table.rows[2][1].content.parse().unwrap();
+    assert!((subtotal - 4.0).abs() < 1e-9);
+    assert!(table.group_by_column_with_aggregator(0, "p99").is_err());
+}
+
+#[test]
+fn test_weighted_aggregation() {
+    let mut table = Table::new(TableStyle::Simple);
+    table.add_column("Price", 8, Alignment::Right);
+    table.add_column("Volume", 8, Alignment::Right);
+    for (price, volume) in [("10", "1"), ("20", "3"), ("n/a", "5")] {
+        table.add_row(vec![Cell::new(price), Cell::new(volume)]);
+    }
+    assert_eq!(table.weighted_average(0, 1), Some(17.5));
+    assert_eq!(table.dot_product(0, 1), Some(70.0));
+    table.set_cell(0, 1, "0").unwrap();
+    table.set_cell(1, 1, "0").unwrap();
+    assert_eq!(table.weighted_average(0, 1), None);
+}
+
+#[test]
+fn test_value_counts() {
+    let mut table = Table::new(TableStyle::Simple);
+    table.add_column("Status", 8, Alignment::Left);
+    table.add_column("Latency", 8, Alignment::Right);
+    for (status, latency) in [("ok", "10"), ("ok", "20"), ("fail", "35"), ("ok", "50")] {
+        table.add_row(vec![Cell::new(status), Cell::new(latency)]);
+    }
+    let counts = table.value_counts(0);
+    assert_eq!(counts.column_headers(), vec!["Status", "Count", "Percent"]);
+    assert_eq!(counts.rows[0][0].content, "ok");
+    assert_eq!(counts.rows[0][2].content, "75.0%");
+    assert_eq!(counts.rows[1][2].content, "25.0%");
+
+    let histogram = table.value_counts_binned(1, 2);
+    assert_eq!(histogram.rows.len(), 2);
+    assert_eq!(histogram.rows[0][0].content, "10 – 30");
+    assert_eq!(histogram.rows[0][1].content, "2");
+    assert_eq!(histogram.rows[1][0].content, "30 – 50");
+    assert_eq!(histogram.rows[1][1].content, "2");
+
+    table.mask_column("Status", MaskStyle::Full).unwrap();
+    let output = table.value_counts("Status").render();
+    assert!(!output.contains("ok") && !output.contains("fail"));
+    assert!(output.contains("********"));
+    assert!(output.contains("75.0%"));
+}
+
+#[cfg(all(feature = "csv", feature = "json"))]
+#[test]
+fn test_csv_meta_round_trip() {
+    let path = std::env::temp_dir().join(format!("tabprinter_meta_{}.csv", std::process::id()));
+    let path = path.to_str().unwrap();
+    let mut table = create_test_table(TableStyle::Round);
+    table.add_column("Score", ColumnWidth::Auto, Alignment::Right);
+    table.rows[0].push(Cell::new("1234.5"));
+    table.rows[1].push(Cell::new("99"));
+    table.set_header_alignment(3, Alignment::Center).unwrap();
+    table.rows[0][3].style.decimal_places = Some(2);
+    table.rows[0][3].style.thousand_separator = true;
+    table.rows[1][0].style.bold = true;
+    table.to_csv_with_meta(path).unwrap();
+
+    let restored = Table::from_csv_with_meta(path).unwrap();
+    let render = |table: &Table| {
+        let mut buffer = Buffer::no_color();
+        table.print_to_writer(&mut buffer).unwrap();
+        String::from_utf8(buffer.into_inner()).unwrap()
+    };
+    assert_eq!(render(&restored), render(&table));
+    assert!(restored.rows[1][0].style.bold);
+    std::fs::remove_file(path).unwrap();
+    std::fs::remove_file(format!("{}.meta.json", path)).unwrap();
+}
+
+#[cfg(feature = "csv")]
+#[test]
+fn test_csv_write_options() {
+    let path = std::env::temp_dir().join(format!("tabprinter_opts_{}.csv", std::process::id()));
+    let path = path.to_str().unwrap();
+    let table = create_test_table(TableStyle::Simple);
+    table.to_csv(path).unwrap();
+    let written = std::fs::read_to_string(path).unwrap();
+    assert_eq!(
+        written,
+        "Name,Age,City\nAlice,30,New York\nBob,25,Los Angeles\n"
+    );
+
+    let options = CsvWriteOptions {
+        write_headers: false,
+        delimiter: b';',
+        quote_style: CsvQuoteStyle::NonNumeric,
+        line_terminator: CsvLineTerminator::Crlf,
+    };
+    table.to_csv_with_options(path, &options).unwrap();
+    let written = std::fs::read_to_string(path).unwrap();
+    assert_eq!(
+        written,
+        "\"Alice\";30;\"New York\"\r\n\"Bob\";25;\"Los Angeles\"\r\n"
+    );
+    std::fs::remove_file(path).unwrap();
+}
+
+#[cfg(feature = "csv")]
+#[test]
+fn test_csv_export_resolves_lazy_cells() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    static CALLS: AtomicUsize = AtomicUsize::new(0);
+    let path = std::env::temp_dir().join(format!("tabprinter_lazy_{}.csv", std::process::id()));
+    let path = path.to_str().unwrap();
+    let mut table = Table::new(TableStyle::Simple);
+    table.add_column("Id", ColumnWidth::Auto, Alignment::Left);
+    table.add_column("Digest", ColumnWidth::Auto, Alignment::Left);
+    for id in 0..2 {
+        table.add_row(vec![
+            Cell::new(&id.to_string()),
+            Cell::lazy(move || {
+                CALLS.fetch_add(1, Ordering::SeqCst);
+                format!("hash-{}", id)
+            }),
+        ]);
+    }
+    table.to_csv(path).unwrap();
+    assert_eq!(CALLS.load(Ordering::SeqCst), 2);
+    let restored = Table::from_csv(path).unwrap();
+    std::fs::remove_file(path).unwrap();
+    assert_eq!(restored.rows[0][1].content, "hash-0");
+    assert_eq!(restored.rows[1][1].content, "hash-1");
+}
+
+#[test]
+fn test_apply_schema() {
+    let mut table = Table::new(TableStyle::Simple);
+    table.add_column("Amount", 10, Alignment::Left);
+    table.add_column("Active", 8, Alignment::Left);
+    table.add_column("Code", 8, Alignment::Left);
+    table.add_row(vec![
+        Cell::new("1.234,5"),
+        Cell::new("yes"),
+        Cell::new("ab"),
+    ]);
+    table.add_row(vec![Cell::new("12"), Cell::new("No"), Cell::new("cd")]);
+    let schema = Schema::new()
+        .column(
+            "Amount",
+            ColumnType::Number {
+                decimal_separator: ',',
+                thousands_separator: Some('.'),
+            },
+        )
+        .column("Active", ColumnType::Boolean)
+        .parser("Code", |text| Ok(text.to_uppercase()));
+    table.apply_schema(&schema).unwrap();
+    assert_eq!(table.rows[0][0].content, "1234.5");
+    assert_eq!(table.rows[1][1].content, "false");
+    assert_eq!(table.rows[1][2].content, "CD");
+    assert_eq!(table.columns[0].alignment, Alignment::Right);
+    assert_eq!(table.sum_column(0), Some(1246.5));
+
+    let strict = Schema::new().column("Active", ColumnType::Integer);
+    let error = table.apply_schema(&strict).unwrap_err();
+    assert!(matches!(error, TableError::Parse { row: 0, .. }));
+    assert_eq!(table.rows[0][1].content, "true");
+}
+
+#[cfg(feature = "csv")]
+#[test]
+fn test_from_csv_with_progress() {
+    let path = std::env::temp_dir().join(format!("tabprinter_progress_{}.csv", std::process::id()));
+    let path = path.to_str().unwrap();
+    create_test_table(TableStyle::Simple).to_csv(path).unwrap();
+    let mut updates = Vec::new();
+    let table =
+        Table::from_csv_with_progress(path, |bytes, rows| updates.push((bytes, rows))).unwrap();
+    assert_eq!(table.rows.len(), 2);
+    let rows: Vec<usize> = updates.iter().map(|&(_, rows)| rows).collect();
+    assert_eq!(rows, vec![1, 2]);
+    assert_eq!(updates[0].0, 32);
+    assert!(updates[1].0 > updates[0].0);
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn test_from_markdown() {
+    let original = create_test_table(TableStyle::Grid);
+    let table = Table::from_markdown(&original.to_markdown()).unwrap();
+    assert_eq!(table.column_headers(), vec!["Name", "Age", "City"]);
+    assert_eq!(table.columns[1].alignment, Alignment::Right);
+    assert_eq!(table.columns[2].alignment, Alignment::Center);
+    assert_eq!(table.rows[1][2].content, "Los Angeles");
+
+    let table = Table::from_markdown("A | B\n--|:-:\na \\| b | c<br>d | extra\n\nshort\n").unwrap();
+    assert_eq!(table.columns[1].alignment, Alignment::Center);
+    assert_eq!(table.rows[0][0].content, "a | b");
+    assert_eq!(table.rows[0][1].content, "c\nd");
+    assert_eq!(table.rows[1][1].content, "");
+
+    assert_eq!(
+        Table::from_markdown("| A |\n| x |\n").err(),
+        Some(TableError::InvalidMarkdown {
+            line: 2,
+            reason: "delimiter cells must look like `---`, `:---`, `---:`, or `:---:`".to_string(),
+        })
+    );
+}
+
+#[test]
+fn test_merge_cells() {
+    let mut table = create_test_table(TableStyle::Grid);
+    table.add_row(vec![
+        Cell::new("Carol"),
+        Cell::new("41"),
+        Cell::new("Boston"),
+    ]);
+    table
+        .merge_cells(&[CellRange::new(0..1, 0..2), CellRange::new(1..3, 2..3)])
+        .unwrap();
+    assert_eq!(table.rows[0][1].content, "");
+    assert_eq!(table.rows[2][2].content, "");
+    let mut buffer = Buffer::no_color();
+    table.print_to_writer(&mut buffer).unwrap();
+    let output = String::from_utf8(buffer.into_inner()).unwrap();
+    assert_eq!(
+        output,
+        "+----------+-------+---------------+\n\
+         |  Name    |  Age  |     City      |\n\
+         +----------+-------+---------------+\n\
+         |      Alice       |   New York    |\n\
+         |  Bob     |   25  |  Los Angeles  |\n\
+         |  Carol   |   41  |               |\n\
+         +----------+-------+---------------+\n"
+    );
+    assert!(matches!(
+        table.merge_cells(&[CellRange::new(0..2, 1..2)]),
+        Err(TableError::InvalidMerge(_))
+    ));
+}
+
+#[test]
+fn test_merged_junctions() {
+    let mut table = create_test_table(TableStyle::Round);
+    table.add_separator();
+    table.add_row(vec![
+        Cell::new("Carol"),
+        Cell::new("41"),
+        Cell::new("Boston"),
+    ]);
+    table
+        .merge_cells(&[CellRange::new(0..1, 0..2), CellRange::new(1..3, 1..3)])
+        .unwrap();
+    let output = table.render();
+    let lines: Vec<&str> = output.lines().collect();
+    assert_eq!(lines[2], "├──────────┴───────┼───────────────┤");
+    assert_eq!(lines[5], "├──────────┼───────────────────────┤");
+    assert_eq!(lines[7], "╰──────────┴───────────────────────╯");
+    assert!(lines[0].starts_with("╭──────────┬"));
+}
+
+#[test]
+fn test_layout() {
+    let mut table = create_test_table(TableStyle::Grid);
+    table.add_separator();
+    table.add_row(vec![
+        Cell::new("Carol"),
+        Cell::new("41"),
+        Cell::new("Boston\nMA"),
+    ]);
+    let layout = table.layout();
+    let mut buffer = Buffer::no_color();
+    table.print_to_writer(&mut buffer).unwrap();
+    let output = String::from_utf8(buffer.into_inner()).unwrap();
+    let lines: Vec<&str> = output.lines().collect();
+    assert_eq!(layout.height, lines.len());
+    assert!(lines.iter().all(|line| text_width(line) == layout.width));
+    assert_eq!(layout.rows, vec![3..4, 4..5, 6..8]);
+    assert_eq!(layout.horizontal_borders, vec![0, 2, 5, 8]);
+    assert_eq!(layout.columns[0], 1..11);
+    assert_eq!(layout.vertical_borders[1], 11..12);
+    assert_eq!(layout.column_at(11), None);
+    assert_eq!(layout.column_at(12), Some(1));
+    assert_eq!(layout.row_at(7), Some(2));
+    assert_eq!(&lines[7][layout.columns[2].clone()], "      MA       ");
+
+    table.set_style(TableStyle::Simple);
+    let layout = table.layout();
+    assert_eq!(layout.rows, vec![1..2, 2..3, 4..6]);
+    assert_eq!(layout.horizontal_borders, vec![3]);
+    assert!(layout.vertical_borders.is_empty());
+}
+
+#[cfg(feature = "color")]
+#[test]
+fn test_row_gradient() {
+    let mut table = create_test_table(TableStyle::Grid);
+    table.add_row(vec![
+        Cell::new("Carol"),
+        Cell::new("41"),
+        Cell::new("Boston"),
+    ]);
+    table.set_row_gradient(Rgb::new(0, 0, 0), Rgb::new(200, 100, 0));
+    assert_eq!(table.row_background(1), Some(Color::Rgb(100, 50, 0)));
+    let mut buffer = Buffer::ansi();
+    table.print_to_writer(&mut buffer).unwrap();
+    let output = String::from_utf8(buffer.into_inner()).unwrap();
+    assert!(output.contains("\x1b[48;2;0;0;0m"));
+    assert!(output.contains("\x1b[48;2;100;50;0m"));
+    assert!(output.contains("\x1b[48;2;200;100;0m"));
+
+    table.clear_row_gradient();
+    let mut buffer = Buffer::ansi();
+    table.print_to_writer(&mut buffer).unwrap();
+    assert!(!String::from_utf8(buffer.into_inner())
+        .unwrap()
+        .contains("48;2"));
+}
+
+#[cfg(feature = "color")]
+#[test]
+fn test_rich_cell_spans() {
+    let mut red_bold = ColorSpec::new();
+    red_bold.set_fg(Some(Color::Red)).set_bold(true);
+    let cell = Cell::rich(vec![
+        Span::styled("ERROR", red_bold),
+        Span::raw(": disk full"),
+    ]);
+    assert_eq!(cell.content, "ERROR: disk full");
+
+    let mut table = Table::new(TableStyle::Grid);
+    table.add_column("Message", ColumnWidth::Auto, Alignment::Left);
+    table.add_row(vec![cell]);
+    let mut buffer = Buffer::ansi();
+    table.print_to_writer(&mut buffer).unwrap();
+    let output = String::from_utf8(buffer.into_inner()).unwrap();
+    assert!(output.contains("\x1b[1m\x1b[31mERROR\x1b[0m: disk full"));
+    assert_eq!(table.column_widths(), vec![18]);
+
+    table.set_column_overflow(0, OverflowPolicy::Wrap).unwrap();
+    table.columns[0].width = ColumnWidth::Fixed(9);
+    let mut buffer = Buffer::ansi();
+    table.print_to_writer(&mut buffer).unwrap();
+    let output = String::from_utf8(buffer.into_inner()).unwrap();
+    assert!(output.contains("\x1b[1m\x1b[31mERROR\x1b[0m:"));
+    assert!(output.contains("\x1b[0mdisk\x1b[0m"));
+
+    table.rows[0][0].content = "replaced".to_string();
+    let mut buffer = Buffer::ansi();
+    table.print_to_writer(&mut buffer).unwrap();
+    assert!(!String::from_utf8(buffer.into_inner())
+        .unwrap()
+        .contains("\x1b[31m"));
+}
+
+#[test]
+fn test_header_repeat() {
+    let mut table = create_test_table(TableStyle::Grid);
+    table.add_row(vec![
+        Cell::new("Carol"),
+        Cell::new("41"),
+        Cell::new("Boston"),
+    ]);
+    table.set_header_repeat(HeaderRepeat::Every(2));
+    let mut buffer = Buffer::no_color();
+    table.print_to_writer(&mut buffer).unwrap();
+    let output = String::from_utf8(buffer.into_inner()).unwrap();
+    assert_eq!(
+        output,
+        "+----------+-------+---------------+\n\
+         |  Name    |  Age  |     City      |\n\
+         +----------+-------+---------------+\n\
+         |  Alice   |   30  |   New York    |\n\
+         |  Bob     |   25  |  Los Angeles  |\n\
+         +----------+-------+---------------+\n\
+         |  Name    |  Age  |     City      |\n\
+         +----------+-------+---------------+\n\
+         |  Carol   |   41  |    Boston     |\n\
+         +----------+-------+---------------+\n"
+    );
+    let layout = table.layout();
+    assert_eq!(layout.repeated_headers, vec![6..7]);
+    assert_eq!(layout.height, output.lines().count());
+
+    table.set_style(TableStyle::Simple);
+    let mut buffer = Buffer::no_color();
+    table.print_to_writer(&mut buffer).unwrap();
+    let output = String::from_utf8(buffer.into_inner()).unwrap();
+    assert_eq!(output.matches("Name").count(), 2);
+    assert_eq!(table.layout().height, output.lines().count());
+}
+
+#[test]
+fn test_print_skeleton() {
+    let table = create_test_table(TableStyle::Grid);
+    let mut buffer = Buffer::no_color();
+    table.print_skeleton_to_writer(&mut buffer, 2).unwrap();
+    let output = String::from_utf8(buffer.into_inner()).unwrap();
+    assert_eq!(
+        output,
+        "+----------+-------+---------------+\n\
+         |  Name    |  Age  |     City      |\n\
+         +----------+-------+---------------+\n\
+         |  ▒▒▒▒▒▒  |  ▒▒▒  |  ▒▒▒▒▒▒▒▒▒▒▒  |\n\
+         |  ▒▒▒▒▒▒  |  ▒▒▒  |  ▒▒▒▒▒▒▒▒▒▒▒  |\n\
+         +----------+-------+---------------+\n"
+    );
+}
+
+#[test]
+fn test_linear_text() {
+    let mut table = create_test_table(TableStyle::Grid);
+    assert_eq!(
+        table.to_linear_text(),
+        "Name: Alice; Age: 30; City: New York\nName: Bob; Age: 25; City: Los Angeles\n"
+    );
+    table.set_linear_separator(", ");
+    table.set_style(TableStyle::Accessible);
+    let mut buffer = Buffer::no_color();
+    table.print_to_writer(&mut buffer).unwrap();
+    let output = String::from_utf8(buffer.into_inner()).unwrap();
+    assert_eq!(
+        output,
+        "Name: Alice, Age: 30, City: New York\nName: Bob, Age: 25, City: Los Angeles\n"
+    );
+    assert_eq!(table.layout().height, 2);
+
+    let mut stream = StreamPrinter::new(table, Buffer::no_color());
+    stream
+        .push_row(vec![
+            Cell::new("Carol"),
+            Cell::new("41"),
+            Cell::new("Boston"),
+        ])
+        .unwrap();
+    let output = String::from_utf8(stream.finish().unwrap().into_inner()).unwrap();
+    assert!(output.ends_with("Name: Carol, Age: 41, City: Boston\n"));
+}
+
+#[test]
+fn test_strict_render_safety() {
+    let mut table = Table::new(TableStyle::FancyGrid);
+    table.add_column("Städte", ColumnWidth::Auto, Alignment::Left);
+    table.add_row(vec![Cell::new("Zürich\x1b[31m")]);
+    table.add_row(vec![Cell::new("tab\there")]);
+    table.set_render_safety(RenderSafety::Strict);
+    let mut buffer = Buffer::no_color();
+    table.print_to_writer(&mut buffer).unwrap();
+    let output = String::from_utf8(buffer.into_inner()).unwrap();
+    let border_chars: String = STYLES[2].top.begin.to_string()
+        + STYLES[2].top.hline
+        + STYLES[2].top.sep
+        + STYLES[2].top.end
+        + STYLES[2].row.begin
+        + STYLES[2].row.sep
+        + STYLES[2].row.end
+        + STYLES[2].below_header.begin
+        + STYLES[2].below_header.hline
+        + STYLES[2].below_header.end
+        + STYLES[2].bottom.begin
+        + STYLES[2].bottom.hline
+        + STYLES[2].bottom.end;
+    assert!(output
+        .chars()
+        .all(|c| c == '\n' || (' '..='~').contains(&c) || border_chars.contains(c)));
+    assert!(output.contains("St\\u{e4}dte"));
+    assert!(output.contains("Z\\u{fc}rich\\u{1b}[31m"));
+    assert!(output.contains("tab\\u{9}here"));
+
+    let mut buffer = Buffer::no_color();
+    table.print_skeleton_to_writer(&mut buffer, 1).unwrap();
+    assert!(!String::from_utf8(buffer.into_inner())
+        .unwrap()
+        .contains('▒'));
+}
+
+#[test]
+fn test_stream_width_policies() {
+    let table = || {
+        let mut table = Table::new(TableStyle::Grid);
+        table.add_column("Name", ColumnWidth::Auto, Alignment::Left);
+        table
+    };
+    let stream = |policy| {
+        let mut printer = StreamPrinter::with_width_policy(table(), Buffer::no_color(), policy);
+        for name in ["Al", "Barbara", "Christopher"] {
+            printer.push_row(vec![Cell::new(name)]).unwrap();
+        }
+        String::from_utf8(printer.finish().unwrap().into_inner()).unwrap()
+    };
+
+    let fixed = stream(WidthPolicy::Fixed);
+    assert!(fixed.contains("|  Christopher  |"));
+    assert!(fixed.starts_with("+--------+\n"));
+
+    let grow_never = stream(WidthPolicy::GrowNever);
+    assert!(grow_never.contains("|  Chr…  |"));
+
+    let sampled = stream(WidthPolicy::SampleFirst(2));
+    assert!(sampled.starts_with("+-----------+\n"));
+    assert!(sampled.contains("|  Barbara  |"));
+    assert!(sampled.contains("|  Christ…  |"));
+
+    let short_sample = stream(WidthPolicy::SampleFirst(10));
+    assert!(short_sample.contains("|  Christopher  |"));
+    assert_eq!(short_sample.lines().count(), 7);
+}
+
+#[test]
+fn test_stream_error_overflow_policy() {
+    let mut table = Table::new(TableStyle::Grid);
+    table.add_column("Name", 8, Alignment::Left);
+    table.add_column("Tag", 7, Alignment::Left);
+    table.set_overflow_policy(OverflowPolicy::Error);
+    let mut printer =
+        StreamPrinter::with_width_policy(table, Buffer::no_color(), WidthPolicy::GrowNever);
+    printer
+        .push_row(vec![Cell::new("Al"), Cell::new("x")])
+        .unwrap();
+    let error = printer
+        .push_row(vec![Cell::new("much too long content"), Cell::new("x")])
+        .unwrap_err();
+    assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+    assert_eq!(
+        error.to_string(),
+        "row 1, column `Name`: content is 21 characters wide but only 6 fit"
+    );
+    printer
+        .push_row(vec![Cell::new("Bo"), Cell::new("y")])
+        .unwrap();
+    let output = String::from_utf8(printer.finish().unwrap().into_inner()).unwrap();
+    assert!(!output.contains("much too long"));
+    assert!(output.contains("|  Bo      |  y      |"));
+
+    let mut table = Table::new(TableStyle::Grid);
+    table.add_column("A much too long header", 8, Alignment::Left);
+    table.set_overflow_policy(OverflowPolicy::Error);
+    let mut printer = StreamPrinter::new(table, Buffer::no_color());
+    assert!(printer.print_header().is_err());
+}
+
+#[test]
+fn test_lazy_cells() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    static CALLS: AtomicUsize = AtomicUsize::new(0);
+    let mut table = Table::new(TableStyle::Grid);
+    table.add_column("Id", 4, Alignment::Left);
+    table.add_column("Digest", 10, Alignment::Left);
+    for id in 0..3 {
+        table.add_row(vec![
+            Cell::new(&id.to_string()),
+            Cell::lazy(move || {
+                CALLS.fetch_add(1, Ordering::SeqCst);
+                format!("hash-{}", id)
+            }),
+        ]);
+    }
+    assert_eq!(CALLS.load(Ordering::SeqCst), 0);
+    let mut buffer = Buffer::no_color();
+    table.slice(1..2, ..).print_to_writer(&mut buffer).unwrap();
+    let output = String::from_utf8(buffer.into_inner()).unwrap();
+    assert!(output.contains("hash-1"));
+    assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+    assert!(table.rows[1][1].is_evaluated());
+    assert!(!table.rows[0][1].is_evaluated());
+
+    let mut buffer = Buffer::no_color();
+    let budget = RenderBudget {
+        max_lines: Some(4),
+        max_bytes: None,
+    };
+    table
+        .print_with_budget_to_writer(&mut buffer, budget)
+        .unwrap();
+    let output = String::from_utf8(buffer.into_inner()).unwrap();
+    assert!(output.contains("hash-0"));
+    assert!(output.ends_with("rows omitted\n"));
+    assert_eq!(CALLS.load(Ordering::SeqCst), 2);
+    assert!(!table.rows[2][1].is_evaluated());
+    table.to_markdown();
+    assert_eq!(CALLS.load(Ordering::SeqCst), 3);
+}
+
+#[test]
+fn test_render_grid() {
+    let mut table = create_test_table(TableStyle::Neon);
+    table.rows[0][0].style.bold = true;
+    let grid = table.render_grid();
+    let mut buffer = Buffer::no_color();
+    table.print_to_writer(&mut buffer).unwrap();
+    let output = String::from_utf8(buffer.into_inner()).unwrap();
+    let lines: Vec<&str> = output.lines().collect();
+    assert_eq!(grid.len(), lines.len());
+    for (row, line) in grid.iter().zip(&lines) {
+        let text: String = row.iter().map(|cell| cell.symbol.as_str()).collect();
+        assert_eq!(&text, line);
+        assert_eq!(row.len(), text_width(line));
+    }
+    assert_eq!(grid[0][0].style.fg(), Some(&Color::Magenta));
+    let alice = lines[3].find('A').unwrap();
+    let column = text_width(&lines[3][..alice]);
+    assert_eq!(grid[3][column].symbol, "A");
+    assert!(grid[3][column].style.bold());
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn test_from_ndjson_column_order() {
+    let log = "{\"level\": \"info\", \"msg\": \"started\"}\n\
+               \n\
+               {\"msg\": \"disk full\", \"level\": \"error\", \"code\": 28}\n\
+               {\"level\": \"warn\", \"tags\": [\"io\"], \"code\": null}\n";
+    let table = Table::from_ndjson(log, ColumnOrderPolicy::FirstSeen).unwrap();
+    assert_eq!(table.column_headers(), vec!["level", "msg", "code", "tags"]);
+    assert_eq!(table.rows[0][2].content, "");
+    assert_eq!(table.rows[1][2].content, "28");
+    assert_eq!(table.rows[2][3].content, "[\"io\"]");
+
+    let table = Table::from_ndjson(log, ColumnOrderPolicy::Sorted).unwrap();
+    assert_eq!(table.column_headers(), vec!["code", "level", "msg", "tags"]);
+
+    let explicit = ColumnOrderPolicy::Explicit(vec!["msg".to_string(), "host".to_string()]);
+    let table = Table::from_ndjson(log, explicit).unwrap();
+    assert_eq!(table.column_headers(), vec!["msg", "host"]);
+    assert_eq!(table.rows[1][0].content, "disk full");
+    assert_eq!(table.rows[1][1].content, "");
+
+    let table =
+        Table::from_json("[{\"a\": 1}, {\"b\": true}]", ColumnOrderPolicy::default()).unwrap();
+    assert_eq!(table.shape(), (2, 2));
+    assert!(matches!(
+        Table::from_ndjson("{\"a\": 1}\n[1]\n", ColumnOrderPolicy::default()).err(),
+        Some(TableError::InvalidJson(reason)) if reason.starts_with("line 2")
+    ));
+}
+
+#[test]
+fn test_print_split() {
+    let table = create_test_table(TableStyle::Grid);
+    assert_eq!(table.split_columns(100, None), vec![vec![0, 1, 2]]);
+    assert_eq!(
+        table.split_columns(25, Some(0)),
+        vec![vec![0, 1], vec![0, 2]]
+    );
+    assert_eq!(
+        table.split_columns(5, None),
+        vec![vec![0], vec![1], vec![2]]
+    );
+
+    let mut buffer = Buffer::no_color();
+    table
+        .print_split_to_writer(&mut buffer, 25, Some("Name".into()))
+        .unwrap();
+    let output = String::from_utf8(buffer.into_inner()).unwrap();
+    assert_eq!(
+        output,
+        "+----------+-------+\n\
+         |  Name    |  Age  |\n\
+         +----------+-------+\n\
+         |  Alice   |   30  |\n\
+         |  Bob     |   25  |\n\
+         +----------+-------+\n\
+         \n\
+         +----------+---------------+\n\
+         |  Name    |     City      |\n\
+         +----------+---------------+\n\
+         |  Alice   |   New York    |\n\
+         |  Bob     |  Los Angeles  |\n\
+         +----------+---------------+\n"
+    );
+    let mut buffer = Buffer::no_color();
+    assert!(table
+        .print_split_to_writer(&mut buffer, 25, Some(3.into()))
+        .is_err());
+}
+
+#[test]
+fn test_view_keeps_sticky_columns() {
+    let mut table = Table::new(TableStyle::Grid);
+    for header in ["A", "B", "Key"] {
+        table.add_column(header, ColumnWidth::Auto, Alignment::Left);
+    }
+    table.add_row(vec![Cell::new("a"), Cell::new("b"), Cell::new("k")]);
+    table.set_sticky_columns(["Key"]).unwrap();
+    let selected = table.view().select(["Key", "A", "B"]).unwrap().to_table();
+    assert_eq!(selected.sticky_columns, [0]);
+    assert!(table
+        .view()
+        .select(["A"])
+        .unwrap()
+        .to_table()
+        .sticky_columns
+        .is_empty());
+
+    let mut buffer = Buffer::no_color();
+    selected
+        .print_split_to_writer(&mut buffer, 12, None)
+        .unwrap();
+    let output = String::from_utf8(buffer.into_inner()).unwrap();
+    let parts: Vec<&str> = output.split("\n\n").collect();
+    assert_eq!(parts.len(), 2);
+    assert!(parts[1].contains("|  Key  |  B  |"));
+}
+
+#[test]
+fn test_print_split_shares_widths_and_footnotes() {
+    let mut table = create_test_table(TableStyle::Grid);
+    table.set_target_width(60);
+    table.columns[0].width = ColumnWidth::Percent(30);
+    table.set_footnotes(&["note one"]);
+    let widths = table.column_widths();
+
+    let mut buffer = Buffer::no_color();
+    table.print_split_to_writer(&mut buffer, 5, None).unwrap();
+    let output = String::from_utf8(buffer.into_inner()).unwrap();
+    assert_eq!(output.matches("note one").count(), 1);
+    assert!(output.trim_end().ends_with("note one"));
+    let first_border = output.lines().next().unwrap();
+    assert_eq!(first_border.len(), widths[0] + 4);
+}
+
+#[test]
+fn test_sticky_columns() {
+    let mut table = create_test_table(TableStyle::Grid);
+    assert!(table.set_sticky_columns(["Town"]).is_err());
+    table.set_sticky_columns([2]).unwrap();
+    assert_eq!(table.split_columns(5, None), vec![vec![2, 0], vec![2, 1]]);
+    assert_eq!(table.split_columns(5, Some(0)), vec![vec![0, 2, 1]]);
+    assert_eq!(table.split_columns(100, None), vec![vec![2, 0, 1]]);
+}
+
+#[cfg(feature = "comfy-table")]
+#[test]
+fn test_comfy_table_conversion() {
+    let mut table = create_test_table(TableStyle::Markdown);
+    table.rows[1][0].style.alignment = Some(Alignment::Right);
+    let converted = comfy_table::Table::from(&table);
+    assert_eq!(
+        converted.to_string(),
+        "| Name  | Age |     City    |\n\
+         |-------|-----|-------------|\n\
+         | Alice |  30 |   New York  |\n\
+         |   Bob |  25 | Los Angeles |"
+    );
+}
+
+#[cfg(feature = "tabled")]
+#[test]
+fn test_from_tabled() {
+    use std::borrow::Cow;
+
+    struct Language {
+        name: &'static str,
+        year: u16,
+    }
+
+    impl ::tabled::Tabled for Language {
+        const LENGTH: usize = 2;
+
+        fn fields(&self) -> Vec<Cow<'_, str>> {
+            vec![Cow::Borrowed(self.name), Cow::Owned(self.year.to_string())]
+        }
+
+        fn headers() -> Vec<Cow<'static, str>> {
+            vec![Cow::Borrowed("Name"), Cow::Borrowed("Year")]
+        }
+    }
+
+    let languages = vec![
+        Language {
+            name: "Rust",
+            year: 2015,
+        },
+        Language {
+            name: "Go",
+            year: 2009,
+        },
+    ];
+    let table = Table::from_tabled(&languages);
+    assert_eq!(
+        table.to_markdown(),
+        "| Name | Year |\n| :--- | :--- |\n| Rust | 2015 |\n| Go | 2009 |\n"
+    );
+}
+
+#[test]
+fn test_try_aggregations() {
+    let mut table = Table::new(TableStyle::Simple);
+    table.add_column("Amount", 10, Alignment::Right);
+    table.add_row(vec![Cell::new("100")]);
+    table.add_row(vec![Cell::new("")]);
+    table.add_row(vec![Cell::new("300")]);
+    assert_eq!(table.try_sum_column(0), Ok(400.0));
+    assert_eq!(table.try_average_column(0), Ok(200.0));
+    assert_eq!(table.try_min_column(0), Ok(100.0));
+    assert_eq!(table.try_max_column(0), Ok(300.0));
+    assert_eq!(
+        table.try_sum_column(1),
+        Err(AggregateError::ColumnOutOfBounds { index: 1, len: 1 })
+    );
+
+    table.add_row(vec![Cell::new("n/a")]);
+    table.add_row(vec![Cell::new("NaN")]);
+    assert_eq!(
+        table.try_sum_column(0),
+        Err(AggregateError::Unparsable {
+            cells: vec![(3, "n/a".to_string())]
+        })
+    );
+    assert_eq!(table.max_column_lossy(0), Some(300.0));
+    assert_eq!(table.min_column(0), Some(100.0));
+
+    table.rows[3][0].content = "200".to_string();
+    assert_eq!(
+        table.try_max_column(0),
+        Err(AggregateError::NotANumber { rows: vec![4] })
+    );
+
+    let empty = Table::new(TableStyle::Simple);
+    assert_eq!(empty.sum_column_lossy(0), None);
+
+    let mut nan = Table::new(TableStyle::Simple);
+    nan.add_column("Amount", 10, Alignment::Right);
+    nan.add_row(vec![Cell::new("NaN")]);
+    assert_eq!(nan.sum_column_lossy(0), None);
+    assert_eq!(nan.average_column_lossy(0), None);
+    assert_eq!(nan.min_column_lossy(0), None);
+    assert_eq!(nan.max_column_lossy(0), None);
+}
+
+#[test]
+fn test_render_budget() {
+    let mut table = create_test_table(TableStyle::Simple);
+    for i in 0..1200 {
+        table.add_row(vec![
+            Cell::new(&format!("P{}", i)),
+            Cell::new("1"),
+            Cell::new("X"),
+        ]);
+    }
+
+    let mut buffer = Buffer::no_color();
+    let budget = RenderBudget {
+        max_lines: Some(4),
+        max_bytes: None,
+    };
+    table
+        .print_with_budget_to_writer(&mut buffer, budget)
+        .unwrap();
+    let output = String::from_utf8(buffer.into_inner()).unwrap();
+    let lines: Vec<&str> = output.lines().collect();
+    assert_eq!(lines.len(), 5);
+    assert_eq!(lines[3], " P0       1       X       ");
+    assert_eq!(lines[4], "… output truncated, 1,199 rows omitted");
+
+    let mut buffer = Buffer::no_color();
+    let budget = RenderBudget {
+        max_lines: None,
+        max_bytes: Some(100),
+    };
+    table.set_render_safety(RenderSafety::Strict);
+    table
+        .print_with_budget_to_writer(&mut buffer, budget)
+        .unwrap();
+    let output = String::from_utf8(buffer.into_inner()).unwrap();
+    let (shown, summary) = output.trim_end().rsplit_once('\n').unwrap();
+    assert!(shown.len() < 100);
+    assert!(summary.starts_with("... output truncated, 1,"));
+
+    let small = create_test_table(TableStyle::Grid);
+    let mut buffer = Buffer::no_color();
+    small
+        .print_with_budget_to_writer(&mut buffer, RenderBudget::default())
+        .unwrap();
+    let mut expected = Buffer::no_color();
+    small.print_color(&mut expected).unwrap();
+    assert_eq!(buffer.into_inner(), expected.into_inner());
+}
+
+#[test]
+fn test_render_budget_leaves_lazy_rows_unevaluated() {
+    let mut table = Table::new(TableStyle::Grid);
+    table.add_column("Id", 6, Alignment::Left);
+    table.add_column("Digest", 10, Alignment::Left);
+    for id in 0..100 {
+        table.add_row(vec![
+            Cell::new(&id.to_string()),
+            Cell::lazy(move || format!("hash-{}", id)),
+        ]);
+    }
+    let mut buffer = Buffer::no_color();
+    let budget = RenderBudget {
+        max_lines: Some(5),
+        max_bytes: None,
+    };
+    table
+        .print_with_budget_to_writer(&mut buffer, budget)
+        .unwrap();
+    let output = String::from_utf8(buffer.into_inner()).unwrap();
+    assert!(output.contains("hash-1"));
+    assert!(output.ends_with("… output truncated, 98 rows omitted\n"));
+    // The third row is rendered up to the line the budget cuts off.
+    let evaluated = table
+        .rows
+        .iter()
+        .filter(|row| row[1].is_evaluated())
+        .count();
+    assert_eq!(evaluated, 3);
+}
+
+#[test]
+fn test_to_rst_simple() {
+    let mut table = create_test_table(TableStyle::Grid);
+    table.add_row(vec![
+        Cell::new(""),
+        Cell::new("7"),
+        Cell::new("Paris\nFrance"),
+    ]);
+    assert_eq!(
+        table.to_rst_simple(),
+        "======  ===  ===========\n\
+         Name    Age     City\n\
+         ======  ===  ===========\n\
+         Alice    30   New York\n\
+         Bob      25  Los Angeles\n\
+         ..        7     Paris\n\
+         \x20              France\n\
+         ======  ===  ===========\n"
+    );
+}
+
+#[test]
+fn test_table_template() {
+    let template = TableTemplate::new(TableStyle::Grid)
+        .column("Item", ColumnWidth::Auto, Alignment::Left)
+        .column("Price", 8, Alignment::Right)
+        .format(
+            "Price",
+            CellStyle {
+                decimal_places: Some(2),
+                ..CellStyle::default()
+            },
+        );
+    assert_eq!(template.headers(), vec!["Item", "Price"]);
+    assert!(template.instantiate().rows.is_empty());
+
+    let table = template.instantiate_with_rows([["Tea", "3"], ["Cake", "4.5"]]);
+    let mut buffer = Buffer::no_color();
+    table.print_color(&mut buffer).unwrap();
+    assert_eq!(
+        String::from_utf8(buffer.into_inner()).unwrap(),
+        "+--------+----------+\n\
+         |  Item  |   Price  |\n\
+         +--------+----------+\n\
+         |  Tea   |    3.00  |\n\
+         |  Cake  |    4.50  |\n\
+         +--------+----------+\n"
+    );
+
+    let captured = table.to_template().instantiate_with_rows([["Tea", "3"]]);
+    assert_eq!(captured.columns.len(), 2);
+    assert_eq!(captured.rows[0][1].style.decimal_places, None);
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn test_table_template_json() {
+    let mut table = create_test_table(TableStyle::Round);
+    table.set_column_overflow(2, OverflowPolicy::Wrap).unwrap();
+    let template = table.to_template().format(
+        "Age",
+        CellStyle {
+            thousand_separator: true,
+            ..CellStyle::default()
+        },
+    );
+    let restored = TableTemplate::from_json(&template.to_json()).unwrap();
+    assert_eq!(restored.to_json(), template.to_json());
+    assert_eq!(restored.style, TableStyle::Round);
+    assert_eq!(restored.columns[2].overflow, Some(OverflowPolicy::Wrap));
+    assert!(restored.formats[1].thousand_separator);
+    assert!(matches!(
+        TableTemplate::from_json("{\"version\": 1}"),
+        Err(TableError::InvalidJson(_))
+    ));
+}
+
+#[test]
+fn test_fluent_transformations() {
+    let rendered = Table::new(TableStyle::Simple)
+        .with_column("Name", ColumnWidth::Auto, Alignment::Left)
+        .with_column("Score", ColumnWidth::Auto, Alignment::Right)
+        .with_row(vec![Cell::new("Bob"), Cell::new("7")])
+        .with_row(vec![Cell::new("Alice"), Cell::new("9")])
+        .with_row(vec![Cell::new("Carol"), Cell::new("3")])
+        .filtered(|row| row[1].content != "3")
+        .sorted_by(0, true)
+        .with_style(TableStyle::Grid)
+        .render();
+    assert_eq!(
+        rendered,
+        "+---------+---------+\n\
+         |  Name   |  Score  |\n\
+         +---------+---------+\n\
+         |  Alice  |      9  |\n\
+         |  Bob    |      7  |\n\
+         +---------+---------+\n"
+    );
+}
+
+#[test]
+fn test_column_ref() {
+    let mut table = Table::new(TableStyle::Simple);
+    table.add_column("Name", 10, Alignment::Left);
+    table.add_column("Amount", 10, Alignment::Right);
+    table.add_row(vec![Cell::new("b"), Cell::new("100")]);
+    table.add_row(vec![Cell::new("a"), Cell::new("200")]);
+
+    assert_eq!(table.column_position("Amount"), Ok(1));
+    assert_eq!(table.column_position(ColumnRef::Index(0)), Ok(0));
+    assert_eq!(
+        table.column_position("Missing"),
+        Err(TableError::UnknownColumn("Missing".to_string()))
+    );
+    assert_eq!(
+        table.column_position(2),
+        Err(TableError::ColumnOutOfBounds { index: 2, len: 2 })
+    );
+
+    assert_eq!(table.sum_column("Amount"), table.sum_column(1));
+    assert_eq!(table.try_max_column("Amount"), Ok(200.0));
+    assert_eq!(
+        table.try_max_column("Missing"),
+        Err(AggregateError::UnknownColumn("Missing".to_string()))
+    );
+    table.sort_by_column("Name", true);
+    assert_eq!(table.rows[0][0].content, "a");
+    let header = "Amount".to_string();
+    table.set_cell(1, &header, "300").unwrap();
+    assert_eq!(table.rows[1][1].content, "300");
+    assert!(table.set_cell(1, "Missing", "0").is_err());
+}
+
+#[test]
+#[should_panic(expected = "no column has the header `Missing`")]
+fn test_column_ref_unknown_name_panics() {
+    let table = create_test_table(TableStyle::Simple);
+    table.sum_column("Missing");
+}
+
+#[test]
+fn test_row_validator() {
+    let mut table = create_test_table(TableStyle::Simple);
+    table.set_row_validator(|row| {
+        row[1]
+            .content
+            .parse::<u32>()
+            .map(|_| ())
+            .map_err(|_| format!("age `{}` is not a number", row[1].content))
+    });
+    assert!(table.validate_rows().is_ok());
+    table
+        .try_add_row(vec![Cell::new("Carol"), Cell::new("41"), Cell::new("Oslo")])
+        .unwrap();
+    assert_eq!(
+        table.try_add_row(vec![Cell::new("Dave"), Cell::new("n/a"), Cell::new("Rome")]),
+        Err(TableError::InvalidRow {
+            row: 3,
+            reason: "age `n/a` is not a number".to_string()
+        })
+    );
+    assert!(matches!(
+        table.try_add_row(vec![Cell::new("Eve")]),
+        Err(TableError::InvalidRow { row: 3, .. })
+    ));
+    assert_eq!(table.rows.len(), 3);
+
+    table.rows[0][1].content = "thirty".to_string();
+    assert!(matches!(
+        table.validate_rows(),
+        Err(TableError::InvalidRow { row: 0, .. })
+    ));
+
+    let mut printer = StreamPrinter::new(table, Buffer::no_color());
+    let rejected = printer.push_row(vec![Cell::new("Dave"), Cell::new("?"), Cell::new("Rome")]);
+    assert_eq!(
+        rejected.unwrap_err().kind(),
+        std::io::ErrorKind::InvalidData
+    );
+    assert!(printer
+        .push_row(vec![Cell::new("Dave"), Cell::new("50"), Cell::new("Rome")])
+        .is_ok());
+}
+
+#[test]
+#[should_panic(expected = "row 2 is invalid: too young")]
+fn test_row_validator_add_row_panics() {
+    let mut table = create_test_table(TableStyle::Simple);
+    table.set_row_validator(|row| match row[1].content.as_str() {
+        "1" => Err("too young".to_string()),
+        _ => Ok(()),
+    });
+    table.add_row(vec![Cell::new("Kid"), Cell::new("1"), Cell::new("Bern")]);
+}
+
+#[test]
+fn test_schema_column_defs() {
+    let price = CellStyle {
+        decimal_places: Some(2),
+        ..CellStyle::default()
+    };
+    let schema = Schema::new()
+        .column("Item", ColumnType::Text)
+        .define(ColumnDef::new("Price", ColumnType::Integer).format(price))
+        .define(ColumnDef::new("Stock", ColumnType::Integer).alignment(Alignment::Center));
+
+    let mut table = Table::new(TableStyle::Simple);
+    table.add_column("Item", 8, Alignment::Left);
+    table.add_column("Price", 8, Alignment::Left);
+    table.add_column("Stock", 8, Alignment::Left);
+    table.add_row(vec![Cell::new("Tea"), Cell::new(" 3 "), Cell::new("10")]);
+    table.apply_schema(&schema).unwrap();
+    assert_eq!(table.rows[0][1].content, "3");
+    assert_eq!(table.rows[0][1].style.decimal_places, Some(2));
+    assert_eq!(table.columns[1].alignment, Alignment::Right);
+    assert_eq!(table.columns[2].alignment, Alignment::Center);
+
+    table.set_schema_validator(&schema).unwrap();
+    assert!(table
+        .try_add_row(vec![Cell::new("Cake"), Cell::new("4"), Cell::new("2")])
+        .is_ok());
+    assert_eq!(
+        table.try_add_row(vec![Cell::new("Pie"), Cell::new("cheap"), Cell::new("1")]),
+        Err(TableError::InvalidRow {
+            row: 2,
+            reason: "column `Price`: cannot parse `cheap`: not a whole number".to_string()
+        })
+    );
+    let missing = Schema::new().column("Weight", ColumnType::Integer);
+    assert!(table.set_schema_validator(&missing).is_err());
+
+    let template = schema.to_template(TableStyle::Grid);
+    assert_eq!(template.headers(), vec!["Item", "Price", "Stock"]);
+    let created = template.instantiate_with_rows([["Tea", "3", "10"]]);
+    assert_eq!(created.columns[1].alignment, Alignment::Right);
+    assert_eq!(created.rows[0][1].display_content(), "3.00");
+}
+
+#[test]
+fn test_auto_adjust_widths_within() {
+    let mut table = Table::new(TableStyle::Grid);
+    table.add_column("Product", 5, Alignment::Left);
+    table.add_column("Description", 5, Alignment::Left);
+    table.add_column("Price", 5, Alignment::Right);
+    table.add_row(vec![
+        Cell::new("Kettle"),
+        Cell::new("Stainless steel kettle with auto shut-off"),
+        Cell::new("39.90"),
+    ]);
+    table.add_row(vec![
+        Cell::new("Mug"),
+        Cell::new("Ceramic mug"),
+        Cell::new("7.50"),
+    ]);
+
+    assert_eq!(table.auto_adjust_widths_within(200), Vec::<usize>::new());
+    assert_eq!(table.columns[1].width, ColumnWidth::Auto);
+
+    assert_eq!(table.auto_adjust_widths_within(40), vec![1]);
+    assert_eq!(table.content_width(), 40);
+    assert_eq!(table.columns[1].overflow, Some(OverflowPolicy::Wrap));
+    let layout = table.layout();
+    assert_eq!(layout.width, 40);
+    assert!(layout.rows[0].len() > 1);
+
+    assert_eq!(table.auto_adjust_widths_within(10), vec![1]);
+    assert_eq!(table.columns[1].width, ColumnWidth::Fixed(13));
+}
+
+#[test]
+fn test_cell_fill_char() {
+    let leader = CellStyle {
+        fill_char: '.',
+        ..CellStyle::default()
+    };
+    let mut table = Table::new(TableStyle::Simple);
+    table.add_column("Setting", 14, Alignment::Left);
+    table.add_column("Value", 8, Alignment::Right);
+    table.add_row(vec![
+        Cell {
+            style: leader,
+            ..Cell::new("timeout")
+        },
+        Cell::new("30"),
+    ]);
+    table.add_row(vec![
+        Cell {
+            style: CellStyle {
+                fill_char: '\u{1b}',
+                ..leader
+            },
+            ..Cell::new("retries")
+        },
+        Cell::new("5"),
+    ]);
+    let output = table.render();
+    let lines: Vec<&str> = output.lines().collect();
+    assert_eq!(lines[1], " timeout....      30  ");
+    assert_eq!(lines[2], " retries           5  ");
+}
+
+#[test]
+fn test_to_doc_comment() {
+    let table = create_test_table(TableStyle::Minimal);
+    let comment = table.to_doc_comment();
+    assert_eq!(comment.lines().count(), table.render().lines().count());
+    assert_eq!(
+        comment.lines().next(),
+        Some("/// ┌──────────┬───────┬───────────────┐")
+    );
+    let table = create_test_table(TableStyle::Simple);
+    let comment = table.to_prefixed("// ");
+    assert!(comment
+        .lines()
+        .all(|line| line.starts_with("// ") && !line.ends_with(' ')));
+}
+
+#[test]
+fn test_watch_redraws_changed_lines() {
+    let mut screen = crate::watch::Screen::default();
+    assert_eq!(
+        screen.redraw("a\nb\nc\n"),
+        "\x1b[2J\x1b[1;1Ha\x1b[K\x1b[2;1Hb\x1b[K\x1b[3;1Hc\x1b[K\x1b[4;1H"
+    );
+    assert_eq!(
+        screen.redraw("a\nB\n"),
+        "\x1b[2;1HB\x1b[K\x1b[3;1H\x1b[J\x1b[3;1H"
+    );
+    assert_eq!(screen.redraw("a\nB\n"), "\x1b[3;1H");
+}
+
+#[test]
+fn test_header_underline() {
+    let mut table = create_test_table(TableStyle::Round);
+    table.set_header_underline(true);
+    let output = table.render();
+    let lines: Vec<&str> = output.lines().collect();
+    assert_eq!(lines[1], "│  Name    │  Age  │     City      │");
+    assert_eq!(lines[2], "│  ════    │  ═══  │     ════      │");
+    assert!(lines[3].starts_with('├'));
+    assert_eq!(table.layout().header, 1..3);
+    assert_eq!(table.layout().height, lines.len());
+
+    table.set_render_safety(RenderSafety::Strict);
+    assert!(table.render().contains("  ====    │  ===  "));
+    table.set_style(TableStyle::Simple);
+    assert!(!table.render().contains("===="));
+}
+
+#[test]
+fn test_print_side_by_side() {
+    let before = create_test_table(TableStyle::Minimal);
+    let mut after = Table::new(TableStyle::Minimal);
+    after.add_column("Total", 9, Alignment::Right);
+    after.add_row(vec![Cell::new("2")]);
+    after.add_row(vec![Cell::new("3")]);
+    after.add_row(vec![Cell::new("4")]);
+    let mut buffer = Buffer::no_color();
+    print_side_by_side_to_writer(&mut buffer, &[&after, &before], 3).unwrap();
+    let output = String::from_utf8(buffer.into_inner()).unwrap();
+    let lines: Vec<&str> = output.lines().collect();
+    assert_eq!(lines.len(), 7);
+    assert_eq!(
+        lines[0],
+        "┌───────────┐   ┌──────────┬───────┬───────────────┐"
+    );
+    assert_eq!(lines[6], "└───────────┘");
+}
+
+#[test]
+fn test_cell_formats() {
+    let format = |content: &str, format: CellFormat| {
+        let mut cell = Cell::new(content);
+        cell.style.format = format;
+        cell.formatted_content()
+    };
+    assert_eq!(format("512", CellFormat::Bytes), "512 B  ");
+    assert_eq!(format("12897485", CellFormat::Bytes), "12.3 MiB");
+    assert_eq!(format("-2048", CellFormat::Bytes), "-2.0 KiB");
+    assert_eq!(format("92", CellFormat::Duration), "1m 32s");
+    assert_eq!(format("7500", CellFormat::Duration), "2h 05m");
+    assert_eq!(format("45", CellFormat::Duration), "45s");
+    assert_eq!(format("0.25", CellFormat::Duration), "250ms");
+    assert_eq!(format("n/a", CellFormat::Duration), "n/a");
+
+    let mut table = Table::new(TableStyle::Simple);
+    table.add_column("Size", 12, Alignment::Right);
+    for bytes in ["900", "1536", "5000000"] {
+        let mut cell = Cell::new(bytes);
+        cell.style.format = CellFormat::Bytes;
+        table.add_row(vec![cell]);
+    }
+    let output = table.render();
+    let units: Vec<usize> = output
+        .lines()
+        .skip(1)
+        .map(|line| line.find(|c: char| c.is_ascii_alphabetic()).unwrap())
+        .collect();
+    assert!(units.windows(2).all(|pair| pair[0] == pair[1]));
+}
+
+#[cfg(feature = "color")]
+#[test]
+fn test_bool_format() {
+    let mut table = Table::new(TableStyle::Simple);
+    table.add_column("Host", 8, Alignment::Left);
+    table.add_column("Up", 4, Alignment::Center);
+    table.add_row(vec![Cell::new("alpha"), Cell::new("yes")]);
+    table.add_row(vec![Cell::new("beta"), Cell::new("false")]);
+    table.add_row(vec![Cell::new("gamma"), Cell::new("?")]);
+    table
+        .set_bool_format("Up", BoolFormat::Checkmark, true)
+        .unwrap();
+    let output = table.render();
+    let lines: Vec<&str> = output.lines().collect();
+    assert!(lines[0].contains("Up"));
+    assert!(lines[1].contains("✓"));
+    assert!(lines[2].contains("✗"));
+    assert!(lines[3].contains('?'));
+    assert_eq!(table.rows[0][1].content, "yes");
+
+    let mut buffer = Buffer::ansi();
+    table.print_color(&mut buffer).unwrap();
+    let output = String::from_utf8(buffer.into_inner()).unwrap();
+    assert!(output.contains("\x1b[32m"));
+    assert!(output.contains("\x1b[31m"));
+
+    table
+        .set_bool_format(1, BoolFormat::Custom("up", "down"), false)
+        .unwrap();
+    assert!(table.to_markdown().contains("| beta | down |"));
+}
+
+#[cfg(feature = "color")]
+#[test]
+fn test_category_styles() {
+    let mut table = Table::new(TableStyle::Simple);
+    table.add_column("Test", 8, Alignment::Left);
+    table.add_column("Result", 8, Alignment::Left);
+    for (test, result) in [("parse", "PASS"), ("render", "FAIL"), ("export", "PASS")] {
+        table.add_row(vec![Cell::new(test), Cell::new(result)]);
+    }
+    let red = CellStyle {
+        color: Some(Color::Red),
+        bold: true,
+        ..CellStyle::default()
+    };
+    let green = CellStyle {
+        color: Some(Color::Green),
+        ..CellStyle::default()
+    };
+    let styles = HashMap::from([("PASS".to_string(), green), ("FAIL".to_string(), red)]);
+    table.set_category_styles("Result", styles).unwrap();
+    assert!(table.set_category_styles(5, HashMap::new()).is_err());
+
+    let mut buffer = Buffer::ansi();
+    table.print_color(&mut buffer).unwrap();
+    let output = String::from_utf8(buffer.into_inner()).unwrap();
+    assert_eq!(output.matches("\x1b[32m PASS").count(), 2);
+    assert_eq!(output.matches("\x1b[1m\x1b[31m FAIL").count(), 1);
+    assert!(!output.contains("\x1b[32mResult"));
+}
+
+#[test]
+fn test_show_whitespace() {
+    let mut table = Table::new(TableStyle::Grid);
+    table.add_column("Name", 10, Alignment::Left);
+    table.add_column("City", 10, Alignment::Left);
+    table.add_row(vec![Cell::new(" Alice "), Cell::new("New York")]);
+    table.add_row(vec![Cell::new("Bob\t"), Cell::new("   ")]);
+    table.set_show_whitespace(true);
+    let output = table.render();
+    assert!(output.contains("·Alice·"));
+    assert!(output.contains("New York"));
+    assert!(output.contains("Bob·"));
+    assert!(output.contains("···"));
+    assert!(table.to_markdown().contains("|  Alice  |"));
+
+    table.set_render_safety(RenderSafety::Strict);
+    assert!(table.render().contains("~Alice~"));
+}
+
+#[test]
+fn test_layout_consistent_across_render_paths() {
+    let styles = [
+        TableStyle::Simple,
+        TableStyle::Grid,
+        TableStyle::FancyGrid,
+        TableStyle::Clean,
+        TableStyle::Round,
+        TableStyle::Banner,
+        TableStyle::Block,
+        TableStyle::Amiga,
+        TableStyle::Minimal,
+        TableStyle::Compact,
+        TableStyle::Markdown,
+        TableStyle::Dotted,
+        TableStyle::Heavy,
+        TableStyle::Neon,
+        TableStyle::Accessible,
+    ];
+    for style in styles {
+        let mut table = create_test_table(style);
+        table.add_separator();
+        table.add_row(vec![
+            Cell::new("Carol").with_footnote("1"),
+            Cell::new("true"),
+            Cell::new("Boston\nMA"),
+        ]);
+        table.set_footer(vec![Cell::new("Total"), Cell::new("96"), Cell::new("")]);
+        table.set_footnotes(&["moved in 2024"]);
+        table.set_row_gradient(Rgb::new(0, 0, 0), Rgb::new(200, 100, 0));
+        table
+            .set_bool_format(1, BoolFormat::Checkmark, true)
+            .unwrap();
+        table.merge_cells(&[CellRange::new(0..1, 1..3)]).unwrap();
+        table.set_header_underline(true);
+        table.assert_layout_consistent();
+    }
+    assert_eq!(
+        crate::text::strip_ansi("\x1b[1m\x1b[38;5;13mbold\x1b[0m plain"),
+        "bold plain"
+    );
+}
+
+#[test]
+fn test_truncation_indicator() {
+    let mut table = Table::new(TableStyle::Simple);
+    table.add_column("Path", 10, Alignment::Left);
+    table.add_column("Note", 10, Alignment::Left);
+    table.add_row(vec![
+        Cell::new("/usr/local/share"),
+        Cell::new("a long note"),
+    ]);
+    table.set_overflow_policy(OverflowPolicy::Truncate);
+    table.set_truncation_indicator("...");
+    table.set_column_truncation_indicator("Path", ">").unwrap();
+    let output = table.render();
+    let row = output.lines().nth(1).unwrap();
+    assert_eq!(row, " /usr/l>   a lo...  ");
+
+    table.set_truncation_indicator("[more]");
+    table.set_column_truncation_indicator(0, "<cut>").unwrap();
+    let output = table.render();
+    let row = output.lines().nth(1).unwrap();
+    assert_eq!(row, " /u<cut>   a[more]  ");
+}
+
+#[test]
+fn test_title_template() {
+    let mut table = create_test_table(TableStyle::Round);
+    table.set_title_template("People — {rows} rows × {columns} columns");
+    let output = table.render();
+    let lines: Vec<&str> = output.lines().collect();
+    assert_eq!(lines[0], "╭─ People — 2 rows × 3 columns ────╮");
+    assert_eq!(text_width(lines[0]), text_width(lines[1]));
+    assert_eq!(table.layout().height, lines.len());
+
+    table.set_title_template("A title far too long to fit inside the top border");
+    let output = table.render();
+    let top = output.lines().next().unwrap();
+    assert_eq!(top, "╭─ A title far too long to fit i… ─╮");
+
+    table.set_style(TableStyle::Simple);
+    table.set_title_template("{rows} rows");
+    let output = table.render();
+    assert_eq!(output.lines().next(), Some("2 rows"));
+    assert_eq!(table.layout().header, 1..2);
+    assert_eq!(table.layout().height, output.lines().count());
+
+    assert_eq!(crate::title::format_utc(0), "1970-01-01 00:00:00 UTC");
+    assert_eq!(
+        crate::title::format_utc(1_700_000_000),
+        "2023-11-14 22:13:20 UTC"
+    );
+    assert_eq!(
+        crate::title::format_utc(951_825_600),
+        "2000-02-29 12:00:00 UTC"
+    );
+}
+
+#[test]
+fn test_to_canonical_text() {
+    let mut table = create_test_table(TableStyle::Neon);
+    table.add_row(vec![
+        Cell::new("  Carol \t Ann "),
+        Cell::new("41"),
+        Cell::new("Boston|MA\r\nUSA"),
+    ]);
+    table.set_footer(vec![Cell::new("Total"), Cell::new("96"), Cell::new("")]);
+    let canonical = table.to_canonical_text();
+    assert_eq!(
+        canonical,
+        "Name | Age | City\n\
+         Alice | 30 | New York\n\
+         Bob | 25 | Los Angeles\n\
+         Carol Ann | 41 | Boston\\|MA\\nUSA\n\
+         --\n\
+         Total | 96 |\n"
+    );
+    table.set_style(TableStyle::Simple);
+    assert_eq!(table.to_canonical_text(), canonical);
+    assert!(table
+        .to_canonical_text_with_sorted_columns()
+        .starts_with("Age | City | Name\n30 | New York | Alice\n"));
+}
+
+#[test]
+fn test_header_overflow() {
+    let mut table = Table::new(TableStyle::Round);
+    table.add_column("Host", ColumnWidth::Auto, Alignment::Left);
+    table.add_column(
+        "Transactions per Second",
+        ColumnWidth::Auto,
+        Alignment::Right,
+    );
+    table.add_row(vec![Cell::new("alpha"), Cell::new("1200")]);
+    table.set_header_overflow(1, OverflowPolicy::Wrap).unwrap();
+    let output = table.render();
+    let lines: Vec<&str> = output.lines().collect();
+    assert_eq!(lines[1], "│  Host   │  Transactions  │");
+    assert_eq!(lines[2], "│         │    per Second  │");
+    assert!(lines[3].starts_with('├'));
+    assert_eq!(lines[4], "│  alpha  │          1200  │");
+    assert_eq!(table.layout().header, 1..3);
+    table.assert_layout_consistent();
+
+    table.set_style(TableStyle::Simple);
+    assert_eq!(table.layout().header.len(), 3);
+    assert_eq!(table.layout().height, table.render().lines().count());
+
+    table.set_style(TableStyle::Round);
+    table
+        .set_header_overflow("Transactions per Second", OverflowPolicy::Truncate)
+        .unwrap();
+    let output = table.render();
+    assert_eq!(output.lines().nth(1), Some("│  Host   │  Tra…  │"));
+}
+
+#[test]
+fn test_vertical_headers() {
+    let mut table = Table::new(TableStyle::Grid);
+    table.add_column("Ab", ColumnWidth::Auto, Alignment::Center);
+    table.add_column("Xyz", ColumnWidth::Auto, Alignment::Center);
+    table.add_row(vec![Cell::new("x"), Cell::new("")]);
+    table.set_header_orientation(HeaderOrientation::Vertical);
+    let output = table.render();
+    let expected = "\
++-----+-----+
+|  A  |  X  |
+|  b  |  y  |
+|     |  z  |
++-----+-----+
+|  x  |     |
++-----+-----+
+";
+    assert_eq!(output, expected);
+    assert_eq!(table.layout().header, 1..4);
+
+    table.set_style(TableStyle::Simple);
+    assert_eq!(table.layout().header.len(), 3);
+    assert_eq!(table.layout().height, table.render().lines().count());
+}
+
+#[test]
+fn test_correlation_matrix() {
+    let mut table = Table::new(TableStyle::Grid);
+    table.add_column("x", ColumnWidth::Auto, Alignment::Right);
+    table.add_column("y", ColumnWidth::Auto, Alignment::Right);
+    table.add_column("z", ColumnWidth::Auto, Alignment::Right);
+    table.add_column("c", ColumnWidth::Auto, Alignment::Right);
+    for (x, y, z) in [
+        ("1", "2", "9"),
+        ("2", "4", "3"),
+        ("3", "6", "n/a"),
+        ("4", "8", "5"),
+    ] {
+        table.add_row(vec![
+            Cell::new(x),
+            Cell::new(y),
+            Cell::new(z),
+            Cell::new("5"),
+        ]);
+    }
+    let matrix = table.correlation_matrix([0, 1, 2, 3]).unwrap();
+    let expected = "\
++-----+---------+---------+---------+-----+
+|     |      x  |      y  |      z  |  c  |
++-----+---------+---------+---------+-----+
+|  x  |   1.00  |   1.00  |  -0.50  |     |
+|  y  |   1.00  |   1.00  |  -0.50  |     |
+|  z  |  -0.50  |  -0.50  |   1.00  |     |
+|  c  |         |         |         |     |
++-----+---------+---------+---------+-----+
+";
+    assert_eq!(matrix.render(), expected);
+    assert_eq!(matrix.rows[0][1].style.color, Some(Color::Rgb(220, 50, 47)));
+    assert_eq!(
+        matrix.rows[2][1].style.color,
+        Some(Color::Rgb(99, 150, 185))
+    );
+    assert_eq!(matrix.rows[3][3].style.color, None);
+    assert_eq!(
+        table.correlation_matrix(["x", "w"]).err(),
+        Some(TableError::UnknownColumn("w".to_string()))
+    );
+}
+
+#[test]
+fn test_running_aggregates() {
+    let mut table = Table::new(TableStyle::Grid);
+    table.add_column("Day", ColumnWidth::Auto, Alignment::Left);
+    table.add_column("Sales", ColumnWidth::Auto, Alignment::Right);
+    for (day, sales) in [("Mon", "10"), ("Tue", "20"), ("Wed", "-"), ("Thu", "35")] {
+        table.add_row(vec![Cell::new(day), Cell::new(sales)]);
+    }
+    table.add_running_total("Sales", "Total");
+    table.add_running_aggregate(1, Aggregation::Mean, Some(2));
+    table.add_running_aggregate("Sales", Aggregation::Max, None);
+    let headers: Vec<&str> = table.columns.iter().map(|c| c.header.as_str()).collect();
+    assert_eq!(
+        headers,
+        [
+            "Day",
+            "Sales",
+            "Total",
+            "Sales (mean of 2)",
+            "Sales (running max)"
+        ]
+    );
+    let column = |index: usize| -> Vec<&str> {
+        table
+            .rows
+            .iter()
+            .map(|row| row[index].content.as_str())
+            .collect()
+    };
+    assert_eq!(column(2), ["10", "30", "30", "65"]);
+    assert_eq!(column(3), ["10", "15", "20", "35"]);
+    assert_eq!(column(4), ["10", "20", "20", "35"]);
+}
+
+#[test]
+fn test_rank_column() {
+    let build = || {
+        let mut table = Table::new(TableStyle::Grid);
+        table.add_column("Player", ColumnWidth::Auto, Alignment::Left);
+        table.add_column("Score", ColumnWidth::Auto, Alignment::Right);
+        for (player, score) in [
+            ("ann", "70"),
+            ("bob", "90"),
+            ("cid", "n/a"),
+            ("dee", "70"),
+            ("eve", "50"),
+        ] {
+            table.add_row(vec![Cell::new(player), Cell::new(score)]);
+        }
+        table
+    };
+    let ranks = |method: RankMethod| -> Vec<String> {
+        let mut table = build();
+        table.add_rank_column("Score", method);
+        assert_eq!(table.columns[0].header, "Rank");
+        table
+            .rows
+            .iter()
+            .map(|row| row[0].content.clone())
+            .collect()
+    };
+    assert_eq!(ranks(RankMethod::Min), ["2", "1", "", "2", "4"]);
+    assert_eq!(ranks(RankMethod::Dense), ["2", "1", "", "2", "3"]);
+    assert_eq!(ranks(RankMethod::Ordinal), ["2", "1", "", "3", "4"]);
+
+    let mut table = build();
+    table.add_rank_column(1, RankMethod::Min);
+    let output = table.render();
+    assert_eq!(
+        output.lines().nth(1),
+        Some("|  Rank  |  Player  |  Score  |")
+    );
+    assert_eq!(
+        output.lines().nth(3),
+        Some("|     2  |  ann     |     70  |")
+    );
+}
+
+#[test]
+fn test_highlight_outliers() {
+    let build = || {
+        let mut table = Table::new(TableStyle::Grid);
+        table.add_column("Latency", ColumnWidth::Auto, Alignment::Right);
+        for latency in ["10", "12", "11", "13", "12", "n/a", "95", "11", "-40"] {
+            table.add_row(vec![Cell::new(latency)]);
+        }
+        table
+    };
+    let mut style = CellStyle::new();
+    style.bold = true;
+    style.color = Some(Color::Red);
+    let highlighted = |table: &Table| -> Vec<String> {
+        table
+            .rows
+            .iter()
+            .filter(|row| row[0].style.bold)
+            .map(|row| row[0].content.clone())
+            .collect()
+    };
+
+    let mut table = build();
+    table
+        .highlight_outliers("Latency", OutlierMethod::Iqr(1.5), style)
+        .unwrap();
+    assert_eq!(highlighted(&table), ["95", "-40"]);
+    assert_eq!(table.rows[6][0].style.color, Some(Color::Red));
+    assert_eq!(table.rows[6][0].style.padding, CellStyle::new().padding);
+
+    let mut table = build();
+    table
+        .highlight_outliers(0, OutlierMethod::ZScore(2.0), style)
+        .unwrap();
+    assert_eq!(highlighted(&table), ["95"]);
+
+    assert!(matches!(
+        table.highlight_outliers("Missing", OutlierMethod::ZScore(3.0), style),
+        Err(TableError::UnknownColumn(_))
+    ));
+}
+
+#[test]
+fn test_sample() {
+    let mut table = Table::new(TableStyle::Grid);
+    table.add_column("Id", ColumnWidth::Auto, Alignment::Right);
+    table.add_column("Region", ColumnWidth::Auto, Alignment::Left);
+    for i in 0..100 {
+        let region = if i % 10 == 0 { "north" } else { "south" };
+        table.add_row(vec![Cell::new(&i.to_string()), Cell::new(region)]);
+    }
+    let ids = |table: &Table| -> Vec<usize> {
+        table
+            .rows
+            .iter()
+            .map(|row| row[0].content.parse().unwrap())
+            .collect()
+    };
+
+    let sample = table.sample(5, 42);
+    let picked = ids(&sample);
+    assert_eq!(picked.len(), 5);
+    assert!(picked.windows(2).all(|pair| pair[0] < pair[1]));
+    assert_eq!(ids(&table.sample(5, 42)), picked);
+    assert_ne!(ids(&table.sample(5, 7)), picked);
+    assert_eq!(table.sample(500, 1).rows.len(), 100);
+
+    let stratified = table.sample_stratified("Region", 3, 42);
+    let regions: Vec<&str> = stratified
+        .rows
+        .iter()
+        .map(|row| row[1].content.as_str())
+        .collect();
+    assert_eq!(regions.iter().filter(|&&r| r == "north").count(), 3);
+    assert_eq!(regions.iter().filter(|&&r| r == "south").count(), 3);
+    assert_eq!(ids(&table.sample_stratified(1, 3, 42)), ids(&stratified));
+}
+
+#[test]
+fn test_split_and_combine_columns() {
+    let mut table = Table::new(TableStyle::Grid);
+    table.add_column("Name", ColumnWidth::Auto, Alignment::Left);
+    table.add_column("Place", ColumnWidth::Auto, Alignment::Left);
+    table.add_column("Age", ColumnWidth::Auto, Alignment::Right);
+    table.add_row(vec![
+        Cell::new("Ann"),
+        Cell::new("Paris, France"),
+        Cell::new("30"),
+    ]);
+    table.add_row(vec![
+        Cell::new("Bob"),
+        Cell::new("Bern, Canton, Swiss"),
+        Cell::new("25"),
+    ]);
+    table.set_footer(vec![Cell::new("2"), Cell::new(""), Cell::new("55")]);
+    table.set_sticky_columns([2]).unwrap();
+
+    table
+        .split_column("Place", ", ", &["City", "Country"])
+        .unwrap();
+    let headers: Vec<&str> = table.columns.iter().map(|c| c.header.as_str()).collect();
+    assert_eq!(headers, ["Name", "City", "Country", "Age"]);
+    assert_eq!(table.rows[1][2].content, "Canton, Swiss");
+    assert_eq!(table.footer.as_ref().unwrap()[3].content, "55");
+    assert_eq!(table.sticky_columns, [3]);
+
+    table
+        .combine_columns(["Country", "City"], " / ", "Location")
+        .unwrap();
+    let headers: Vec<&str> = table.columns.iter().map(|c| c.header.as_str()).collect();
+    assert_eq!(headers, ["Name", "Location", "Age"]);
+    assert_eq!(table.rows[0][1].content, "France / Paris");
+    assert_eq!(table.sticky_columns, [2]);
+    let output = table.render();
+    assert_eq!(
+        output.lines().nth(3),
+        Some("|  Ann   |  France / Paris        |   30  |")
+    );
+
+    let before = table.render();
+    assert!(matches!(
+        table.split_column(1, " / ", &["A", "B", "C"]),
+        Err(TableError::Parse { row: 0, .. })
+    ));
+    assert!(matches!(
+        table.combine_columns([0, 0], "", "X"),
+        Err(TableError::InvalidColumns(_))
+    ));
+    assert_eq!(
+        table.combine_columns([0, 9], "", "X"),
+        Err(TableError::ColumnOutOfBounds { index: 9, len: 3 })
+    );
+    assert_eq!(table.render(), before);
+}
+
+#[cfg(feature = "regex")]
+#[test]
+fn test_replace_in_column() {
+    let mut table = Table::new(TableStyle::Grid);
+    table.add_column("Host", ColumnWidth::Auto, Alignment::Left);
+    table.add_column("Latency", ColumnWidth::Auto, Alignment::Right);
+    table.add_row(vec![Cell::new("alpha"), Cell::new("120 ms")]);
+    table.add_row(vec![Cell::new("beta"), Cell::new("85ms")]);
+    table.add_row(vec![Cell::new("gamma"), Cell::new("n/a")]);
+    table
+        .replace_in_column("Latency", r"(\d+) ?ms", "$1")
+        .unwrap();
+    table.replace_in_column(0, "^a", "A").unwrap();
+    let cells: Vec<Vec<&str>> = table
+        .rows
+        .iter()
+        .map(|row| row.iter().map(|cell| cell.content.as_str()).collect())
+        .collect();
+    assert_eq!(cells, [["Alpha", "120"], ["beta", "85"], ["gamma", "n/a"]]);
+    assert_eq!(table.sum_column(1), Some(205.0));
+
+    assert!(matches!(
+        table.replace_in_column(1, "(", ""),
+        Err(TableError::InvalidExpression { .. })
+    ));
+    assert!(matches!(
+        table.replace_in_column("Missing", "x", ""),
+        Err(TableError::UnknownColumn(_))
+    ));
+}
+
+#[test]
+fn test_normalize_column() {
+    let mut table = Table::new(TableStyle::Grid);
+    table.add_column("City", ColumnWidth::Auto, Alignment::Left);
+    table.add_column("Sales", ColumnWidth::Auto, Alignment::Right);
+    for (city, sales) in [
+        (" Berlin", "1"),
+        ("berlin ", "2"),
+        ("New   York", "3"),
+        ("new\tyork", "4"),
+    ] {
+        table.add_row(vec![Cell::new(city), Cell::new(sales)]);
+    }
+    table
+        .normalize_column(
+            "City",
+            &[
+                Normalize::Trim,
+                Normalize::CollapseWhitespace,
+                Normalize::Lower,
+            ],
+        )
+        .unwrap();
+    let cities: Vec<&str> = table
+        .rows
+        .iter()
+        .map(|row| row[0].content.as_str())
+        .collect();
+    assert_eq!(cities, ["berlin", "berlin", "new york", "new york"]);
+    assert_eq!(table.count_duplicates("City").rows.len(), 2);
+
+    table.normalize_column(0, &[Normalize::Upper]).unwrap();
+    assert_eq!(table.rows[2][0].content, "NEW YORK");
+    assert_eq!(table.columns[0].header, "City");
+    assert!(table.normalize_column(5, &[Normalize::Trim]).is_err());
+}
+
+#[test]
+fn test_row_editing() {
+    let mut table = create_test_table(TableStyle::Grid);
+    let id = table.row_id(1).unwrap();
+    table.add_separator();
+    table
+        .insert_row(
+            1,
+            vec![Cell::new("Carol"), Cell::new("41"), Cell::new("Boston")],
+        )
+        .unwrap();
+    table
+        .insert_row(
+            0,
+            vec![Cell::new("Dan"), Cell::new("19"), Cell::new("Austin")],
+        )
+        .unwrap();
+    let names = |table: &Table| -> Vec<String> {
+        table
+            .rows
+            .iter()
+            .map(|row| row[0].content.clone())
+            .collect()
+    };
+    assert_eq!(names(&table), ["Dan", "Alice", "Carol", "Bob"]);
+    assert_eq!(table.row_index(id), Some(3));
+    assert_eq!(table.separators, [4]);
+
+    table.swap_rows(0, 3).unwrap();
+    assert_eq!(names(&table), ["Bob", "Alice", "Carol", "Dan"]);
+    let removed = table.remove_row(2).unwrap();
+    assert_eq!(removed[0].content, "Carol");
+    assert_eq!(names(&table), ["Bob", "Alice", "Dan"]);
+    assert_eq!(table.separators, [3]);
+
+    table.truncate_rows(2).unwrap();
+    assert_eq!(names(&table), ["Bob", "Alice"]);
+    assert!(table.separators.is_empty());
+
+    assert_eq!(
+        table.insert_row(3, vec![Cell::new("x"), Cell::new("1"), Cell::new("y")]),
+        Err(TableError::RowOutOfBounds { index: 3, len: 2 })
+    );
+    assert!(matches!(
+        table.insert_row(0, vec![Cell::new("x")]),
+        Err(TableError::InvalidRow { row: 0, .. })
+    ));
+    assert!(table.remove_row(2).is_err());
+    assert!(table.swap_rows(0, 2).is_err());
+    assert!(table.truncate_rows(3).is_err());
+    assert_eq!(table.rows.len(), 2);
+}
+
+#[test]
+fn test_extend_rows() {
+    let mut table = create_test_table(TableStyle::Grid);
+    let rows = (0..1000).map(|i| {
+        vec![
+            Cell::new(&format!("n{}", i)),
+            Cell::new("1"),
+            Cell::new("x"),
+        ]
+    });
+    table.extend_rows(rows).unwrap();
+    assert_eq!(table.rows.len(), 1002);
+    assert_eq!(table.rows[1001][0].content, "n999");
+    let last = table.row_id(1001).unwrap();
+
+    let rows = vec![
+        vec![Cell::new("ok"), Cell::new("1"), Cell::new("x")],
+        vec![Cell::new("short")],
+    ];
+    assert!(matches!(
+        table.extend_rows(rows),
+        Err(TableError::InvalidRow { row: 1003, .. })
+    ));
+    assert_eq!(table.rows.len(), 1002);
+    table.add_row(vec![Cell::new("next"), Cell::new("2"), Cell::new("y")]);
+    assert!(table.row_id(1002).unwrap() > last);
+}
+
+#[test]
+fn test_numeric_cache_follows_mutations() {
+    let mut table = create_test_table(TableStyle::Grid);
+    assert_eq!(table.sum_column("Age"), Some(55.0));
+    let parsed = table.parsed_column(1);
+    assert!(Arc::ptr_eq(&parsed, &table.parsed_column(1)));
+    drop(parsed);
+
+    table.add_row(vec![
+        Cell::new("Carol"),
+        Cell::new("45"),
+        Cell::new("Boston"),
+    ]);
+    assert_eq!(table.sum_column("Age"), Some(100.0));
+    table.set_cell(0, "Age", Cell::new("n/a")).unwrap();
+    assert_eq!(table.sum_column("Age"), Some(70.0));
+    assert_eq!(table.min_column("Age"), Some(25.0));
+
+    table.sort_by_column("Name", false);
+    assert_eq!(
+        table.parsed_column(1).as_slice(),
+        [Some(45.0), Some(25.0), None]
+    );
+    table.swap_rows(0, 2).unwrap();
+    assert_eq!(
+        table.parsed_column(1).as_slice(),
+        [None, Some(25.0), Some(45.0)]
+    );
+    table.remove_row(2).unwrap();
+    assert_eq!(table.average_column("Age"), Some(25.0));
+}
+
+#[cfg(not(feature = "color"))]
+#[test]
+fn test_plain_output_without_color() {
+    let mut table = create_test_table(TableStyle::Neon);
+    table.set_row_gradient(Rgb::new(0, 0, 0), Rgb::new(255, 255, 255));
+    let mut buffer = Buffer::ansi();
+    table.print_color(&mut buffer).unwrap();
+    let output = String::from_utf8(buffer.into_inner()).unwrap();
+    assert!(!output.contains('\x1b'));
+    assert_eq!(output, table.render());
+
+    let mut writer = NoColor::new(Vec::new());
+    table.print_to_writer(&mut writer).unwrap();
+    assert_eq!(writer.into_inner(), table.render().into_bytes());
+    assert_eq!("208".parse::<Color>(), Ok(Color::Ansi256(208)));
+    assert_eq!("255,128,0".parse::<Color>(), Ok(Color::Rgb(255, 128, 0)));
+    assert!("mauve".parse::<Color>().is_err());
+}
+
+#[test]
+fn test_shared_table_concurrent_appends() {
+    let mut table = Table::new(TableStyle::Simple);
+    table.add_column("Host", ColumnWidth::Auto, Alignment::Left);
+    table.add_column("Port", ColumnWidth::Auto, Alignment::Right);
+    let shared = SharedTable::new(table);
+    let workers: Vec<_> = (0..8)
+        .map(|worker| {
+            let shared = shared.clone();
+            std::thread::spawn(move || {
+                for port in 0..100 {
+                    let host = format!("10.0.0.{}", worker);
+                    shared
+                        .append_row(vec![Cell::new(&host), Cell::new(&port.to_string())])
+                        .unwrap();
+                }
+            })
+        })
+        .collect();
+    let snapshot = shared.snapshot();
+    let snapshot_len = snapshot.rows.len();
+    for worker in workers {
+        worker.join().unwrap();
+    }
+    assert_eq!(shared.len(), 800);
+    assert_eq!(snapshot.rows.len(), snapshot_len);
+    assert_eq!(
+        shared.with(|table| table.sum_column("Port")),
+        Some(8.0 * 4950.0)
+    );
+    assert!(shared.append_row(vec![Cell::new("short")]).is_err());
+
+    let copy = shared.clone();
+    let Err(shared) = shared.try_into_inner() else {
+        panic!("the table is still shared");
+    };
+    drop(copy);
+    let table = shared.try_into_inner().ok().unwrap();
+    assert_eq!(table.rows.len(), 800);
+}
+
+#[test]
+fn test_shared_table_snapshot_keeps_layout() {
+    let mut table = create_test_table(TableStyle::Grid);
+    table.add_separator();
+    table.add_row(vec![
+        Cell::new("Carol"),
+        Cell::new("41"),
+        Cell::new("Boston"),
+    ]);
+    table.merge_cells(&[CellRange::new(0..2, 2..3)]).unwrap();
+    let shared = SharedTable::new(table);
+    assert_eq!(shared.snapshot().render(), shared.render());
+}
+
+#[test]
+fn test_reporter() {
+    let mut reporter = Reporter::new(&["Test", "Time"]);
+    assert!(reporter.success());
+    reporter.pass(["parse", "12ms"]);
+    reporter.pass(vec![Cell::new("render"), Cell::new("30ms")]);
+    reporter.skip(["network", ""]);
+    assert!(reporter.success());
+    reporter.fail(["export", "41ms"]);
+    assert!(!reporter.success());
+    assert_eq!(reporter.count(Outcome::Pass), 2);
+    assert_eq!(reporter.count(Outcome::Fail), 1);
+    assert_eq!(reporter.count(Outcome::Skip), 1);
+
+    let output = reporter.render();
+    assert!(output.contains("│  SKIP    │  network"));
+    assert!(output.contains("│  4 run   │  2 passed, 1 failed, 1 skipped  │"));
+
+    let table = reporter.into_table();
+    assert_eq!(table.columns[0].header, "Status");
+    assert_eq!(table.rows[3][0].content, "FAIL");
+    let spec = table.display_spec(0, &table.rows[3][0], None);
+    assert_eq!(spec.fg(), Some(&Color::Red));
+    assert!(spec.bold());
+}
+
+#[cfg(feature = "fs")]
+#[test]
+fn test_from_dir() {
+    let dir = std::env::temp_dir().join(format!("tabprinter_dir_{}", std::process::id()));
+    std::fs::create_dir_all(dir.join("logs")).unwrap();
+    std::fs::write(dir.join("b.txt"), vec![b'x'; 3072]).unwrap();
+    std::fs::write(dir.join("a.txt"), "hello").unwrap();
+
+    let table = Table::from_dir(dir.to_str().unwrap()).unwrap();
+    std::fs::remove_dir_all(&dir).unwrap();
+    let headers: Vec<&str> = table
+        .columns
+        .iter()
+        .map(|col| col.header.as_str())
+        .collect();
+    assert_eq!(headers, ["Name", "Size", "Modified", "Permissions"]);
+    let names: Vec<&str> = table
+        .rows
+        .iter()
+        .map(|row| row[0].content.as_str())
+        .collect();
+    assert_eq!(names, ["a.txt", "b.txt", "logs/"]);
+    assert_eq!(table.rows[1][1].content, "3072");
+    assert_eq!(table.rows[1][1].formatted_content(), "3.0 KiB");
+    assert_eq!(table.rows[2][1].content, "");
+    assert_eq!(table.sum_column("Size"), Some(3077.0));
+    assert!(table.rows[0][2].content.ends_with(" UTC"));
+    assert!(table.rows[2][3].content.starts_with('d'));
+}
+
+#[cfg(feature = "sysinfo")]
+#[test]
+fn test_from_processes() {
+    let table = Table::from_processes();
+    let headers: Vec<&str> = table
+        .columns
+        .iter()
+        .map(|col| col.header.as_str())
+        .collect();
+    assert_eq!(headers, ["PID", "Name", "CPU %", "Memory"]);
+    let pid = std::process::id().to_string();
+    let own = table.rows.iter().find(|row| row[0].content == pid).unwrap();
+    assert!(own[3].content.parse::<u64>().unwrap() > 0);
+    assert!(own[3].formatted_content().ends_with("iB"));
+    let usage: Vec<f64> = table
+        .rows
+        .iter()
+        .map(|row| row[2].content.parse().unwrap())
+        .collect();
+    assert!(usage.windows(2).all(|pair| pair[0] >= pair[1]));
+}
+
+#[test]
+fn test_default_style_from_env() {
+    assert_eq!(
+        "fancy-grid".parse::<TableStyle>(),
+        Ok(TableStyle::FancyGrid)
+    );
+    assert_eq!(" Round ".parse::<TableStyle>(), Ok(TableStyle::Round));
+    assert_eq!(
+        "border=cyan header=255,128,0".parse::<Theme>(),
+        Ok(Theme {
+            border_color: Some(Color::Cyan),
+            header_color: Some(Color::Rgb(255, 128, 0)),
+        })
+    );
+    assert_eq!(
+        "208".parse::<Theme>().unwrap().border_color,
+        Some(Color::Ansi256(208))
+    );
+    assert!("footer=red".parse::<Theme>().is_err());
+
+    let mut table = create_test_table(TableStyle::Simple);
+    std::env::set_var("TABPRINTER_STYLE", "round");
+    std::env::set_var("TABPRINTER_THEME", "border=blue header=yellow");
+    table.default_style_from_env().unwrap();
+    assert!(table.render().starts_with("╭"));
+    assert_eq!(table.line_color(), Some(Color::Blue));
+    assert_eq!(table.edge_color(), Some(Color::Blue));
+    assert_eq!(table.header_cells()[0].style.color, Some(Color::Yellow));
+
+    std::env::set_var("TABPRINTER_STYLE", "fancy");
+    std::env::set_var("TABPRINTER_THEME", "");
+    let error = table.default_style_from_env().unwrap_err();
+    assert!(matches!(
+        &error,
+        TableError::InvalidEnvironment { variable, .. } if variable == "TABPRINTER_STYLE"
+    ));
+    assert!(error.to_string().contains("expected one of simple, grid"));
+    assert!(table.render().starts_with("╭"));
+
+    std::env::remove_var("TABPRINTER_STYLE");
+    table.set_style(TableStyle::Neon);
+    table.default_style_from_env().unwrap();
+    std::env::remove_var("TABPRINTER_THEME");
+    assert_eq!(table.line_color(), Some(Color::Blue));
+    table.set_theme(Theme::default());
+    assert_eq!(table.line_color(), Some(Color::Magenta));
+    assert_eq!(table.edge_color(), Some(Color::Cyan));
+}
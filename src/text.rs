@@ -0,0 +1,230 @@
+// SPDX-License-Identifier: MIT
+// Project: tabprinter
+// File: src/text.rs
+// Author: Volker Schwaberow <volker@schwaberow.de>
+// Copyright (c) 2025 Volker Schwaberow
+
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+/// Returns the number of terminal columns needed to display the text.
+pub(crate) fn text_width(text: &str) -> usize {
+    UnicodeWidthStr::width(text)
+}
+
+/// Shortens the text to at most `width` terminal columns, ending with `indicator`.
+/// Grapheme clusters such as emoji sequences, flags, and combining marks are never split.
+pub(crate) fn truncate(text: &str, width: usize, indicator: &str) -> String {
+    if text_width(text) <= width {
+        return text.to_string();
+    }
+    let indicator = if text_width(indicator) <= width {
+        indicator
+    } else {
+        ""
+    };
+    let budget = width - text_width(indicator);
+    let mut truncated = String::new();
+    let mut used = 0;
+    for grapheme in text.graphemes(true) {
+        let grapheme_width = text_width(grapheme);
+        if used + grapheme_width > budget {
+            break;
+        }
+        truncated.push_str(grapheme);
+        used += grapheme_width;
+    }
+    truncated.push_str(indicator);
+    truncated
+}
+
+/// Replaces tabs with spaces up to the next multiple of `tab_width` display columns.
+/// Columns are counted from the start of each line.
+pub(crate) fn expand_tabs(text: &str, tab_width: usize) -> String {
+    if !text.contains('\t') {
+        return text.to_string();
+    }
+    let mut expanded = String::with_capacity(text.len());
+    let mut column = 0;
+    for c in text.chars() {
+        match c {
+            '\t' => {
+                let spaces = if tab_width == 0 {
+                    0
+                } else {
+                    tab_width - column % tab_width
+                };
+                expanded.push_str(&" ".repeat(spaces));
+                column += spaces;
+            }
+            '\n' => {
+                expanded.push(c);
+                column = 0;
+            }
+            _ => {
+                expanded.push(c);
+                column += c.width().unwrap_or(0);
+            }
+        }
+    }
+    expanded
+}
+
+/// Splits the text into lines of at most `width` terminal columns.
+/// Lines break between words where possible; words wider than `width` are split
+/// between grapheme clusters. Existing line breaks are kept.
+pub(crate) fn wrap(text: &str, width: usize) -> Vec<String> {
+    let width = width.max(1);
+    let mut lines = Vec::new();
+    for source in text.split('\n') {
+        let mut line = String::new();
+        let mut used = 0;
+        for word in source.split_word_bounds() {
+            let word_width = text_width(word);
+            if used + word_width <= width {
+                line.push_str(word);
+                used += word_width;
+                continue;
+            }
+            if !line.is_empty() {
+                lines.push(line.trim_end().to_string());
+                line = String::new();
+                used = 0;
+            }
+            if word.trim().is_empty() {
+                continue;
+            }
+            for grapheme in word.graphemes(true) {
+                let grapheme_width = text_width(grapheme);
+                if used + grapheme_width > width && !line.is_empty() {
+                    lines.push(std::mem::take(&mut line));
+                    used = 0;
+                }
+                line.push_str(grapheme);
+                used += grapheme_width;
+            }
+        }
+        lines.push(line.trim_end().to_string());
+    }
+    lines
+}
+
+/// Splits the text into one line per grapheme cluster, for text read top to bottom.
+/// Spaces become empty lines; existing line breaks are dropped.
+pub(crate) fn vertical_lines(text: &str) -> Vec<String> {
+    text.graphemes(true)
+        .filter(|grapheme| *grapheme != "\n" && *grapheme != "\r\n")
+        .map(|grapheme| grapheme.trim().to_string())
+        .collect()
+}
+
+/// Replaces every character other than printable ASCII and line breaks
+/// with its `\u{...}` escape.
+pub(crate) fn escape_unprintable(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if c == '\n' || (' '..='~').contains(&c) {
+            escaped.push(c);
+        } else {
+            escaped.push_str(&format!("\\u{{{:x}}}", c as u32));
+        }
+    }
+    escaped
+}
+
+/// Formats a computed number: whole numbers without decimals, others with two.
+pub(crate) fn format_number(value: f64) -> String {
+    if value.fract() == 0.0 {
+        format!("{}", value)
+    } else {
+        format!("{:.2}", value)
+    }
+}
+
+/// The binary units used by `format_bytes`, padded to the same width.
+const BYTE_UNITS: [&str; 7] = ["B  ", "KiB", "MiB", "GiB", "TiB", "PiB", "EiB"];
+
+/// Formats a number of bytes with the largest binary unit that keeps the value
+/// at least one, such as `12.3 MiB`. Plain bytes have no decimals. Units are padded
+/// to the same width so values line up in right-aligned columns.
+pub(crate) fn format_bytes(bytes: f64, decimal_places: usize) -> String {
+    let mut value = bytes.abs();
+    let mut unit = 0;
+    while value >= 1024.0 && unit < BYTE_UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    let sign = if bytes < 0.0 { "-" } else { "" };
+    match unit {
+        0 => format!("{}{} {}", sign, value.round(), BYTE_UNITS[0]),
+        _ => format!("{}{:.*} {}", sign, decimal_places, value, BYTE_UNITS[unit]),
+    }
+}
+
+/// Formats a number of seconds with its two largest units, such as `1m 32s` or
+/// `2d 04h`. The second unit is zero-padded so values line up in right-aligned
+/// columns. Durations below a second are shown in milliseconds.
+pub(crate) fn format_duration(seconds: f64) -> String {
+    const UNITS: [(&str, u64); 4] = [("d", 86_400), ("h", 3_600), ("m", 60), ("s", 1)];
+    let sign = if seconds < 0.0 { "-" } else { "" };
+    let seconds = seconds.abs();
+    if seconds < 1.0 {
+        return format!("{}{}ms", sign, (seconds * 1000.0).round());
+    }
+    let total = seconds.round() as u64;
+    let (i, (unit, size)) = UNITS
+        .iter()
+        .enumerate()
+        .find(|(_, (_, size))| total >= *size)
+        .expect("the last unit is one second");
+    match UNITS.get(i + 1) {
+        Some((next, next_size)) => format!(
+            "{}{}{} {:02}{}",
+            sign,
+            total / size,
+            unit,
+            total % size / next_size,
+            next
+        ),
+        None => format!("{}{}{}", sign, total, unit),
+    }
+}
+
+/// Replaces the whitespace at the start and end of every line with `mark`,
+/// leaving the whitespace between words as it is.
+pub(crate) fn mark_edge_whitespace(text: &str, mark: char) -> String {
+    text.split('\n')
+        .map(|line| {
+            let start = line.len() - line.trim_start().len();
+            let end = line.trim_end().len().max(start);
+            let marks = |part: &str| mark.to_string().repeat(part.chars().count());
+            format!(
+                "{}{}{}",
+                marks(&line[..start]),
+                &line[start..end],
+                marks(&line[end..])
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Removes ANSI escape sequences, such as the color codes written by `termcolor`.
+pub(crate) fn strip_ansi(text: &str) -> String {
+    let mut stripped = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c != '\x1b' {
+            stripped.push(c);
+            continue;
+        }
+        if chars.next() == Some('[') {
+            for c in chars.by_ref() {
+                if ('\x40'..='\x7e').contains(&c) {
+                    break;
+                }
+            }
+        }
+    }
+    stripped
+}
@@ -0,0 +1,157 @@
+// SPDX-License-Identifier: MIT
+// Project: tabprinter
+// File: src/theme.rs
+// Author: Volker Schwaberow <volker@schwaberow.de>
+// Copyright (c) 2025 Volker Schwaberow
+
+use std::env;
+use std::str::FromStr;
+
+use crate::color::Color;
+use crate::{Table, TableError, TableStyle};
+
+/// The environment variable naming the style picked by the end user.
+const STYLE_VARIABLE: &str = "TABPRINTER_STYLE";
+/// The environment variable describing the theme picked by the end user.
+const THEME_VARIABLE: &str = "TABPRINTER_THEME";
+
+/// Every table style with its name, as written in sidecar files and accepted
+/// by `TableStyle::from_str`.
+pub(crate) const STYLE_NAMES: [(&str, TableStyle); 15] = [
+    ("simple", TableStyle::Simple),
+    ("grid", TableStyle::Grid),
+    ("fancy_grid", TableStyle::FancyGrid),
+    ("clean", TableStyle::Clean),
+    ("round", TableStyle::Round),
+    ("banner", TableStyle::Banner),
+    ("block", TableStyle::Block),
+    ("amiga", TableStyle::Amiga),
+    ("minimal", TableStyle::Minimal),
+    ("compact", TableStyle::Compact),
+    ("markdown", TableStyle::Markdown),
+    ("dotted", TableStyle::Dotted),
+    ("heavy", TableStyle::Heavy),
+    ("neon", TableStyle::Neon),
+    ("accessible", TableStyle::Accessible),
+];
+
+impl FromStr for TableStyle {
+    type Err = TableError;
+
+    /// Parses a style name such as `round` or `fancy-grid`, ignoring case and
+    /// treating dashes as underscores.
+    fn from_str(s: &str) -> Result<TableStyle, TableError> {
+        let name = s.trim().to_lowercase().replace('-', "_");
+        STYLE_NAMES
+            .iter()
+            .find(|(style_name, _)| *style_name == name)
+            .map(|(_, style)| *style)
+            .ok_or_else(|| {
+                let names: Vec<&str> = STYLE_NAMES.iter().map(|(name, _)| *name).collect();
+                TableError::InvalidStyle(format!(
+                    "unknown style `{}`, expected one of {}",
+                    s,
+                    names.join(", ")
+                ))
+            })
+    }
+}
+
+/// Colors replacing those of the table style.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Theme {
+    /// The color of all borders and lines, replacing the colors of the style.
+    pub border_color: Option<Color>,
+    /// The color of the header text.
+    pub header_color: Option<Color>,
+}
+
+impl FromStr for Theme {
+    type Err = TableError;
+
+    /// Parses whitespace-separated `border=<color>` and `header=<color>` settings,
+    /// such as `border=cyan header=208`. A color alone sets the borders. Colors
+    /// are names such as `red`, palette indexes such as `208`, or components such
+    /// as `255,128,0`.
+    fn from_str(s: &str) -> Result<Theme, TableError> {
+        let mut theme = Theme::default();
+        for setting in s.split_whitespace() {
+            let (key, value) = setting.split_once('=').unwrap_or(("border", setting));
+            let color = value.parse::<Color>().map_err(|error| {
+                TableError::InvalidStyle(format!("invalid {} color: {}", key, error))
+            })?;
+            match key.to_lowercase().as_str() {
+                "border" => theme.border_color = Some(color),
+                "header" => theme.header_color = Some(color),
+                _ => {
+                    return Err(TableError::InvalidStyle(format!(
+                        "unknown theme setting `{}`, expected `border` or `header`",
+                        key
+                    )))
+                }
+            }
+        }
+        Ok(theme)
+    }
+}
+
+/// Returns the value of the environment variable parsed, or `None` if it is unset
+/// or blank.
+fn parse_variable<T>(variable: &str) -> Result<Option<T>, TableError>
+where
+    T: FromStr<Err = TableError>,
+{
+    let invalid = |reason: String| TableError::InvalidEnvironment {
+        variable: variable.to_string(),
+        reason,
+    };
+    match env::var(variable) {
+        Ok(value) if value.trim().is_empty() => Ok(None),
+        Ok(value) => value.parse().map(Some).map_err(|error| match error {
+            TableError::InvalidStyle(reason) => invalid(reason),
+            error => invalid(error.to_string()),
+        }),
+        Err(env::VarError::NotPresent) => Ok(None),
+        Err(error) => Err(invalid(error.to_string())),
+    }
+}
+
+impl Table {
+    /// Sets colors replacing those of the style, such as a border color for
+    /// styles without colors.
+    pub fn set_theme(&mut self, theme: Theme) {
+        self.theme = theme;
+    }
+
+    /// Applies the style named in `TABPRINTER_STYLE` and the theme described in
+    /// `TABPRINTER_THEME`, so end users of a tool can pick their preferred look
+    /// without the tool offering a flag. The style and theme the tool set are kept
+    /// when a variable is unset or blank. Fails without changing the table if a
+    /// variable has an invalid value; the error names the variable and the
+    /// accepted values, ready to be reported to the user.
+    pub fn default_style_from_env(&mut self) -> Result<(), TableError> {
+        let style = parse_variable::<TableStyle>(STYLE_VARIABLE)?;
+        let theme = parse_variable::<Theme>(THEME_VARIABLE)?;
+        if let Some(style) = style {
+            self.style = style;
+        }
+        if let Some(theme) = theme {
+            self.theme = theme;
+        }
+        Ok(())
+    }
+
+    /// Returns the color of the horizontal lines.
+    pub(crate) fn line_color(&self) -> Option<Color> {
+        self.theme
+            .border_color
+            .or_else(|| self.style.config().and_then(|config| config.line_color))
+    }
+
+    /// Returns the color of the vertical borders of each row.
+    pub(crate) fn edge_color(&self) -> Option<Color> {
+        self.theme
+            .border_color
+            .or_else(|| self.style.config().and_then(|config| config.edge_color))
+    }
+}
@@ -0,0 +1,112 @@
+// SPDX-License-Identifier: MIT
+// Project: tabprinter
+// File: src/title.rs
+// Author: Volker Schwaberow <volker@schwaberow.de>
+// Copyright (c) 2025 Volker Schwaberow
+
+use std::io;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::color::WriteColor;
+use crate::text::{text_width, truncate};
+use crate::{LineStyle, Table};
+
+/// Formats seconds since the Unix epoch as a UTC date and time, such as
+/// `2025-03-14 09:26:53 UTC`.
+pub(crate) fn format_utc(seconds: u64) -> String {
+    let days = (seconds / 86_400) as i64;
+    let time = seconds % 86_400;
+    // Converts days since the epoch to a civil date, after Howard Hinnant's algorithm.
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}:{:02} UTC",
+        year,
+        month,
+        day,
+        time / 3_600,
+        time % 3_600 / 60,
+        time % 60
+    )
+}
+
+/// Replaces the middle of a horizontal border with ` title `, keeping one line
+/// character on either side. The title is shortened to fit, and left out when
+/// not even one character of it fits.
+fn overlay_title(line: &str, begin: &str, end: &str, title: &str, ellipsis: &str) -> String {
+    let border: Vec<char> = line.chars().collect();
+    let start = text_width(begin) + 1;
+    let room = border.len().saturating_sub(start + 1 + text_width(end) + 2);
+    if room == 0 {
+        return line.to_string();
+    }
+    let title = format!(" {} ", truncate(title, room, ellipsis));
+    let mut titled: String = border[..start].iter().collect();
+    titled.push_str(&title);
+    titled.extend(&border[start + text_width(&title)..]);
+    titled
+}
+
+impl Table {
+    /// Sets a title drawn inside the top border of bordered styles, like the title bar
+    /// of a window, and on its own line above tables without a top border.
+    /// The placeholders `{rows}`, `{columns}`, and `{now}` are replaced when the table
+    /// is printed by the number of data rows, the number of columns, and the current
+    /// UTC date and time. Accessible output has no title.
+    pub fn set_title_template(&mut self, template: &str) {
+        self.title_template = Some(template.to_string());
+    }
+
+    /// Returns the title with its placeholders expanded, if one is set.
+    fn title(&self) -> Option<String> {
+        let template = self.title_template.as_ref()?;
+        let mut title = template
+            .replace("{rows}", &self.rows.len().to_string())
+            .replace("{columns}", &self.columns.len().to_string());
+        if title.contains("{now}") {
+            let now = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |elapsed| elapsed.as_secs());
+            title = title.replace("{now}", &format_utc(now));
+        }
+        Some(self.safe_text(title))
+    }
+
+    /// Returns whether the title is printed on its own line above the table.
+    pub(crate) fn has_title_line(&self, top: &LineStyle) -> bool {
+        self.title_template.is_some() && top.is_empty()
+    }
+
+    /// Prints the top border, with the title inside it if one is set.
+    /// Without a top border, the title is printed on its own line.
+    pub(crate) fn print_top(
+        &self,
+        writer: &mut dyn WriteColor,
+        top: &LineStyle,
+        widths: &[usize],
+    ) -> io::Result<()> {
+        let Some(title) = self.title() else {
+            return self.print_line(writer, top, widths);
+        };
+        if top.is_empty() {
+            return writeln!(writer, "{}", title);
+        }
+        let color = self.line_color();
+        let line = self.line_text(top, widths, None, None);
+        let line = overlay_title(&line, top.begin, top.end, &title, self.ellipsis());
+        Self::write_border(writer, &line, color)?;
+        writeln!(writer)
+    }
+}
@@ -0,0 +1,97 @@
+// SPDX-License-Identifier: MIT
+// Project: tabprinter
+// File: src/validate.rs
+// Author: Volker Schwaberow <volker@schwaberow.de>
+// Copyright (c) 2025 Volker Schwaberow
+
+use std::sync::Arc;
+
+use crate::{Cell, Row, Table, TableError};
+
+/// Checks a row before it is added, returning a description of the problem if
+/// the row is rejected.
+pub(crate) type RowValidator = Arc<dyn Fn(&[Cell]) -> Result<(), String> + Send + Sync>;
+
+impl Table {
+    /// Sets a check run on every row added with `add_row`, `try_add_row`, or
+    /// `StreamPrinter::push_row`, such as that numeric columns parse or that a
+    /// column holds one of a few allowed values. Rows already in the table, such as
+    /// imported ones, are checked with `validate_rows`.
+    pub fn set_row_validator<F>(&mut self, validator: F)
+    where
+        F: Fn(&[Cell]) -> Result<(), String> + Send + Sync + 'static,
+    {
+        self.row_validator = Some(Arc::new(validator));
+    }
+
+    /// Removes the row validator.
+    pub fn clear_row_validator(&mut self) {
+        self.row_validator = None;
+    }
+
+    /// Checks a row that would be added at the position against the number of
+    /// columns and the row validator.
+    pub(crate) fn check_new_row(&self, row: &[Cell], position: usize) -> Result<(), TableError> {
+        if row.len() != self.columns.len() {
+            return Err(TableError::InvalidRow {
+                row: position,
+                reason: format!(
+                    "the row has {} cells but the table has {} columns",
+                    row.len(),
+                    self.columns.len()
+                ),
+            });
+        }
+        match &self.row_validator {
+            Some(validator) => validator(row).map_err(|reason| TableError::InvalidRow {
+                row: position,
+                reason,
+            }),
+            None => Ok(()),
+        }
+    }
+
+    /// Adds a row to the table if it has one cell per column and passes the row
+    /// validator, or returns why it was rejected, leaving the table unchanged.
+    pub fn try_add_row(&mut self, row: Vec<Cell>) -> Result<(), TableError> {
+        self.check_new_row(&row, self.rows.len())?;
+        let id = self.allocate_row_id();
+        self.rows.push(Row { id, cells: row });
+        Ok(())
+    }
+
+    /// Moves the rows into the table, checking each like `try_add_row` without
+    /// copying its cells. Fails on the first rejected row, leaving the table as it was.
+    /// Room for the rows is reserved up front from the size hint of the iterator.
+    pub fn extend_rows<I>(&mut self, rows: I) -> Result<(), TableError>
+    where
+        I: IntoIterator<Item = Vec<Cell>>,
+    {
+        let rows = rows.into_iter();
+        let start = self.rows.len();
+        let next_row_id = self.next_row_id;
+        self.rows.reserve(rows.size_hint().0);
+        for row in rows {
+            if let Err(error) = self.check_new_row(&row, self.rows.len()) {
+                self.rows.truncate(start);
+                self.next_row_id = next_row_id;
+                return Err(error);
+            }
+            let id = self.allocate_row_id();
+            self.rows.push(Row { id, cells: row });
+        }
+        Ok(())
+    }
+
+    /// Checks every row of the table with the row validator, failing on the first
+    /// rejected row. Use it after importing rows into a table with a validator.
+    pub fn validate_rows(&self) -> Result<(), TableError> {
+        let Some(validator) = &self.row_validator else {
+            return Ok(());
+        };
+        for (i, row) in self.rows.iter().enumerate() {
+            validator(row).map_err(|reason| TableError::InvalidRow { row: i, reason })?;
+        }
+        Ok(())
+    }
+}
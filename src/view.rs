@@ -0,0 +1,194 @@
+// SPDX-License-Identifier: MIT
+// Project: tabprinter
+// File: src/view.rs
+// Author: Volker Schwaberow <volker@schwaberow.de>
+// Copyright (c) 2025 Volker Schwaberow
+
+use std::io::{self, Write};
+use std::ops::{Bound, Range, RangeBounds};
+
+use crate::color::WriteColor;
+use crate::{Cell, ColumnRef, Row, SortOptions, Table, TableError};
+
+/// Resolves a range against a length, clamping it to `0..len`.
+fn clamp_range(range: impl RangeBounds<usize>, len: usize) -> Range<usize> {
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start.saturating_add(1),
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end.saturating_add(1),
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => len,
+    };
+    let end = end.min(len);
+    start.min(end)..end
+}
+
+/// A non-destructive view over a borrowed table.
+/// Ordering, filters, column selection, and grouping are applied when the view is
+/// rendered or materialized; the underlying table is never modified.
+pub struct TableView<'a> {
+    /// The table the view is built on.
+    table: &'a Table,
+    /// The positions of the visible rows in the table, in display order.
+    rows: Vec<usize>,
+    /// The positions of the visible columns in the table, in display order.
+    columns: Vec<usize>,
+    /// The column used to group rows with subtotals, if any.
+    group_by: Option<usize>,
+}
+
+impl<'a> TableView<'a> {
+    /// Creates a view showing every row and column of the table.
+    fn new(table: &'a Table) -> Self {
+        Self {
+            table,
+            rows: (0..table.rows.len()).collect(),
+            columns: (0..table.columns.len()).collect(),
+            group_by: None,
+        }
+    }
+
+    /// Sorts the visible rows by the specified column of the underlying table.
+    /// If `ascending` is true, sorts in ascending order; otherwise, sorts in descending order.
+    pub fn sort_by_column<'c>(mut self, column: impl Into<ColumnRef<'c>>, ascending: bool) -> Self {
+        let column_index = self.table.resolve_column(column);
+        let rows = &self.table.rows;
+        self.rows.sort_by(|&a, &b| {
+            let ord = rows[a][column_index]
+                .content
+                .cmp(&rows[b][column_index].content);
+            if ascending {
+                ord
+            } else {
+                ord.reverse()
+            }
+        });
+        self
+    }
+
+    /// Sorts the visible rows by the specified column using the given comparison options.
+    pub fn sort_by_column_with<'c>(
+        mut self,
+        column: impl Into<ColumnRef<'c>>,
+        ascending: bool,
+        options: &SortOptions,
+    ) -> Self {
+        let column_index = self.table.resolve_column(column);
+        let rows = &self.table.rows;
+        self.rows.sort_by(|&a, &b| {
+            options.compare_directed(&rows[a][column_index], &rows[b][column_index], ascending)
+        });
+        self
+    }
+
+    /// Keeps only the visible rows matching the predicate.
+    pub fn filter<F>(mut self, predicate: F) -> Self
+    where
+        F: Fn(&Vec<Cell>) -> bool,
+    {
+        let rows = &self.table.rows;
+        self.rows.retain(|&i| predicate(&rows[i].cells));
+        self
+    }
+
+    /// Shows only the specified columns of the underlying table, given by index or
+    /// header, in the given order. Fails if a column does not exist.
+    pub fn select<'c, C: Into<ColumnRef<'c>>>(
+        mut self,
+        columns: impl IntoIterator<Item = C>,
+    ) -> Result<Self, TableError> {
+        self.columns = self.table.column_positions(columns)?;
+        Ok(self)
+    }
+
+    /// Keeps only the visible rows and columns at the given positions of the view.
+    /// Ranges reaching past the view are clamped.
+    pub fn slice(
+        mut self,
+        rows: impl RangeBounds<usize>,
+        columns: impl RangeBounds<usize>,
+    ) -> Self {
+        let rows = clamp_range(rows, self.rows.len());
+        let columns = clamp_range(columns, self.columns.len());
+        self.rows = self.rows[rows].to_vec();
+        self.columns = self.columns[columns].to_vec();
+        self
+    }
+
+    /// Groups consecutive rows by the specified column and adds subtotals.
+    pub fn group_by_column_with_subtotals<'c>(mut self, column: impl Into<ColumnRef<'c>>) -> Self {
+        self.group_by = Some(self.table.resolve_column(column));
+        self
+    }
+
+    /// Returns the number of rows shown by the view, excluding subtotals.
+    pub fn row_count(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Builds a new table containing exactly what the view shows.
+    pub fn to_table(&self) -> Table {
+        let rows: Vec<Row> = self
+            .rows
+            .iter()
+            .map(|&i| self.table.rows[i].clone())
+            .collect();
+        let mut table = self.table.derive_with_rows(rows);
+        if let Some(column_index) = self.group_by {
+            table.group_by_column_with_subtotals(column_index);
+        }
+        for row in table.rows.iter_mut() {
+            row.cells = self.columns.iter().map(|&c| row.cells[c].clone()).collect();
+        }
+        if let Some(footer) = &mut table.footer {
+            *footer = self.columns.iter().map(|&c| footer[c].clone()).collect();
+        }
+        table.columns = self
+            .columns
+            .iter()
+            .map(|&c| self.table.columns[c].clone())
+            .collect();
+        table.sticky_columns = self
+            .table
+            .sticky_columns
+            .iter()
+            .filter_map(|&sticky| self.columns.iter().position(|&c| c == sticky))
+            .collect();
+        table
+    }
+
+    /// Prints the view to the specified writer.
+    pub fn print_to_writer(&self, writer: &mut dyn WriteColor) -> io::Result<()> {
+        self.to_table().print_to_writer(writer)
+    }
+
+    /// Prints the view with color support.
+    pub fn print_color<W: Write + WriteColor>(&self, writer: &mut W) -> io::Result<()> {
+        self.to_table().print_color(writer)
+    }
+
+    /// Prints the view to the standard output with color support.
+    pub fn print(&self) -> io::Result<()> {
+        self.to_table().print()
+    }
+}
+
+impl Table {
+    /// Returns a view over the table showing every row and column.
+    pub fn view(&self) -> TableView<'_> {
+        TableView::new(self)
+    }
+
+    /// Returns a view showing only a rectangular window of the table, such as
+    /// rows `1000..1050` and columns `2..6`. Ranges reaching past the table are clamped.
+    pub fn slice(
+        &self,
+        rows: impl RangeBounds<usize>,
+        columns: impl RangeBounds<usize>,
+    ) -> TableView<'_> {
+        self.view().slice(rows, columns)
+    }
+}
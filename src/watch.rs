@@ -0,0 +1,83 @@
+// SPDX-License-Identifier: MIT
+// Project: tabprinter
+// File: src/watch.rs
+// Author: Volker Schwaberow <volker@schwaberow.de>
+// Copyright (c) 2025 Volker Schwaberow
+
+use std::fmt::Write as _;
+use std::io::{self, Write};
+use std::thread;
+use std::time::Duration;
+
+use crate::color::{Buffer, ColorChoice, StandardStream, WriteColor};
+use crate::Table;
+
+/// The lines last drawn on the terminal, used to redraw only what changed.
+#[derive(Default)]
+pub(crate) struct Screen {
+    /// The lines of the previous frame, or `None` before the first frame.
+    lines: Option<Vec<String>>,
+}
+
+impl Screen {
+    /// Returns the terminal output that turns the previous frame into `frame`.
+    /// The first frame clears the screen; later frames rewrite only the changed
+    /// lines and clear the lines left over from a taller previous frame.
+    /// The cursor is left on the line below the frame.
+    pub(crate) fn redraw(&mut self, frame: &str) -> String {
+        let lines: Vec<String> = frame.lines().map(String::from).collect();
+        let mut output = String::new();
+        let previous = match self.lines.take() {
+            Some(previous) => previous,
+            None => {
+                output.push_str("\x1b[2J");
+                Vec::new()
+            }
+        };
+        for (i, line) in lines.iter().enumerate() {
+            if previous.get(i) != Some(line) {
+                let _ = write!(output, "\x1b[{};1H{}\x1b[K", i + 1, line);
+            }
+        }
+        if lines.len() < previous.len() {
+            let _ = write!(output, "\x1b[{};1H\x1b[J", lines.len() + 1);
+        }
+        let _ = write!(output, "\x1b[{};1H", lines.len() + 1);
+        self.lines = Some(lines);
+        output
+    }
+}
+
+/// Clears the terminal and prints the table produced by `produce` every `interval`,
+/// like `watch(1)`. Only the lines that changed since the previous frame are
+/// redrawn, which keeps the output from flickering. Only returns on error.
+pub fn watch<F>(interval: Duration, produce: F) -> io::Result<()>
+where
+    F: FnMut() -> Table,
+{
+    let mut stdout = StandardStream::stdout(ColorChoice::Always);
+    watch_to_writer(&mut stdout, interval, produce)
+}
+
+/// Prints the table produced by `produce` to the writer every `interval`, redrawing
+/// only the lines that changed. The writer must understand ANSI escape sequences.
+/// Only returns on error.
+pub fn watch_to_writer<W, F>(writer: &mut W, interval: Duration, mut produce: F) -> io::Result<()>
+where
+    W: Write + WriteColor,
+    F: FnMut() -> Table,
+{
+    let mut screen = Screen::default();
+    loop {
+        let mut buffer = if writer.supports_color() {
+            Buffer::ansi()
+        } else {
+            Buffer::no_color()
+        };
+        produce().print_color(&mut buffer)?;
+        let frame = String::from_utf8_lossy(buffer.as_slice());
+        writer.write_all(screen.redraw(&frame).as_bytes())?;
+        writer.flush()?;
+        thread::sleep(interval);
+    }
+}